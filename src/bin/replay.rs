@@ -0,0 +1,16 @@
+use std::path::Path;
+
+// Replays a session recorded with `record_sessions_dir` and prints the final state of the game
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .expect("Usage: replay <recorded session file>");
+    let game = headcrab::replay_file(Path::new(&path)).unwrap_or_else(|error| {
+        panic!("Could not replay the session. File: '{path}', Error: '{error}'.")
+    });
+
+    println!("Title: {}", game.title());
+    println!("State: {:?}", game.state());
+    println!("Players: {:#?}", game.players());
+    println!("Rounds: {:#?}", game.rounds());
+}
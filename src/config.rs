@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use config::ConfigError;
@@ -11,7 +12,15 @@ pub struct Config {
     pub application: ApplicationSettings,
     pub game: GameSettings,
     pub words_file: String,
+    // Other lists of words by name, a file or a URL like the words file, which is the default one
+    #[serde(default)]
+    pub word_categories: HashMap<String, String>,
+    // Words that players can't submit, one per line and compared regardless of case
+    #[serde(default)]
+    pub blocklist_file: Option<String>,
     pub allow_cors: bool,
+    #[serde(default)]
+    pub game_creation_rate_limit: RateLimitSettings,
 }
 
 #[derive(serde::Deserialize, Clone)]
@@ -21,15 +30,249 @@ pub struct ApplicationSettings {
     pub port: u16,
 }
 
-#[derive(serde::Deserialize, Clone)]
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
+#[serde(default)]
 pub struct GameSettings {
+    pub minimum_players: u8,
+    pub maximum_rounds: u8,
     pub inactivity_timeout_seconds: u64,
+    // Players that don't send a ping within this time are disconnected
+    pub player_ping_timeout_seconds: u64,
+    // When set, the server sends websocket Ping frames this often, the Pong replies keep the player
+    // connected without client pings
+    pub player_heartbeat_interval_seconds: Option<u64>,
+    // Replaces the inactivity timeout once a game past the lobby has no connected players
+    pub mid_game_empty_grace_seconds: Option<u64>,
+    // Freezes the word reveal and the word submission deadline while a game past the lobby has no
+    // connected players, they resume when the first player reconnects
+    pub pause_when_empty: bool,
+    // How long the ticket returned by the rejoin endpoint can be presented on the websocket upgrade
+    pub rejoin_ticket_seconds: u64,
+    pub closed_game_retention_seconds: u64,
+    // When set, no more than this many games are created every second across all clients, on top of
+    // the per IP rate limit
+    pub max_creates_per_second: Option<u32>,
+    pub collapse_internal_whitespace: bool,
+    // Maximum amount of characters of a submitted word, once normalized
+    pub max_word_length: usize,
+    // Maximum amount of words a player can submit in a round, once normalized
+    pub max_words_per_player: usize,
+    // Minimum amount of words a connected player has to submit in a round, once normalized
+    pub min_words_per_player: usize,
+    // The players without the word are not skipped, and the voting words can only be accepted
+    // once every connected player has voted
+    pub require_explicit_votes: bool,
+    // When set, how many times a player can change their voting word for the same voting item
+    pub max_vote_changes_per_item: Option<u8>,
+    // Broadcast as a system message when a round starts, `{round}` and `{word}` are filled in
+    pub round_start_message_template: Option<String>,
+    // 0 shows the word of the round immediately, otherwise it is revealed in stages while players submit words
+    pub word_reveal_stages: u8,
+    pub word_reveal_interval_seconds: u64,
+    // When set, players that haven't submitted their words by then submit no words
+    pub word_submission_seconds: Option<u64>,
+    // 0 shows the first voting item as soon as the words are submitted, otherwise the game waits this
+    // long in between so that the players can get ready to vote
+    pub voting_pause_seconds: u64,
+    pub all_skip_policy: AllSkipPolicy,
+    // When set, every game appends the commands it receives to a JSONL file in this directory
+    pub record_sessions_dir: Option<String>,
+    // Disconnected players get random words from the game's word list instead of no words
+    pub autofill_disconnected_words: bool,
+    pub max_chat_length: usize,
+    // Amount of the latest chat messages that are replayed to a player when they join
+    pub chat_history_size: usize,
+    // Lets players select their own words as voting words regardless of case
+    pub case_insensitive_voting_words: bool,
+    pub score_event_verbosity: ScoreEventVerbosity,
+    // Shuffles the order in which players are voted each round, instead of following the roster
+    pub shuffle_turn_order: bool,
+    // Resubmitting a word the host rejected clears the rejection instead of failing
+    pub allow_resubmit_after_reject: bool,
+    // Privately tells the owner of the next voting item to get ready while the current one is voted
+    pub announce_next_voting_player: bool,
+    // Amount of players, the owner included, that must match a word for it to score
+    pub min_matchers_to_score: usize,
+    // Words identical to the voting item are voted with it, so the group is scored at once
+    pub group_identical_words: bool,
+    // The host can't accept the voting words until the voting item has been shown for this long
+    pub min_voting_item_seconds: u64,
+    // A player that disconnects while voting skips the voting item, which is accepted without the host
+    // once every connected player has voted
+    pub skip_disconnected_voters: bool,
+    // Players that don't submit any words themselves for this many rounds in a row, disconnected or
+    // not, are removed from the game when the next round starts
+    pub kick_after_missed_rounds: Option<u8>,
+    pub round_review_order: RoundReviewOrder,
+    // When set, the points every player that submitted at least one word gets each round, on top of
+    // the score of their words
+    pub participation_points: Option<usize>,
+    // When set, the game state only includes this many trailing rounds, the older ones have to be
+    // requested separately. The current round is always included
+    pub max_rounds_in_state: Option<usize>,
+    // Maximum amount of rounds of the history sent in a single message, also the page size when the
+    // player does not ask for one
+    pub round_history_page_size: usize,
+    // While the words are submitted, tells how many players already submitted them, but no longer
+    // which ones did
+    pub show_submission_progress: bool,
+    // When set, the words of every game are shuffled in the same order, so that games and tests
+    // can be reproduced
+    pub word_shuffle_seed: Option<u64>,
+    // When set, a game without words of its own plays a random sample of this many built-in words
+    // instead of all of them, so that those games don't all look the same
+    pub fallback_words_per_game: Option<usize>,
+}
+
+// How the word of the voting item is scored when every other player skipped it
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, Default, PartialEq)]
+pub enum AllSkipPolicy {
+    #[default]
+    ZeroScore,
+    NegativeOne,
+    FlagWord,
+}
+
+// The order in which the words of a round are suggested for review once the round ends
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, Default, PartialEq)]
+pub enum RoundReviewOrder {
+    #[default]
+    Natural,
+    ScoreDescending,
+    Shuffled,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            minimum_players: 3,
+            maximum_rounds: 20,
+            inactivity_timeout_seconds: 300,
+            player_ping_timeout_seconds: 5,
+            player_heartbeat_interval_seconds: None,
+            mid_game_empty_grace_seconds: None,
+            pause_when_empty: false,
+            rejoin_ticket_seconds: 30,
+            closed_game_retention_seconds: 0,
+            max_creates_per_second: None,
+            collapse_internal_whitespace: false,
+            max_word_length: 30,
+            max_words_per_player: 10,
+            min_words_per_player: 0,
+            require_explicit_votes: false,
+            max_vote_changes_per_item: None,
+            round_start_message_template: None,
+            word_reveal_stages: 0,
+            word_reveal_interval_seconds: 5,
+            word_submission_seconds: None,
+            voting_pause_seconds: 0,
+            all_skip_policy: AllSkipPolicy::default(),
+            record_sessions_dir: None,
+            autofill_disconnected_words: false,
+            max_chat_length: 500,
+            chat_history_size: 50,
+            case_insensitive_voting_words: false,
+            score_event_verbosity: ScoreEventVerbosity::default(),
+            shuffle_turn_order: false,
+            allow_resubmit_after_reject: false,
+            announce_next_voting_player: false,
+            min_matchers_to_score: 2,
+            group_identical_words: false,
+            min_voting_item_seconds: 0,
+            skip_disconnected_voters: false,
+            kick_after_missed_rounds: None,
+            round_review_order: RoundReviewOrder::default(),
+            participation_points: None,
+            max_rounds_in_state: None,
+            round_history_page_size: 10,
+            show_submission_progress: false,
+            word_shuffle_seed: None,
+            fallback_words_per_game: None,
+        }
+    }
 }
 
 impl GameSettings {
     pub fn inactivity_timeout(&self) -> Duration {
         Duration::from_secs(self.inactivity_timeout_seconds)
     }
+
+    pub fn player_ping_timeout(&self) -> Duration {
+        Duration::from_secs(self.player_ping_timeout_seconds)
+    }
+
+    pub fn player_heartbeat_interval(&self) -> Option<Duration> {
+        self.player_heartbeat_interval_seconds
+            .map(Duration::from_secs)
+    }
+
+    pub fn mid_game_empty_grace(&self) -> Duration {
+        self.mid_game_empty_grace_seconds
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| self.inactivity_timeout())
+    }
+
+    pub fn voting_pause(&self) -> Duration {
+        Duration::from_secs(self.voting_pause_seconds)
+    }
+
+    pub fn rejoin_ticket_lifetime(&self) -> Duration {
+        Duration::from_secs(self.rejoin_ticket_seconds)
+    }
+
+    pub fn closed_game_retention(&self) -> Duration {
+        Duration::from_secs(self.closed_game_retention_seconds)
+    }
+
+    pub fn word_reveal_interval(&self) -> Duration {
+        Duration::from_secs(self.word_reveal_interval_seconds)
+    }
+
+    pub fn min_voting_item_time(&self) -> Duration {
+        Duration::from_secs(self.min_voting_item_seconds)
+    }
+
+    pub fn word_submission_deadline(&self) -> Option<Duration> {
+        self.word_submission_seconds.map(Duration::from_secs)
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    // A burst of 0 disables the rate limit
+    pub burst: u32,
+    pub refill_interval_seconds: u64,
+    // When set, the client IP is taken from this header, which a trusted proxy like the ingress sets,
+    // instead of from the connection
+    pub client_ip_header: Option<String>,
+}
+
+// Which scoring messages are broadcast once the voting words are accepted, the GameState is always sent
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug, Default, PartialEq)]
+pub enum ScoreEventVerbosity {
+    #[default]
+    Full,
+    StateOnly,
+}
+
+impl RateLimitSettings {
+    pub fn is_enabled(&self) -> bool {
+        self.burst > 0
+    }
+
+    pub fn refill_interval(&self) -> Duration {
+        Duration::from_secs(self.refill_interval_seconds)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.is_enabled() && self.refill_interval_seconds == 0 {
+            return Err(ConfigError::Message(
+                "game_creation_rate_limit.refill_interval_seconds must be greater than 0 when the rate limit is enabled".to_string(),
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl Config {
@@ -53,7 +296,9 @@ impl Config {
             ))
             .build()?;
 
-        config.try_deserialize::<Config>()
+        let config = config.try_deserialize::<Config>()?;
+        config.game_creation_rate_limit.validate()?;
+        Ok(config)
     }
 }
 
@@ -87,3 +332,20 @@ impl TryFrom<String> for Environment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimitSettings;
+
+    #[test]
+    fn enabled_rate_limit_without_refill_interval_is_invalid() {
+        let settings = RateLimitSettings {
+            burst: 1,
+            refill_interval_seconds: 0,
+            client_ip_header: None,
+        };
+
+        assert!(settings.validate().is_err());
+        assert!(RateLimitSettings::default().validate().is_ok());
+    }
+}
@@ -4,18 +4,56 @@ use crate::game::game_fsm::GameFsmState;
 
 #[derive(Clone, Debug, Error, PartialEq)]
 pub enum DomainError {
+    #[error("A player cannot submit a blocked word. Nickname: '{nickname}', Word: '{word}'.")]
+    BlockedWord { nickname: String, word: String },
     #[error("Cannot reject matching words when voting item is none")]
     CannotRejectMatchedWordsWhenVotingItemIsNone,
     #[error("Cannot submit a word for matching if it was previously rejected")]
     CannotResubmitRejectedMatchedWord,
+    #[error("A player cannot kick themselves. Nickname: '{0}'.")]
+    CannotKickSelf(String),
+    #[error("A player cannot be merged with itself. Nickname: '{0}'.")]
+    CannotMergePlayerWithItself(String),
+    #[error("The chat message is too long. MaximumLength: '{max}'.")]
+    ChatMessageTooLong { max: usize },
     #[error("The game is already in progress. GameId: '{0}'.")]
     GameAlreadyInProgress(String),
+    #[error("The game has already been started. ActualState: '{0:?}'.")]
+    GameAlreadyStarted(GameFsmState),
     #[error("The game does not exist. GameId: '{0}'.")]
     GameDoesNotExist(String),
+    #[error("The game has recently been closed. GameId: '{0}'.")]
+    GameRecentlyClosed(String),
     #[error(
         "Cannot reject words in the current state. Actual state: '{0:?}', Expected state: '{1:?}'."
     )]
     InvalidStateForRejectingMatchedWords(GameFsmState, GameFsmState),
+    #[error(
+        "Cannot remove words in the current state. Actual state: '{0:?}', Expected state: '{1:?}'."
+    )]
+    InvalidStateForRemovingWords(GameFsmState, GameFsmState),
+    #[error("The kicked player does not exist. Nickname: '{0}'.")]
+    KickedPlayerDoesNotExist(String),
+    #[error("The merged player does not exist. Nickname: '{0}'.")]
+    MergedPlayerDoesNotExist(String),
+    #[error("The new host does not exist or is not connected. Nickname: '{0}'.")]
+    NewHostIsNotAvailable(String),
+    #[error("The nickname must have between 1 and 20 characters and no control characters. Nickname: '{0}'.")]
+    InvalidNickname(String),
+    #[error("The reconnection token does not belong to any player of the game.")]
+    InvalidReconnectionToken,
+    #[error("The rejoin ticket is not valid or has expired. Nickname: '{0}'.")]
+    InvalidRejoinTicket(String),
+    #[error(
+        "Cannot merge players in the current state. ActualState: '{0:?}', ExpectedState: '{1:?}'."
+    )]
+    InvalidStateForMergingPlayers(GameFsmState, GameFsmState),
+    #[error("The post game actions are only available at the end of the game. ActualState: '{0:?}', ExpectedState: '{1:?}'.")]
+    InvalidStateForPostGameAction(GameFsmState, GameFsmState),
+    #[error("Cannot skip the voting item in the current state. ActualState: '{0:?}', ExpectedState: '{1:?}'.")]
+    InvalidStateForSkippingVotingItem(GameFsmState, GameFsmState),
+    #[error("Invalid title length. ActualLength: '{0}', MaximumLength: '{1}'.")]
+    InvalidTitleLength(usize, usize),
     #[error("Invalid state for submitting Words. ActualState: '{0:?}', ExpectedState: '{1:?}'.")]
     InvalidStateForWordsSubmission(GameFsmState, GameFsmState),
     #[error(
@@ -24,10 +62,30 @@ pub enum DomainError {
     InvalidStateForVotingWordSubmission(GameFsmState, GameFsmState),
     #[error("Non host cannot reject matched words")]
     NonHostCannotRejectMatchedWords,
+    #[error("Non host cannot remove words")]
+    NonHostCannotRemoveWords,
+    #[error("A non host player cannot kick other players. Nickname: '{0}'.")]
+    NonHostPlayerCannotKick(String),
+    #[error("A non host player cannot merge players. Nickname: '{0}'.")]
+    NonHostPlayerCannotMergePlayers(String),
+    #[error("A non host player cannot end the session. Nickname: '{0}'.")]
+    NonHostPlayerCannotEndSession(String),
+    #[error("A non host player cannot skip the voting item. Nickname: '{0}'.")]
+    NonHostPlayerCannotSkipVotingItem(String),
+    #[error("A non host player cannot transfer the host. Nickname: '{0}'.")]
+    NonHostPlayerCannotTransferHost(String),
     #[error("Not enough players to start the game. ActualPlayers: '{0}', MinimumPlayers: '{1}'.")]
     NotEnoughPlayers(usize, usize),
+    #[error("A player cannot submit less than the minimum amount of words. Nickname: '{nickname}', ActualWords: '{count}', MinimumWords: '{min}'.")]
+    NotEnoughWords {
+        nickname: String,
+        count: usize,
+        min: usize,
+    },
     #[error("Not enough rounds to start the game. ActualRounds: '{0}', MinimumRounds: '{1}'.")]
     NotEnoughRounds(usize, usize),
+    #[error("Every connected player has to vote before accepting the voting words. MissingVotes: '{0}'.")]
+    NotEveryPlayerHasVoted(usize),
     #[error("A non host player cannot continue the game to the next round. Nickname: '{0}'.")]
     NonHostPlayerCannotContinueToNextRound(String),
     #[error(
@@ -40,18 +98,28 @@ pub enum DomainError {
         "A non host player cannot continue the game to the next voting item. Nickname: '{0}'."
     )]
     NonHostPlayerCannotStartGame(String),
+    #[error("A non host player cannot set the title of the game. Nickname: '{0}'.")]
+    NonHostPlayerCannotSetTitle(String),
     #[error("A player with the same nickname already exists. Nickname: '{0}'.")]
     PlayerAlreadyExists(String),
+    #[error("The player was kicked from the game. Nickname: '{0}'.")]
+    PlayerWasKicked(String),
     #[error(
         "A player cannot submit a non-existing or used word as a Voting Word. Nickname: '{0}'."
     )]
     PlayerCannotSubmitNonExistingOrUsedVotingWord(String),
     #[error("A player cannot submit a Voting Word when the current Voting Item is none. Nickname: '{0}'.")]
     PlayerCannotSubmitVotingWordWhenVotingItemIsNone(String),
+    #[error("The rejoining player is not part of the game. Nickname: '{0}'.")]
+    RejoiningPlayerDoesNotExist(String),
     #[error("The rejected player does not exist.")]
     RejectedMatchedPlayerDoesNotExist,
     #[error("The rejected matched word does not exist.")]
     RejectedMatchedWordDoesNotExist,
+    #[error("The removed word does not exist.")]
+    RemovedWordDoesNotExist,
+    #[error("The removed word has already been used.")]
+    RemovedWordIsAlreadyUsed,
     #[error(
         "A player cannot submit Words with repeated words. Nickname: '{nickname}', RepeatedWords: '{}'.", .repeated_words.join(",")
     )]
@@ -61,6 +129,22 @@ pub enum DomainError {
     },
     #[error("Cannot reject a word that was not previously picked by the player during matching")]
     RejectedMatchedWordWasNotPickedByPlayer,
+    #[error("A player cannot submit more than the maximum amount of words. Nickname: '{nickname}', ActualWords: '{count}', MaximumWords: '{max}'.")]
+    TooManyWords {
+        nickname: String,
+        count: usize,
+        max: usize,
+    },
+    #[error("A player cannot change their Voting Word for the current Voting Item anymore. Nickname: '{0}'.")]
+    TooManyVoteChanges(String),
+    #[error("Too many rounds to start the game. ActualRounds: '{0}', MaximumRounds: '{1}'.")]
+    TooManyRounds(usize, usize),
+    #[error("A player cannot submit a word longer than the maximum length. Nickname: '{nickname}', Word: '{word}'.")]
+    WordTooLong { nickname: String, word: String },
+    #[error("The words of this round have already been submitted. Nickname: '{0}'.")]
+    WordsAlreadySubmitted(String),
+    #[error("The voting item has been shown for too little time to accept the voting words. MinimumSeconds: '{0}'.")]
+    VotingItemTooSoon(u64),
     #[error("The player of the current Voting Item cannot submit a Voting Word. Nickname: '{0}'.")]
     VotingItemPlayerCannotSubmitVotingWord(String),
 }
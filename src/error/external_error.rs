@@ -4,6 +4,14 @@ use thiserror::Error;
 pub enum ExternalError {
     #[error("Received a bad formatted message. Message: '{1}', Error: '{0}'.")]
     UnprocessableWebsocketMessage(String, String),
+    #[error("The GameFactory did not respond in time. Command: '{0}'.")]
+    GameFactoryTimeout(String),
+    #[error("Too many games are being created. MaximumPerSecond: '{0}'.")]
+    GameCreationThrottled(u32),
+    #[error("The server is shutting down, no new games can be created.")]
+    ServerShuttingDown,
+    #[error("Received a message of an unknown kind. Kind: '{0}'.")]
+    UnknownMessageKind(String),
     #[error("The websocket with the player is closed. Reason: '{0}'.")]
     WebsocketClosed(String),
 }
@@ -1,39 +1,101 @@
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Display, Formatter};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::broadcast::error::SendError;
+
+use rand::distributions::{Alphanumeric, DistString};
 use tokio::sync::oneshot::Sender as OneshotSender;
 use tokio::sync::{
     broadcast, mpsc,
-    mpsc::{Receiver, Sender},
+    mpsc::{Receiver, Sender, WeakSender},
 };
+use tokio::task::AbortHandle;
 use tokio::time;
 
-use crate::config::GameSettings;
+use crate::config::{GameSettings, ScoreEventVerbosity};
+use crate::error::domain_error::DomainError;
 use crate::error::Error;
 use crate::game::actor_client::GameClient;
 use crate::game::game_fsm::GameFsmState;
-use crate::game::Game;
+use crate::game::recording::{RecordedCommand, SessionRecorder};
+use crate::game::{Game, ScoreBreakdownEntry};
 use crate::game_factory::actor_client::GameFactoryClient;
-use crate::metrics::ACTIVE_GAMES;
+use crate::metrics::{
+    record_game_state_change, ACTIVE_GAMES, GAMES_BY_STATE, ROUNDS_PLAYED, ROUND_DURATION_SECONDS,
+    WORDS_SUBMITTED,
+};
 use crate::player::Player;
-use crate::round::Round;
+use crate::round::{RemainingVotingItem, Round, VotingItemResolution};
+
+const INACTIVE_REASON: &str = "inactive";
+pub const KICKED_REASON: &str = "kicked";
+const MERGED_REASON: &str = "merged";
+
+#[derive(Debug, Clone)]
+pub struct ChatHistoryMessage {
+    pub sender: String,
+    pub content: String,
+}
+
+// The state of the game for those who are not subscribed to the game broadcast
+#[derive(Debug)]
+pub struct PublicGameState {
+    pub title: String,
+    pub state: GameFsmState,
+    pub players: Vec<Player>,
+    pub rounds: Vec<Round>,
+    pub amount_of_rounds: Option<u8>,
+    pub player_total_scores: HashMap<String, isize>,
+}
+
+struct PausedTimers {
+    word_reveal: Option<Duration>,
+    word_submission: Option<Duration>,
+}
 
 pub struct GameActor {
     game: Game,
+    // Weak, so that the actor holding it does not keep its own channel open
+    game_tx: WeakSender<GameCommand>,
     game_rx: Receiver<GameCommand>,
     broadcast_tx: broadcast::Sender<GameWideEvent>,
     game_factory: GameFactoryClient,
     inactivity_timeout: Duration,
+    mid_game_empty_grace: Duration,
+    recorder: Option<SessionRecorder>,
+    // Round number and nickname of the last player told they are next, so that they are told once
+    announced_next_voting_player: Option<(usize, String)>,
+    // The pending timers of the round and when they fire
+    word_reveal_timer: Option<(AbortHandle, time::Instant)>,
+    word_submission_timer: Option<(AbortHandle, time::Instant)>,
+    // Time left on each timer of the round, while they are frozen because the game is empty
+    paused_timers: Option<PausedTimers>,
+    // Ticket to the nickname it was issued for and when it expires
+    rejoin_tickets: HashMap<String, (String, time::Instant)>,
+    // Round number of the last voting pause that was scheduled, so that it is scheduled once
+    scheduled_voting_pause: Option<usize>,
+    // The latest chat messages, oldest first, replayed to the players that join
+    chat_history: VecDeque<ChatHistoryMessage>,
+    // Players that are typing a chat message, so that they stop typing when they disconnect
+    typing_players: HashSet<String>,
+    // The state the game is counted in by GAMES_BY_STATE
+    reported_state: GameFsmState,
 }
 
 impl GameActor {
     pub fn spawn(
         id: &str,
+        title: Option<String>,
         settings: GameSettings,
         words: Vec<String>,
+        blocklist: Arc<HashSet<String>>,
         game_factory: GameFactoryClient,
     ) -> GameClient {
-        let game = Game::new(id, words);
+        let game = Game::new(id, title, settings.clone(), words, blocklist);
+        let recorder = settings
+            .record_sessions_dir
+            .as_ref()
+            .map(|directory| SessionRecorder::create(directory, &game));
         let (game_tx, game_rx): (Sender<GameCommand>, Receiver<GameCommand>) = mpsc::channel(128);
         let (broadcast_tx, _): (
             broadcast::Sender<GameWideEvent>,
@@ -43,10 +105,22 @@ impl GameActor {
         tokio::spawn(
             GameActor {
                 game,
+                game_tx: game_tx.downgrade(),
                 game_rx,
                 broadcast_tx,
                 game_factory,
                 inactivity_timeout: settings.inactivity_timeout(),
+                mid_game_empty_grace: settings.mid_game_empty_grace(),
+                recorder,
+                announced_next_voting_player: None,
+                word_reveal_timer: None,
+                word_submission_timer: None,
+                paused_timers: None,
+                rejoin_tickets: HashMap::default(),
+                scheduled_voting_pause: None,
+                chat_history: VecDeque::default(),
+                typing_players: HashSet::default(),
+                reported_state: GameFsmState::Lobby,
             }
             .start(),
         );
@@ -54,16 +128,28 @@ impl GameActor {
         GameClient { game_tx }
     }
 
+    // A game that empties mid-game gets its own grace period, so that a group whose connections
+    // flicker does not lose its progress
+    fn current_inactivity_timeout(&self) -> Duration {
+        if self.game.state() != &GameFsmState::Lobby && self.game.all_players_are_disconnected() {
+            self.mid_game_empty_grace
+        } else {
+            self.inactivity_timeout
+        }
+    }
+
     async fn start(mut self) {
         ACTIVE_GAMES.inc();
+        record_game_state_change(&GAMES_BY_STATE, None, Some(&self.reported_state));
 
         loop {
-            match time::timeout(self.inactivity_timeout, self.game_rx.recv()).await {
+            let inactivity_timeout = self.current_inactivity_timeout();
+            match time::timeout(inactivity_timeout, self.game_rx.recv()).await {
                 Err(_) => {
                     if self.game.all_players_are_disconnected() {
                         log::info!(
                             "No activity detected in game {} after {} seconds. Stopping game actor.",
-                            self.game.id(), self.inactivity_timeout.as_secs()
+                            self.game.id(), inactivity_timeout.as_secs()
                         );
                         break;
                     }
@@ -73,21 +159,71 @@ impl GameActor {
                     break;
                 }
                 Ok(Some(command)) => {
+                    let command = match command {
+                        // Resolved before being recorded, the players of a replay have other tokens
+                        GameCommand::AddPlayer {
+                            nickname,
+                            reconnection_token,
+                            rejoin_ticket,
+                            response_tx,
+                        } => match self.resolve_joining_nickname(
+                            nickname.clone(),
+                            reconnection_token.as_deref(),
+                            rejoin_ticket.as_deref(),
+                        ) {
+                            Ok(nickname) => GameCommand::AddPlayer {
+                                nickname,
+                                reconnection_token: None,
+                                rejoin_ticket: None,
+                                response_tx,
+                            },
+                            Err(error) => {
+                                if let Err(event) = response_tx.send(GameEvent::Error { error }) {
+                                    log::error!("Sent GameEvent::Error to Player {nickname} but the response channel is closed. GameEvent: '{event}'.");
+                                }
+                                continue;
+                            }
+                        },
+                        command => command,
+                    };
+                    if let Some(recorder) = &self.recorder {
+                        if let Some(recorded_command) = command.to_recorded_command() {
+                            recorder.record(recorded_command);
+                        }
+                    }
                     let response = match command {
                         GameCommand::AddPlayer {
                             nickname,
                             response_tx,
+                            ..
                         } => {
-                            let result =
-                                self.game
-                                    .add_player(&nickname)
-                                    .map(|_| GameEvent::PlayerAdded {
-                                        broadcast_rx: self.broadcast_tx.subscribe(),
-                                    });
+                            let is_reconnection = self
+                                .game
+                                .players()
+                                .iter()
+                                .any(|player| player.has_nickname(&nickname));
+                            let result = self.game.add_player(&nickname).map(|player| {
+                                self.send_player_joined(
+                                    player.nickname.to_string(),
+                                    is_reconnection,
+                                );
+                                GameEvent::PlayerAdded {
+                                    player,
+                                    chat_history: self.chat_history.iter().cloned().collect(),
+                                    reconnect_state: is_reconnection.then(|| self.game_state()),
+                                    broadcast_rx: self.broadcast_tx.subscribe(),
+                                }
+                            });
                             Some((result, nickname, response_tx))
                         }
                         GameCommand::DisconnectPlayer { nickname } => {
-                            let _ = self.game.disconnect_player(&nickname);
+                            if let Ok(Some(resolution)) = self
+                                .game
+                                .disconnect_player(&nickname, time::Instant::now().into_std())
+                            {
+                                self.send_voting_item_resolution(resolution);
+                            }
+                            self.send_player_typing(&nickname, false);
                             None
                         }
                         GameCommand::StartGame {
@@ -95,22 +231,161 @@ impl GameActor {
                             response_tx,
                             amount_of_rounds,
                         } => {
-                            let result = self
-                                .game
-                                .start_game(&nickname, amount_of_rounds)
-                                .map(|_| GameEvent::Ok);
+                            let result =
+                                self.game.start_game(&nickname, amount_of_rounds).map(|_| {
+                                    self.on_round_started();
+                                    GameEvent::Ok
+                                });
                             Some((result, nickname, response_tx))
                         }
-                        GameCommand::AddChatMessage { sender, content } => {
-                            if let Err(error) = self
-                                .broadcast_tx
-                                .send(GameWideEvent::ChatMessage { sender, content })
-                            {
+                        GameCommand::AddChatMessage {
+                            sender,
+                            content,
+                            response_tx,
+                        } => {
+                            let event = match self.game.sanitize_chat_message(&content) {
+                                // Empty messages are acknowledged but never broadcast, so
+                                // clients do not have to handle an error for them
+                                Ok(None) => {
+                                    log::debug!("Dropped empty chat message from Player {sender}.");
+                                    GameEvent::Ok
+                                }
+                                Ok(Some(content)) => {
+                                    self.add_to_chat_history(&sender, &content);
+                                    if let Err(error) =
+                                        self.broadcast_tx.send(GameWideEvent::ChatMessage {
+                                            sender: sender.to_string(),
+                                            content,
+                                        })
+                                    {
+                                        log::error!(
+                                            "Error when sending GameWideEvent::ChatMessage broadcast: {}.",
+                                            error
+                                        );
+                                    }
+                                    GameEvent::Ok
+                                }
+                                Err(error) => GameEvent::Error { error },
+                            };
+                            if let Err(event) = response_tx.send(event) {
+                                log::error!("Sent GameEvent to Player {sender} but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::SetPlayerTyping {
+                            nickname,
+                            is_typing,
+                            response_tx,
+                        } => {
+                            self.send_player_typing(&nickname, is_typing);
+                            if let Err(event) = response_tx.send(GameEvent::Ok) {
+                                log::error!("Sent GameEvent to Player {nickname} but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::RevealWord { round } => {
+                            if let Some(masked) = self.game.reveal_next_word_stage(round) {
+                                if let Err(error) =
+                                    self.broadcast_tx.send(GameWideEvent::WordReveal { masked })
+                                {
+                                    log::error!(
+                                        "Error when sending GameWideEvent::WordReveal broadcast: {}.",
+                                        error
+                                    );
+                                }
+                                self.schedule_word_reveal(
+                                    self.game.settings().word_reveal_interval(),
+                                );
+                            }
+                            continue;
+                        }
+                        GameCommand::EndWordSubmission { round } => {
+                            if let Err(error) = self.game.end_word_submission(round) {
+                                log::error!("Could not end the word submission. GameId: '{}', Error: '{error}'.", self.game.id());
+                            }
+                            None
+                        }
+                        GameCommand::StartVoting { round } => {
+                            if let Err(error) = self.game.start_voting(round) {
                                 log::error!(
-                                    "Error when sending GameWideEvent::ChatMessage broadcast: {}.",
-                                    error
+                                    "Could not start the voting. GameId: '{}', Error: '{error}'.",
+                                    self.game.id()
                                 );
                             }
+                            None
+                        }
+                        GameCommand::GetLastWords {
+                            nickname,
+                            response_tx,
+                        } => {
+                            if let Err(event) = response_tx.send(GameEvent::LastWords {
+                                words: self.game.last_words(&nickname),
+                            }) {
+                                log::error!("Sent GameEvent::LastWords but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::GetRemainingVotingItems {
+                            nickname,
+                            response_tx,
+                        } => {
+                            if let Err(event) = response_tx.send(GameEvent::RemainingVotingItems {
+                                items: self.game.remaining_voting_items(&nickname),
+                            }) {
+                                log::error!("Sent GameEvent::RemainingVotingItems but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::GetScoreBreakdown { response_tx } => {
+                            if let Err(event) = response_tx.send(GameEvent::ScoreBreakdown {
+                                breakdown: self.game.score_breakdown(),
+                            }) {
+                                log::error!("Sent GameEvent::ScoreBreakdown but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::GetRoundHistory {
+                            offset,
+                            limit,
+                            response_tx,
+                        } => {
+                            if let Err(event) = response_tx.send(GameEvent::RoundHistory {
+                                rounds: self.game.round_history_page(offset, limit).to_vec(),
+                                total: self.game.round_history().len(),
+                            }) {
+                                log::error!("Sent GameEvent::RoundHistory but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::GetGameState { response_tx } => {
+                            if let Err(event) = response_tx.send(GameEvent::GameState {
+                                game_state: self.public_game_state(),
+                            }) {
+                                log::error!("Sent GameEvent::GameState but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::IssueRejoinTicket {
+                            nickname,
+                            reconnection_token,
+                            response_tx,
+                        } => {
+                            let event =
+                                match self.issue_rejoin_ticket(&nickname, &reconnection_token) {
+                                    Ok(ticket) => GameEvent::RejoinTicket { ticket },
+                                    Err(error) => GameEvent::Error { error },
+                                };
+                            if let Err(event) = response_tx.send(event) {
+                                log::error!("Sent GameEvent to Player {nickname} but the response channel is closed. GameEvent: '{event}'.");
+                            }
+                            continue;
+                        }
+                        GameCommand::GetPlayers { response_tx } => {
+                            if let Err(event) = response_tx.send(GameEvent::Players {
+                                players: self.game.players().to_vec(),
+                            }) {
+                                log::error!("Sent GameEvent::Players but the response channel is closed. GameEvent: '{event}'.");
+                            }
                             continue;
                         }
                         GameCommand::AddPlayerWords {
@@ -118,10 +393,14 @@ impl GameActor {
                             words,
                             response_tx,
                         } => {
-                            let result = self
-                                .game
-                                .add_player_words(&nickname, words)
-                                .map(|_| GameEvent::Ok);
+                            let amount_of_words = words.len() as u64;
+                            let result =
+                                self.game
+                                    .add_player_words(&nickname, words)
+                                    .map(|accepted| {
+                                        WORDS_SUBMITTED.inc_by(amount_of_words);
+                                        GameEvent::WordsAccepted { accepted }
+                                    });
                             Some((result, nickname, response_tx))
                         }
                         GameCommand::SetPlayerVotingWord {
@@ -135,6 +414,16 @@ impl GameActor {
                                 .map(|_| GameEvent::Ok);
                             Some((result, nickname, response_tx))
                         }
+                        GameCommand::ClearPlayerVotingWord {
+                            nickname,
+                            response_tx,
+                        } => {
+                            let result = self
+                                .game
+                                .clear_player_voting_word(&nickname)
+                                .map(|_| GameEvent::Ok);
+                            Some((result, nickname, response_tx))
+                        }
                         GameCommand::RejectPlayerMatchedWord {
                             nickname,
                             rejected_player,
@@ -147,26 +436,104 @@ impl GameActor {
                                 .map(|_| GameEvent::Ok);
                             Some((result, nickname, response_tx))
                         }
-                        GameCommand::AcceptPlayersVotingWords {
+                        GameCommand::RemoveWord {
                             nickname,
+                            player,
+                            word,
                             response_tx,
                         } => {
                             let result = self
                                 .game
-                                .accept_players_voting_words(&nickname)
+                                .remove_word(&nickname, &player, &word)
                                 .map(|_| GameEvent::Ok);
                             Some((result, nickname, response_tx))
                         }
-                        GameCommand::ContinueToNextRound {
+                        GameCommand::KickPlayer {
+                            nickname,
+                            kicked_player,
+                            response_tx,
+                        } => {
+                            let result = self.game.kick_player(&nickname, &kicked_player).map(
+                                |kicked_player| {
+                                    self.send_player_left(kicked_player, KICKED_REASON);
+                                    GameEvent::Ok
+                                },
+                            );
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::MergePlayers {
+                            nickname,
+                            source,
+                            target,
+                            response_tx,
+                        } => {
+                            let result = self.game.merge_players(&nickname, &source, &target).map(
+                                |source| {
+                                    self.send_player_left(source, MERGED_REASON);
+                                    GameEvent::Ok
+                                },
+                            );
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::TransferHost {
+                            nickname,
+                            new_host,
+                            response_tx,
+                        } => {
+                            let result = self
+                                .game
+                                .transfer_host(&nickname, &new_host)
+                                .map(|_| GameEvent::Ok);
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::AcceptPlayersVotingWords {
+                            nickname,
+                            response_tx,
+                        } => {
+                            let result = self
+                                .game
+                                .accept_players_voting_words(
+                                    &nickname,
+                                    time::Instant::now().into_std(),
+                                )
+                                .map(|resolution| {
+                                    if let Some(resolution) = resolution {
+                                        self.send_voting_item_resolution(resolution);
+                                    }
+                                    GameEvent::Ok
+                                });
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::SkipVotingItem {
+                            nickname,
+                            response_tx,
+                        } => {
+                            let result =
+                                self.game.skip_voting_item(&nickname).map(|_| GameEvent::Ok);
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::SetTitle {
                             nickname,
+                            title,
                             response_tx,
                         } => {
                             let result = self
                                 .game
-                                .continue_to_next_round(&nickname)
+                                .set_title(&nickname, &title)
                                 .map(|_| GameEvent::Ok);
                             Some((result, nickname, response_tx))
                         }
+                        GameCommand::ContinueToNextRound {
+                            nickname,
+                            response_tx,
+                        } => {
+                            let result = self.game.continue_to_next_round(&nickname).map(|_| {
+                                self.on_round_started();
+                                self.on_game_ended();
+                                GameEvent::Ok
+                            });
+                            Some((result, nickname, response_tx))
+                        }
                         GameCommand::PlayAgain {
                             nickname,
                             response_tx,
@@ -174,6 +541,36 @@ impl GameActor {
                             let result = self.game.play_again(&nickname).map(|_| GameEvent::Ok);
                             Some((result, nickname, response_tx))
                         }
+                        GameCommand::PlayAgainSameSettings {
+                            nickname,
+                            response_tx,
+                        } => {
+                            let result = self.game.play_again_same_settings(&nickname).map(|_| {
+                                self.on_round_started();
+                                GameEvent::Ok
+                            });
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::EndSession {
+                            nickname,
+                            response_tx,
+                        } => {
+                            let result = self.game.end_session(&nickname).map(|_| GameEvent::Ok);
+                            Some((result, nickname, response_tx))
+                        }
+                        GameCommand::ShutDown { response_tx } => {
+                            log::info!(
+                                "The server is shutting down. Stopping game actor {}.",
+                                self.game.id()
+                            );
+                            if let Err(error) =
+                                self.broadcast_tx.send(GameWideEvent::ServerShuttingDown)
+                            {
+                                log::error!("Error when sending GameWideEvent::ServerShuttingDown broadcast: {}.", error);
+                            }
+                            let _ = response_tx.send(GameEvent::Ok);
+                            break;
+                        }
                     };
                     if let Some((result, nickname, response_tx)) = response {
                         let event = match result {
@@ -182,25 +579,424 @@ impl GameActor {
                         };
                         if let Err(error) = response_tx.send(event) {
                             log::error!("Sent GameEvent to Player {nickname} but the response channel is closed. Removing the Player. Error: '{error}'.");
-                            let _ = self.game.disconnect_player(&nickname);
+                            let _ = self
+                                .game
+                                .disconnect_player(&nickname, time::Instant::now().into_std());
                         }
                     }
-                    let _ = self.send_game_state();
+                    self.send_game_state();
+                    self.schedule_voting_pause();
+                    self.announce_next_voting_player();
+                    self.cancel_round_timers();
+                    self.game.track_voting_item(time::Instant::now().into_std());
+                    self.pause_or_resume_round_timers();
+                    self.report_state();
+                    if self.game.is_session_ended() {
+                        log::info!(
+                            "The host ended the session of game {}. Stopping game actor.",
+                            self.game.id()
+                        );
+                        self.send_session_ended();
+                        break;
+                    }
                 }
             }
         }
 
+        record_game_state_change(&GAMES_BY_STATE, Some(&self.reported_state), None);
         self.stop_game().await;
         ACTIVE_GAMES.dec();
     }
 
-    fn send_game_state(&self) -> Result<usize, SendError<GameWideEvent>> {
-        self.broadcast_tx.send(GameWideEvent::GameState {
+    fn report_state(&mut self) {
+        let state = self.game.state().clone();
+        // A round waits in EndOfRound for the host, so it is never missed between two commands
+        if state == GameFsmState::EndOfRound && self.reported_state != GameFsmState::EndOfRound {
+            if let Some(round_started_at) = self.game.round_started_at() {
+                ROUND_DURATION_SECONDS.observe(round_started_at.elapsed().as_secs_f64());
+            }
+        }
+        record_game_state_change(&GAMES_BY_STATE, Some(&self.reported_state), Some(&state));
+        self.reported_state = state;
+    }
+
+    fn game_state(&self) -> GameWideEvent {
+        let PublicGameState {
+            title,
+            state,
+            players,
+            rounds,
+            amount_of_rounds,
+            player_total_scores,
+        } = self.public_game_state();
+        GameWideEvent::GameState {
+            title,
+            state,
+            players,
+            rounds,
+            amount_of_rounds,
+            player_total_scores,
+        }
+    }
+
+    fn public_game_state(&self) -> PublicGameState {
+        PublicGameState {
+            title: self.game.title().to_string(),
             state: self.game.state().clone(),
             players: self.game.players().to_vec(),
-            rounds: self.game.rounds().to_vec(),
+            rounds: self.game.rounds_in_state().to_vec(),
             amount_of_rounds: self.game.amount_of_rounds,
-        })
+            player_total_scores: self.game.player_total_scores(),
+        }
+    }
+
+    fn add_to_chat_history(&mut self, sender: &str, content: &str) {
+        let chat_history_size = self.game.settings().chat_history_size;
+        if chat_history_size == 0 {
+            return;
+        }
+        if self.chat_history.len() >= chat_history_size {
+            self.chat_history.pop_front();
+        }
+        self.chat_history.push_back(ChatHistoryMessage {
+            sender: sender.to_string(),
+            content: content.to_string(),
+        });
+    }
+
+    fn send_game_state(&self) {
+        // Sending only fails when there are no players listening, so there's no one to tell
+        let _ = self.broadcast_tx.send(self.game_state());
+    }
+
+    // Every scored word first, then the voting item they resolved
+    fn send_voting_item_resolution(&self, resolution: VotingItemResolution) {
+        if self.game.settings().score_event_verbosity == ScoreEventVerbosity::StateOnly {
+            return;
+        }
+        for scored_word in resolution.scored_words {
+            if let Err(error) = self.broadcast_tx.send(GameWideEvent::WordScored {
+                owner: scored_word.nickname,
+                word: scored_word.word,
+                score: scored_word.score,
+            }) {
+                log::error!(
+                    "Error when sending GameWideEvent::WordScored broadcast: {}.",
+                    error
+                );
+            }
+        }
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::VotingItemResolved {
+            player_nickname: resolution.player_nickname,
+            word: resolution.word,
+            score: resolution.score,
+            matched_players: resolution.matched_players,
+        }) {
+            log::error!(
+                "Error when sending GameWideEvent::VotingItemResolved broadcast: {}.",
+                error
+            );
+        }
+    }
+
+    fn announce_next_voting_player(&mut self) {
+        if !self.game.settings().announce_next_voting_player {
+            return;
+        }
+        let next_voting_player = self
+            .game
+            .next_voting_player()
+            .map(|nickname| (self.game.rounds().len(), nickname));
+        if next_voting_player == self.announced_next_voting_player {
+            return;
+        }
+        self.announced_next_voting_player = next_voting_player.clone();
+        if let Some((_, nickname)) = next_voting_player {
+            if let Err(error) = self
+                .broadcast_tx
+                .send(GameWideEvent::NextVotingPlayer { nickname })
+            {
+                log::error!(
+                    "Error when sending GameWideEvent::NextVotingPlayer broadcast: {}.",
+                    error
+                );
+            }
+        }
+    }
+
+    fn on_round_started(&mut self) {
+        if self.game.state() != &GameFsmState::PlayersSubmittingWords {
+            return;
+        }
+        ROUNDS_PLAYED.inc();
+        self.send_kicked_players();
+        self.send_round_start_message();
+        self.schedule_word_reveal(self.game.settings().word_reveal_interval());
+        if let Some(deadline) = self.game.settings().word_submission_deadline() {
+            self.schedule_word_submission_deadline(deadline);
+        }
+    }
+
+    // The game only reaches EndOfGame through a successful ContinueToNextRound, so this is sent
+    // once per game end
+    fn on_game_ended(&self) {
+        if self.game.state() != &GameFsmState::EndOfGame {
+            return;
+        }
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::FinalScoreboard {
+            ranking: self.game.ranking(),
+        }) {
+            log::error!(
+                "Error when sending GameWideEvent::FinalScoreboard broadcast: {}.",
+                error
+            );
+        }
+    }
+
+    fn send_kicked_players(&mut self) {
+        for nickname in self.game.take_kicked_players() {
+            self.send_player_left(nickname, INACTIVE_REASON);
+        }
+    }
+
+    fn send_player_joined(&self, nickname: String, is_reconnection: bool) {
+        let event = if is_reconnection {
+            GameWideEvent::PlayerReconnected { nickname }
+        } else {
+            GameWideEvent::PlayerJoined { nickname }
+        };
+        if let Err(error) = self.broadcast_tx.send(event) {
+            log::error!(
+                "Error when sending GameWideEvent::PlayerJoined broadcast: {}.",
+                error
+            );
+        }
+    }
+
+    fn send_session_ended(&self) {
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::SessionEnded) {
+            log::error!(
+                "Error when sending GameWideEvent::SessionEnded broadcast: {}.",
+                error
+            );
+        }
+    }
+
+    fn send_player_left(&self, nickname: String, reason: &str) {
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::PlayerLeft {
+            nickname,
+            reason: reason.to_string(),
+        }) {
+            log::error!(
+                "Error when sending GameWideEvent::PlayerLeft broadcast: {}.",
+                error
+            );
+        }
+    }
+
+    // Only changes are broadcast, so repeated typing notifications are not sent to everyone again
+    fn send_player_typing(&mut self, nickname: &str, is_typing: bool) {
+        let changed = if is_typing {
+            self.typing_players.insert(nickname.to_string())
+        } else {
+            self.typing_players.remove(nickname)
+        };
+        if !changed {
+            return;
+        }
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::PlayerTyping {
+            nickname: nickname.to_string(),
+            is_typing,
+        }) {
+            log::error!(
+                "Error when sending GameWideEvent::PlayerTyping broadcast: {}.",
+                error
+            );
+        }
+    }
+
+    fn send_round_start_message(&self) {
+        if let Some(text) = self.game.round_start_message() {
+            if let Err(error) = self
+                .broadcast_tx
+                .send(GameWideEvent::SystemMessage { text })
+            {
+                log::error!(
+                    "Error when sending GameWideEvent::SystemMessage broadcast: {}.",
+                    error
+                );
+            }
+        }
+    }
+
+    // One stage at a time, so that the reveal can be frozen between stages
+    fn schedule_word_reveal(&mut self, delay: Duration) {
+        let has_stages_left = self
+            .game
+            .rounds()
+            .last()
+            .is_some_and(|round| round.has_word_reveal_stages_left());
+        if !has_stages_left {
+            return;
+        }
+        let round = self.game.rounds().len();
+        self.word_reveal_timer = Some(self.spawn_timer(delay, GameCommand::RevealWord { round }));
+    }
+
+    fn schedule_word_submission_deadline(&mut self, deadline: Duration) {
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::PhaseDeadline {
+            state: GameFsmState::PlayersSubmittingWords,
+            seconds_remaining: deadline.as_secs(),
+        }) {
+            log::error!(
+                "Error when sending GameWideEvent::PhaseDeadline broadcast: {}.",
+                error
+            );
+        }
+        let round = self.game.rounds().len();
+        self.word_submission_timer =
+            Some(self.spawn_timer(deadline, GameCommand::EndWordSubmission { round }));
+    }
+
+    fn schedule_voting_pause(&mut self) {
+        let round = self.game.rounds().len();
+        if self.game.state() != &GameFsmState::PreparingVoting
+            || self.scheduled_voting_pause == Some(round)
+        {
+            return;
+        }
+        let pause = self.game.settings().voting_pause();
+        if let Err(error) = self.broadcast_tx.send(GameWideEvent::PreparingVoting {
+            seconds_remaining: pause.as_secs(),
+        }) {
+            log::error!(
+                "Error when sending GameWideEvent::PreparingVoting broadcast: {}.",
+                error
+            );
+        }
+        self.scheduled_voting_pause = Some(round);
+        // Not aborted, starting the voting of a round that is no longer preparing it does nothing
+        self.spawn_timer(pause, GameCommand::StartVoting { round });
+    }
+
+    fn spawn_timer(&self, delay: Duration, command: GameCommand) -> (AbortHandle, time::Instant) {
+        let game_tx = self.game_tx.clone();
+        let timer = tokio::spawn(async move {
+            time::sleep(delay).await;
+            if let Some(game_tx) = game_tx.upgrade() {
+                let _ = game_tx.send(command).await;
+            }
+        });
+        (timer.abort_handle(), time::Instant::now() + delay)
+    }
+
+    // Once every player has submitted, the pending reveal and deadline have nothing left to do
+    fn cancel_round_timers(&mut self) {
+        if self.game.state() == &GameFsmState::PlayersSubmittingWords {
+            return;
+        }
+        for (timer, _) in [
+            self.word_reveal_timer.take(),
+            self.word_submission_timer.take(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            timer.abort();
+        }
+        self.paused_timers = None;
+    }
+
+    fn pause_or_resume_round_timers(&mut self) {
+        if !self.game.settings().pause_when_empty {
+            return;
+        }
+        let is_empty =
+            self.game.state() != &GameFsmState::Lobby && self.game.all_players_are_disconnected();
+        match (is_empty, self.paused_timers.take()) {
+            (true, None) => {
+                let now = time::Instant::now();
+                let pause = |timer: Option<(AbortHandle, time::Instant)>| {
+                    timer.map(|(timer, fires_at)| {
+                        timer.abort();
+                        fires_at.saturating_duration_since(now)
+                    })
+                };
+                self.paused_timers = Some(PausedTimers {
+                    word_reveal: pause(self.word_reveal_timer.take()),
+                    word_submission: pause(self.word_submission_timer.take()),
+                });
+            }
+            (false, Some(paused_timers)) => {
+                if let Some(delay) = paused_timers.word_reveal {
+                    self.schedule_word_reveal(delay);
+                }
+                if let Some(deadline) = paused_timers.word_submission {
+                    self.schedule_word_submission_deadline(deadline);
+                }
+            }
+            (_, paused_timers) => self.paused_timers = paused_timers,
+        }
+    }
+
+    fn issue_rejoin_ticket(
+        &mut self,
+        nickname: &str,
+        reconnection_token: &str,
+    ) -> Result<String, Error> {
+        self.game.validate_rejoin(nickname, reconnection_token)?;
+        let now = time::Instant::now();
+        self.rejoin_tickets
+            .retain(|_, (_, expires_at)| *expires_at > now);
+        let ticket = Alphanumeric.sample_string(&mut rand::thread_rng(), 24);
+        self.rejoin_tickets.insert(
+            ticket.clone(),
+            (
+                nickname.to_string(),
+                now + self.game.settings().rejoin_ticket_lifetime(),
+            ),
+        );
+        Ok(ticket)
+    }
+
+    // A disconnected player is only reclaimed with its reconnection token or with a rejoin ticket,
+    // a plain nickname can only join as a new player
+    fn resolve_joining_nickname(
+        &mut self,
+        nickname: String,
+        reconnection_token: Option<&str>,
+        rejoin_ticket: Option<&str>,
+    ) -> Result<String, Error> {
+        if let Some(reconnection_token) = reconnection_token {
+            return self.game.reconnection_token_nickname(reconnection_token);
+        }
+        match rejoin_ticket {
+            Some(ticket) => self.redeem_rejoin_ticket(&nickname, ticket)?,
+            None if self
+                .game
+                .players()
+                .iter()
+                .any(|player| player.has_nickname(&nickname) && !player.is_connected) =>
+            {
+                return Err(Error::Domain(DomainError::InvalidRejoinTicket(nickname)));
+            }
+            None => {}
+        }
+        Ok(nickname)
+    }
+
+    // A ticket can only be presented once, by the player it was issued for
+    fn redeem_rejoin_ticket(&mut self, nickname: &str, ticket: &str) -> Result<(), Error> {
+        match self.rejoin_tickets.remove(ticket) {
+            Some((ticket_nickname, expires_at))
+                if ticket_nickname == nickname && expires_at > time::Instant::now() =>
+            {
+                Ok(())
+            }
+            _ => Err(Error::Domain(DomainError::InvalidRejoinTicket(
+                nickname.to_string(),
+            ))),
+        }
     }
 
     async fn stop_game(self) {
@@ -208,12 +1004,19 @@ impl GameActor {
         if let Err(error) = self.game_factory.remove_game(game_id).await {
             log::error!("The GameFactory channel is closed, can't remove the Game. GameId: '{game_id}', Error: '{error}'.");
         }
+        if let Some(recorder) = self.recorder {
+            recorder.close().await;
+        }
     }
 }
 
 pub(crate) enum GameCommand {
     AddPlayer {
         nickname: String,
+        // Reclaims the player it was issued to, regardless of the nickname
+        reconnection_token: Option<String>,
+        // Issued by the rejoin endpoint, needed to reclaim a disconnected player by its nickname
+        rejoin_ticket: Option<String>,
         response_tx: OneshotSender<GameEvent>,
     },
     DisconnectPlayer {
@@ -227,6 +1030,48 @@ pub(crate) enum GameCommand {
     AddChatMessage {
         sender: String,
         content: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    SetPlayerTyping {
+        nickname: String,
+        is_typing: bool,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    GetPlayers {
+        response_tx: OneshotSender<GameEvent>,
+    },
+    IssueRejoinTicket {
+        nickname: String,
+        reconnection_token: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    GetGameState {
+        response_tx: OneshotSender<GameEvent>,
+    },
+    GetLastWords {
+        nickname: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    GetRemainingVotingItems {
+        nickname: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    GetScoreBreakdown {
+        response_tx: OneshotSender<GameEvent>,
+    },
+    GetRoundHistory {
+        offset: usize,
+        limit: Option<usize>,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    RevealWord {
+        round: usize,
+    },
+    EndWordSubmission {
+        round: usize,
+    },
+    StartVoting {
+        round: usize,
     },
     AddPlayerWords {
         nickname: String,
@@ -238,16 +1083,51 @@ pub(crate) enum GameCommand {
         word: Option<String>,
         response_tx: OneshotSender<GameEvent>,
     },
+    ClearPlayerVotingWord {
+        nickname: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
     RejectPlayerMatchedWord {
         nickname: String,
         rejected_player: String,
         rejected_word: String,
         response_tx: OneshotSender<GameEvent>,
     },
+    RemoveWord {
+        nickname: String,
+        player: String,
+        word: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    KickPlayer {
+        nickname: String,
+        kicked_player: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    MergePlayers {
+        nickname: String,
+        source: String,
+        target: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    TransferHost {
+        nickname: String,
+        new_host: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
     AcceptPlayersVotingWords {
         nickname: String,
         response_tx: OneshotSender<GameEvent>,
     },
+    SkipVotingItem {
+        nickname: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    SetTitle {
+        nickname: String,
+        title: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
     ContinueToNextRound {
         nickname: String,
         response_tx: OneshotSender<GameEvent>,
@@ -256,13 +1136,186 @@ pub(crate) enum GameCommand {
         nickname: String,
         response_tx: OneshotSender<GameEvent>,
     },
+    PlayAgainSameSettings {
+        nickname: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    EndSession {
+        nickname: String,
+        response_tx: OneshotSender<GameEvent>,
+    },
+    ShutDown {
+        response_tx: OneshotSender<GameEvent>,
+    },
+}
+
+impl GameCommand {
+    fn to_recorded_command(&self) -> Option<RecordedCommand> {
+        match self {
+            GameCommand::AddPlayer { nickname, .. } => Some(RecordedCommand::AddPlayer {
+                nickname: nickname.to_string(),
+            }),
+            GameCommand::DisconnectPlayer { nickname } => Some(RecordedCommand::DisconnectPlayer {
+                nickname: nickname.to_string(),
+            }),
+            GameCommand::StartGame {
+                nickname,
+                amount_of_rounds,
+                ..
+            } => Some(RecordedCommand::StartGame {
+                nickname: nickname.to_string(),
+                amount_of_rounds: *amount_of_rounds,
+            }),
+            GameCommand::AddPlayerWords {
+                nickname, words, ..
+            } => Some(RecordedCommand::AddPlayerWords {
+                nickname: nickname.to_string(),
+                words: words.clone(),
+            }),
+            GameCommand::SetPlayerVotingWord { nickname, word, .. } => {
+                Some(RecordedCommand::SetPlayerVotingWord {
+                    nickname: nickname.to_string(),
+                    word: word.clone(),
+                })
+            }
+            GameCommand::ClearPlayerVotingWord { nickname, .. } => {
+                Some(RecordedCommand::ClearPlayerVotingWord {
+                    nickname: nickname.to_string(),
+                })
+            }
+            GameCommand::RejectPlayerMatchedWord {
+                nickname,
+                rejected_player,
+                rejected_word,
+                ..
+            } => Some(RecordedCommand::RejectPlayerMatchedWord {
+                nickname: nickname.to_string(),
+                rejected_player: rejected_player.to_string(),
+                rejected_word: rejected_word.to_string(),
+            }),
+            GameCommand::RemoveWord {
+                nickname,
+                player,
+                word,
+                ..
+            } => Some(RecordedCommand::RemoveWord {
+                nickname: nickname.to_string(),
+                player: player.to_string(),
+                word: word.to_string(),
+            }),
+            GameCommand::KickPlayer {
+                nickname,
+                kicked_player,
+                ..
+            } => Some(RecordedCommand::KickPlayer {
+                nickname: nickname.to_string(),
+                kicked_player: kicked_player.to_string(),
+            }),
+            GameCommand::MergePlayers {
+                nickname,
+                source,
+                target,
+                ..
+            } => Some(RecordedCommand::MergePlayers {
+                nickname: nickname.to_string(),
+                source: source.to_string(),
+                target: target.to_string(),
+            }),
+            GameCommand::TransferHost {
+                nickname, new_host, ..
+            } => Some(RecordedCommand::TransferHost {
+                nickname: nickname.to_string(),
+                new_host: new_host.to_string(),
+            }),
+            GameCommand::AcceptPlayersVotingWords { nickname, .. } => {
+                Some(RecordedCommand::AcceptPlayersVotingWords {
+                    nickname: nickname.to_string(),
+                })
+            }
+            GameCommand::SkipVotingItem { nickname, .. } => Some(RecordedCommand::SkipVotingItem {
+                nickname: nickname.to_string(),
+            }),
+            GameCommand::SetTitle {
+                nickname, title, ..
+            } => Some(RecordedCommand::SetTitle {
+                nickname: nickname.to_string(),
+                title: title.to_string(),
+            }),
+            GameCommand::ContinueToNextRound { nickname, .. } => {
+                Some(RecordedCommand::ContinueToNextRound {
+                    nickname: nickname.to_string(),
+                })
+            }
+            GameCommand::PlayAgain { nickname, .. } => Some(RecordedCommand::PlayAgain {
+                nickname: nickname.to_string(),
+            }),
+            GameCommand::PlayAgainSameSettings { nickname, .. } => {
+                Some(RecordedCommand::PlayAgainSameSettings {
+                    nickname: nickname.to_string(),
+                })
+            }
+            GameCommand::EndSession { nickname, .. } => Some(RecordedCommand::EndSession {
+                nickname: nickname.to_string(),
+            }),
+            GameCommand::RevealWord { round } => {
+                Some(RecordedCommand::RevealWord { round: *round })
+            }
+            GameCommand::EndWordSubmission { round } => {
+                Some(RecordedCommand::EndWordSubmission { round: *round })
+            }
+            GameCommand::StartVoting { round } => {
+                Some(RecordedCommand::StartVoting { round: *round })
+            }
+            // These commands don't change the state of the game
+            GameCommand::AddChatMessage { .. }
+            | GameCommand::SetPlayerTyping { .. }
+            | GameCommand::GetPlayers { .. }
+            | GameCommand::IssueRejoinTicket { .. }
+            | GameCommand::GetGameState { .. }
+            | GameCommand::GetLastWords { .. }
+            | GameCommand::GetRemainingVotingItems { .. }
+            | GameCommand::GetScoreBreakdown { .. }
+            | GameCommand::GetRoundHistory { .. }
+            | GameCommand::ShutDown { .. } => None,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub(crate) enum GameEvent {
     PlayerAdded {
+        player: Player,
+        chat_history: Vec<ChatHistoryMessage>,
+        // Only for a player that was already part of the game, the state right after it reconnected
+        reconnect_state: Option<GameWideEvent>,
         broadcast_rx: broadcast::Receiver<GameWideEvent>,
     },
+    Players {
+        players: Vec<Player>,
+    },
+    LastWords {
+        words: Vec<String>,
+    },
+    RemainingVotingItems {
+        items: Vec<RemainingVotingItem>,
+    },
+    ScoreBreakdown {
+        breakdown: HashMap<String, Vec<ScoreBreakdownEntry>>,
+    },
+    RoundHistory {
+        rounds: Vec<Round>,
+        // Every round of the history, not only those of the page
+        total: usize,
+    },
+    GameState {
+        game_state: PublicGameState,
+    },
+    RejoinTicket {
+        ticket: String,
+    },
+    WordsAccepted {
+        accepted: Vec<String>,
+    },
     Ok,
     Error {
         error: Error,
@@ -276,6 +1329,16 @@ impl Display for GameEvent {
             "{}",
             match self {
                 GameEvent::PlayerAdded { .. } => "GameEvent::PlayerAdded".to_string(),
+                GameEvent::Players { .. } => "GameEvent::Players".to_string(),
+                GameEvent::LastWords { .. } => "GameEvent::LastWords".to_string(),
+                GameEvent::RemainingVotingItems { .. } => {
+                    "GameEvent::RemainingVotingItems".to_string()
+                }
+                GameEvent::ScoreBreakdown { .. } => "GameEvent::ScoreBreakdown".to_string(),
+                GameEvent::RoundHistory { .. } => "GameEvent::RoundHistory".to_string(),
+                GameEvent::GameState { .. } => "GameEvent::GameState".to_string(),
+                GameEvent::RejoinTicket { .. } => "GameEvent::RejoinTicket".to_string(),
+                GameEvent::WordsAccepted { .. } => "GameEvent::WordsAccepted".to_string(),
                 GameEvent::Ok => "GameEvent::Ok".to_string(),
                 GameEvent::Error { error } => format!("Error '{error}'").to_string(),
             }
@@ -286,13 +1349,64 @@ impl Display for GameEvent {
 #[derive(Clone, Debug)]
 pub enum GameWideEvent {
     GameState {
+        title: String,
         state: GameFsmState,
         players: Vec<Player>,
         rounds: Vec<Round>,
         amount_of_rounds: Option<u8>,
+        player_total_scores: HashMap<String, isize>,
     },
     ChatMessage {
         sender: String,
         content: String,
     },
+    PlayerTyping {
+        nickname: String,
+        is_typing: bool,
+    },
+    WordScored {
+        owner: String,
+        word: String,
+        score: isize,
+    },
+    VotingItemResolved {
+        player_nickname: String,
+        word: String,
+        score: isize,
+        matched_players: Vec<String>,
+    },
+    SystemMessage {
+        text: String,
+    },
+    WordReveal {
+        masked: String,
+    },
+    NextVotingPlayer {
+        nickname: String,
+    },
+    FinalScoreboard {
+        ranking: Vec<(String, isize)>,
+    },
+    PlayerJoined {
+        nickname: String,
+    },
+    // A player that was already part of the game is connected again
+    PlayerReconnected {
+        nickname: String,
+    },
+    PlayerLeft {
+        nickname: String,
+        reason: String,
+    },
+    PhaseDeadline {
+        state: GameFsmState,
+        seconds_remaining: u64,
+    },
+    PreparingVoting {
+        seconds_remaining: u64,
+    },
+    // The last event of the game, the players are disconnected after it
+    SessionEnded,
+    // Like SessionEnded, but every game gets it at once
+    ServerShuttingDown,
 }
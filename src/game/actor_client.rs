@@ -1,10 +1,25 @@
+use std::collections::HashMap;
+
 use tokio::sync::broadcast;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::oneshot::error::RecvError;
 use tokio::sync::oneshot::{self, Receiver as OneshotReceiver, Sender as OneshotSender};
 
 use crate::error::Error;
-use crate::game::actor::{GameCommand, GameEvent, GameWideEvent};
+use crate::game::actor::{
+    ChatHistoryMessage, GameCommand, GameEvent, GameWideEvent, PublicGameState,
+};
+use crate::game::ScoreBreakdownEntry;
+use crate::player::Player;
+use crate::round::{RemainingVotingItem, Round};
+
+// The player as the game knows it, with what it needs to catch up on the game
+pub struct JoinedPlayer {
+    pub player: Player,
+    pub chat_history: Vec<ChatHistoryMessage>,
+    pub reconnect_state: Option<GameWideEvent>,
+    pub game_wide_event_receiver: GameWideEventReceiver,
+}
 
 #[derive(Clone, Debug)]
 pub struct GameClient {
@@ -12,7 +27,12 @@ pub struct GameClient {
 }
 
 impl GameClient {
-    pub async fn add_player(&self, nickname: &str) -> Result<GameWideEventReceiver, Error> {
+    pub async fn add_player(
+        &self,
+        nickname: &str,
+        reconnection_token: Option<String>,
+        rejoin_ticket: Option<String>,
+    ) -> Result<JoinedPlayer, Error> {
         let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
 
         // An error can be returned at this point if:
@@ -22,6 +42,8 @@ impl GameClient {
         self.send_command(
             GameCommand::AddPlayer {
                 nickname: nickname.to_string(),
+                reconnection_token,
+                rejoin_ticket,
                 response_tx: tx,
             },
             "GameCommand::AddPlayer",
@@ -29,9 +51,17 @@ impl GameClient {
         .await?;
 
         match rx.await {
-            Ok(GameEvent::PlayerAdded { broadcast_rx }) => {
-                Ok(GameWideEventReceiver { broadcast_rx })
-            }
+            Ok(GameEvent::PlayerAdded {
+                player,
+                chat_history,
+                reconnect_state,
+                broadcast_rx,
+            }) => Ok(JoinedPlayer {
+                player,
+                chat_history,
+                reconnect_state,
+                game_wide_event_receiver: GameWideEventReceiver { broadcast_rx },
+            }),
             error => Err(GameClient::handle_event_error(error)),
         }
     }
@@ -66,17 +96,180 @@ impl GameClient {
     }
 
     pub async fn send_chat_message(&self, sender: &str, content: &str) -> Result<(), Error> {
-        self
-            .game_tx
-            .send(GameCommand::AddChatMessage {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::AddChatMessage {
                 sender: sender.to_string(),
                 content: content.to_string(),
-            })
-            .await
-            .map_err(|error| Error::log_and_create_internal(&format!("Tried to send GameCommand::AddChatMessage but GameActor is not listening. Error: {error}.")))
+                response_tx: tx,
+            },
+            "GameCommand::AddChatMessage",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn set_player_typing(&self, nickname: &str, is_typing: bool) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::SetPlayerTyping {
+                nickname: nickname.to_string(),
+                is_typing,
+                response_tx: tx,
+            },
+            "GameCommand::SetPlayerTyping",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
     }
 
-    pub async fn add_player_words(&self, nickname: &str, words: Vec<String>) -> Result<(), Error> {
+    pub async fn get_players(&self) -> Result<Vec<Player>, Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::GetPlayers { response_tx: tx },
+            "GameCommand::GetPlayers",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Players { players }) => Ok(players),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn issue_rejoin_ticket(
+        &self,
+        nickname: &str,
+        reconnection_token: String,
+    ) -> Result<String, Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::IssueRejoinTicket {
+                nickname: nickname.to_string(),
+                reconnection_token,
+                response_tx: tx,
+            },
+            "GameCommand::IssueRejoinTicket",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::RejoinTicket { ticket }) => Ok(ticket),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn get_game_state(&self) -> Result<PublicGameState, Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::GetGameState { response_tx: tx },
+            "GameCommand::GetGameState",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::GameState { game_state }) => Ok(game_state),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn get_last_words(&self, nickname: &str) -> Result<Vec<String>, Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::GetLastWords {
+                nickname: nickname.to_string(),
+                response_tx: tx,
+            },
+            "GameCommand::GetLastWords",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::LastWords { words }) => Ok(words),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn get_remaining_voting_items(
+        &self,
+        nickname: &str,
+    ) -> Result<Vec<RemainingVotingItem>, Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::GetRemainingVotingItems {
+                nickname: nickname.to_string(),
+                response_tx: tx,
+            },
+            "GameCommand::GetRemainingVotingItems",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::RemainingVotingItems { items }) => Ok(items),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn get_score_breakdown(
+        &self,
+    ) -> Result<HashMap<String, Vec<ScoreBreakdownEntry>>, Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::GetScoreBreakdown { response_tx: tx },
+            "GameCommand::GetScoreBreakdown",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::ScoreBreakdown { breakdown }) => Ok(breakdown),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn get_round_history(
+        &self,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<(Vec<Round>, usize), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::GetRoundHistory {
+                offset,
+                limit,
+                response_tx: tx,
+            },
+            "GameCommand::GetRoundHistory",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::RoundHistory { rounds, total }) => Ok((rounds, total)),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn add_player_words(
+        &self,
+        nickname: &str,
+        words: Vec<String>,
+    ) -> Result<Vec<String>, Error> {
         let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
 
         self.send_command(
@@ -90,7 +283,7 @@ impl GameClient {
         .await?;
 
         match rx.await {
-            Ok(GameEvent::Ok) => Ok(()),
+            Ok(GameEvent::WordsAccepted { accepted }) => Ok(accepted),
             error => Err(GameClient::handle_event_error(error)),
         }
     }
@@ -118,6 +311,24 @@ impl GameClient {
         }
     }
 
+    pub async fn clear_player_voting_word(&self, nickname: &str) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::ClearPlayerVotingWord {
+                nickname: nickname.to_string(),
+                response_tx: tx,
+            },
+            "GameCommand::ClearPlayerVotingWord",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
     pub async fn accept_players_voting_words(&self, nickname: &str) -> Result<(), Error> {
         let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
 
@@ -136,6 +347,43 @@ impl GameClient {
         }
     }
 
+    pub async fn skip_voting_item(&self, nickname: &str) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::SkipVotingItem {
+                nickname: nickname.to_string(),
+                response_tx: tx,
+            },
+            "GameCommand::SkipVotingItem",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn set_title(&self, nickname: &str, title: String) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::SetTitle {
+                nickname: nickname.to_string(),
+                title,
+                response_tx: tx,
+            },
+            "GameCommand::SetTitle",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
     pub async fn continue_to_next_round(&self, nickname: &str) -> Result<(), Error> {
         let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
 
@@ -172,6 +420,57 @@ impl GameClient {
         }
     }
 
+    pub async fn play_again_same_settings(&self, nickname: &str) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::PlayAgainSameSettings {
+                nickname: nickname.to_string(),
+                response_tx: tx,
+            },
+            "GameCommand::PlayAgainSameSettings",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn end_session(&self, nickname: &str) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::EndSession {
+                nickname: nickname.to_string(),
+                response_tx: tx,
+            },
+            "GameCommand::EndSession",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn shut_down(&self) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::ShutDown { response_tx: tx },
+            "GameCommand::ShutDown",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
     async fn send_command(&self, command: GameCommand, command_name: &str) -> Result<(), Error> {
         self.game_tx.send(command).await.map_err(|error| {
             Error::log_and_create_internal(&format!("The Game channel is closed, cloud not send command '{command_name}'. Error: '{error}'"))
@@ -214,6 +513,94 @@ impl GameClient {
             error => Err(GameClient::handle_event_error(error)),
         }
     }
+
+    pub async fn remove_word(
+        &self,
+        nickname: &str,
+        player: String,
+        word: String,
+    ) -> Result<(), Error> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::RemoveWord {
+                nickname: nickname.to_string(),
+                player,
+                word,
+                response_tx: tx,
+            },
+            "GameCommand::RemoveWord",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn kick_player(&self, nickname: &str, kicked_player: String) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::KickPlayer {
+                nickname: nickname.to_string(),
+                kicked_player,
+                response_tx: tx,
+            },
+            "GameCommand::KickPlayer",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn merge_players(
+        &self,
+        nickname: &str,
+        source: String,
+        target: String,
+    ) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::MergePlayers {
+                nickname: nickname.to_string(),
+                source,
+                target,
+                response_tx: tx,
+            },
+            "GameCommand::MergePlayers",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
+
+    pub async fn transfer_host(&self, nickname: &str, new_host: String) -> Result<(), Error> {
+        let (tx, rx): (OneshotSender<GameEvent>, OneshotReceiver<GameEvent>) = oneshot::channel();
+
+        self.send_command(
+            GameCommand::TransferHost {
+                nickname: nickname.to_string(),
+                new_host,
+                response_tx: tx,
+            },
+            "GameCommand::TransferHost",
+        )
+        .await?;
+
+        match rx.await {
+            Ok(GameEvent::Ok) => Ok(()),
+            error => Err(GameClient::handle_event_error(error)),
+        }
+    }
 }
 
 pub struct GameWideEventReceiver {
@@ -22,7 +22,11 @@ state_machine! {
     },
     PlayersSubmittingWords => {
         // TODO: TimesUp => ScoreCounting,
-        AllPlayersSubmittedWords => ChooseNextVotingItem
+        AllPlayersSubmittedWords => ChooseNextVotingItem,
+        PrepareVoting => PreparingVoting,
+    },
+    PreparingVoting => {
+        StartVoting => ChooseNextVotingItem,
     },
     ChooseNextVotingItem => {
         NextVotingItem => PlayersSubmittingVotingWord,
@@ -35,6 +39,7 @@ state_machine! {
         ContinueToNextRound => CreatingNewRound,
     },
     EndOfGame => {
-        PlayAgain => Lobby
+        PlayAgain => Lobby,
+        PlayAgainSameSettings => CreatingNewRound,
     }
 }
@@ -2,59 +2,239 @@ pub mod actor;
 pub mod actor_client;
 pub mod game_fsm;
 mod game_word;
+pub mod nickname;
+pub mod recording;
 
-use rand::{seq::SliceRandom, thread_rng};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+
+use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
 use rust_fsm::StateMachine;
 
+use crate::config::GameSettings;
 use crate::error::domain_error::DomainError;
 use crate::error::Error;
 use crate::game::game_fsm::{GameFsm, GameFsmInput, GameFsmState};
 use crate::player::Player;
-use crate::round::Round;
+use crate::round::{RemainingVotingItem, Round, VotingItemResolution};
 
 use self::game_word::GameWord;
 
+// A word a player submitted and what it scored, rounds are numbered from 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreBreakdownEntry {
+    pub round: usize,
+    // None for the participation points of the round
+    pub word: Option<String>,
+    pub score: isize,
+}
+
 pub struct Game {
     id: String,
+    title: Option<String>,
+    settings: GameSettings,
     words: Vec<GameWord>,
+    blocklist: Arc<HashSet<String>>,
     fsm: StateMachine<GameFsm>,
     players: Vec<Player>,
     rounds: Vec<Round>,
+    // The words each player submitted in their most recent completed round, kept across play again
+    last_words: HashMap<String, Vec<String>>,
+    // Consecutive rounds in which each player did not submit any words themselves, either because
+    // they were disconnected or because the game submitted them when the time was up
+    missed_rounds: HashMap<String, u8>,
+    // Removed when the last round started and not yet announced
+    kicked_players: Vec<String>,
+    // Kicked by the host, they can't join this game again. Lowercase, like the nickname comparisons
+    banned_players: HashSet<String>,
+    // Set by the host at the end of the game, nothing else can happen afterwards
+    is_session_ended: bool,
+    // The prompt of the most recent round, a reshuffle never starts with it
+    last_chosen_word: Option<String>,
+    // When the current round started, the duration of the round is measured from it
+    round_started_at: Option<Instant>,
+    // Round number, nickname and word of the current voting item, and when it was first shown
+    voting_item_shown_at: Option<((usize, String, String), Instant)>,
+    // Recorded with the session, every random choice made while playing comes from it so that a
+    // replay makes the same ones
+    rng_seed: u64,
+    rng: StdRng,
     pub amount_of_rounds: Option<u8>,
 }
 
 impl Game {
-    const MINIMUM_PLAYERS: u8 = 3;
-    const MINIMUM_ROUNDS: u8 = 1;
+    pub(crate) const MINIMUM_ROUNDS: u8 = 1;
     const DEFAULT_ROUNDS: u8 = 3;
-
-    pub fn new(id: &str, words: Vec<String>) -> Self {
+    const MAXIMUM_TITLE_LENGTH: usize = 50;
+    const AUTOFILLED_WORDS: usize = 3;
+    // Used when a game has no words of its own, e.g. the words file could not be fetched
+    const DEFAULT_WORDS: [&'static str; 48] = [
+        "summer",
+        "space",
+        "dog",
+        "pizza",
+        "rock",
+        "picnic",
+        "surf",
+        "winter",
+        "ocean",
+        "forest",
+        "castle",
+        "robot",
+        "dragon",
+        "coffee",
+        "music",
+        "train",
+        "island",
+        "garden",
+        "pirate",
+        "circus",
+        "jungle",
+        "desert",
+        "camping",
+        "birthday",
+        "school",
+        "kitchen",
+        "football",
+        "volcano",
+        "carnival",
+        "museum",
+        "airport",
+        "wedding",
+        "hospital",
+        "farm",
+        "library",
+        "moon",
+        "cinema",
+        "breakfast",
+        "snow",
+        "rain",
+        "city",
+        "river",
+        "mountain",
+        "party",
+        "treasure",
+        "zoo",
+        "beach",
+        "festival",
+    ];
+
+    pub fn new(
+        id: &str,
+        title: Option<String>,
+        settings: GameSettings,
+        words: Vec<String>,
+        blocklist: Arc<HashSet<String>>,
+    ) -> Self {
+        // A blank word would be an empty prompt, so they never count towards the minimum
+        let words: Vec<String> = words
+            .into_iter()
+            .filter(|word| !word.trim().is_empty())
+            .collect();
         let words = if words.len() >= Game::MINIMUM_ROUNDS.into() {
             words
         } else {
             log::error!("Game created without enough words, defaulting to the built-in list of words. GameId: '{}', ActualWords: '{}', MinimumWords: '{}'", id, words.len(), Game::MINIMUM_ROUNDS);
-            Game::default_words()
+            Game::fallback_words(&settings)
         };
 
+        // Create a pre-shuffled list of words, so that we don't need to do random picks every round
+        let words = Game::shuffle_words(words, settings.word_shuffle_seed);
+        let rng_seed = settings
+            .word_shuffle_seed
+            .unwrap_or_else(|| thread_rng().gen());
+        Game::with_game_words(id, title, settings, words, blocklist, rng_seed)
+    }
+
+    // Keeps the given order of words, used to replay a recorded game with the same random words
+    pub(crate) fn with_word_order(
+        id: &str,
+        title: Option<String>,
+        settings: GameSettings,
+        words: Vec<String>,
+        blocklist: Arc<HashSet<String>>,
+        rng_seed: u64,
+    ) -> Self {
+        let words = words
+            .into_iter()
+            .map(|word| GameWord {
+                value: word,
+                is_used: false,
+            })
+            .collect();
+        Game::with_game_words(id, title, settings, words, blocklist, rng_seed)
+    }
+
+    fn with_game_words(
+        id: &str,
+        title: Option<String>,
+        settings: GameSettings,
+        words: Vec<GameWord>,
+        blocklist: Arc<HashSet<String>>,
+        rng_seed: u64,
+    ) -> Self {
         Self {
             id: id.to_string(),
-            // Create a pre-shuffled list of words, so that we don't need to do random picks every round
-            words: Game::shuffle_words(words),
+            title,
+            settings,
+            words,
+            blocklist,
             fsm: StateMachine::default(),
             players: Vec::default(),
             rounds: Vec::default(),
+            last_words: HashMap::default(),
+            missed_rounds: HashMap::default(),
+            kicked_players: Vec::default(),
+            banned_players: HashSet::default(),
+            is_session_ended: false,
+            last_chosen_word: None,
+            round_started_at: None,
+            voting_item_shown_at: None,
+            rng_seed,
+            rng: StdRng::seed_from_u64(rng_seed),
             amount_of_rounds: None,
         }
     }
 
-    fn default_words() -> Vec<String> {
-        ["summer", "space", "dog", "pizza", "rock", "picnic", "surf"]
+    pub fn round_started_at(&self) -> Option<Instant> {
+        self.round_started_at
+    }
+
+    pub(crate) fn rng_seed(&self) -> u64 {
+        self.rng_seed
+    }
+
+    pub(crate) fn word_order(&self) -> Vec<String> {
+        self.words
+            .iter()
+            .map(|word| word.value.to_string())
+            .collect()
+    }
+
+    pub(crate) fn default_words() -> Vec<String> {
+        Game::DEFAULT_WORDS
             .iter()
             .map(|word| word.to_string())
             .collect()
     }
 
-    fn shuffle_words(words: Vec<String>) -> Vec<GameWord> {
+    // Without a sample size every fallback game draws from the whole built-in list
+    fn fallback_words(settings: &GameSettings) -> Vec<String> {
+        let Some(fallback_words_per_game) = settings.fallback_words_per_game else {
+            return Game::default_words();
+        };
+        // Fewer words than a single round would leave the game without a word to choose
+        let fallback_words_per_game = fallback_words_per_game.max(Game::MINIMUM_ROUNDS.into());
+        let sample = match settings.word_shuffle_seed {
+            Some(seed) => Game::DEFAULT_WORDS
+                .choose_multiple(&mut StdRng::seed_from_u64(seed), fallback_words_per_game),
+            None => Game::DEFAULT_WORDS.choose_multiple(&mut thread_rng(), fallback_words_per_game),
+        };
+        sample.map(|word| word.to_string()).collect()
+    }
+
+    fn shuffle_words(words: Vec<String>, seed: Option<u64>) -> Vec<GameWord> {
         let mut words: Vec<GameWord> = words
             .into_iter()
             .map(|word| GameWord {
@@ -62,8 +242,10 @@ impl Game {
                 is_used: false,
             })
             .collect();
-        let mut rng = thread_rng();
-        words.shuffle(&mut rng);
+        match seed {
+            Some(seed) => words.shuffle(&mut StdRng::seed_from_u64(seed)),
+            None => words.shuffle(&mut thread_rng()),
+        }
         words
     }
 
@@ -71,6 +253,56 @@ impl Game {
         &self.id
     }
 
+    pub fn settings(&self) -> &GameSettings {
+        &self.settings
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.id)
+    }
+
+    pub fn set_title(&mut self, nickname: &str, title: &str) -> Result<(), Error> {
+        if self.is_host(nickname) {
+            self.title = Some(Game::validate_title(title)?);
+            Ok(())
+        } else {
+            Err(Error::Domain(DomainError::NonHostPlayerCannotSetTitle(
+                nickname.to_string(),
+            )))
+        }
+    }
+
+    pub fn validate_title(title: &str) -> Result<String, Error> {
+        let title = title.trim();
+        let length = title.chars().count();
+        if length == 0 || length > Game::MAXIMUM_TITLE_LENGTH {
+            Err(Error::Domain(DomainError::InvalidTitleLength(
+                length,
+                Game::MAXIMUM_TITLE_LENGTH,
+            )))
+        } else {
+            Ok(title.to_string())
+        }
+    }
+
+    // Returns None when nothing but whitespace is left, those messages are not worth an error
+    pub fn sanitize_chat_message(&self, content: &str) -> Result<Option<String>, Error> {
+        let content: String = content
+            .chars()
+            .filter(|character| !character.is_control())
+            .collect();
+        let content = content.trim();
+        if content.is_empty() {
+            Ok(None)
+        } else if content.chars().count() > self.settings.max_chat_length {
+            Err(Error::Domain(DomainError::ChatMessageTooLong {
+                max: self.settings.max_chat_length,
+            }))
+        } else {
+            Ok(Some(content.to_string()))
+        }
+    }
+
     pub fn state(&self) -> &GameFsmState {
         self.fsm.state()
     }
@@ -83,6 +315,146 @@ impl Game {
         &self.rounds
     }
 
+    pub fn rounds_in_state(&self) -> &[Round] {
+        &self.rounds[self.first_round_in_state()..]
+    }
+
+    // The rounds that max_rounds_in_state leaves out of the game state
+    pub fn round_history(&self) -> &[Round] {
+        &self.rounds[..self.first_round_in_state()]
+    }
+
+    // At most a page of the round history, however many rounds the player asks for
+    pub fn round_history_page(&self, offset: usize, limit: Option<usize>) -> &[Round] {
+        let history = self.round_history();
+        let page_size = self.settings.round_history_page_size;
+        let start = offset.min(history.len());
+        let end = start + limit.unwrap_or(page_size).min(page_size);
+        &history[start..end.min(history.len())]
+    }
+
+    fn first_round_in_state(&self) -> usize {
+        match self.settings.max_rounds_in_state {
+            Some(max_rounds) => self.rounds.len().saturating_sub(max_rounds.max(1)),
+            None => 0,
+        }
+    }
+
+    // Every player shows up, even if disconnected or without scored words yet
+    pub fn player_total_scores(&self) -> HashMap<String, isize> {
+        let mut total_scores: HashMap<String, isize> = self
+            .players
+            .iter()
+            .map(|player| (player.nickname.to_string(), 0))
+            .collect();
+        let participation_points = self.settings.participation_points.unwrap_or_default() as isize;
+        for round in &self.rounds {
+            for (nickname, words) in &round.player_words {
+                // Kicked players are no longer ranked
+                let Some(total_score) = total_scores.get_mut(nickname) else {
+                    continue;
+                };
+                let participation_points = if round.is_participant(nickname) {
+                    participation_points
+                } else {
+                    0
+                };
+                *total_score +=
+                    words.iter().map(|word| word.score).sum::<isize>() + participation_points;
+            }
+        }
+        total_scores
+    }
+
+    // Every word each player submitted in every round along with their participation points, adding
+    // up to their total score
+    pub fn score_breakdown(&self) -> HashMap<String, Vec<ScoreBreakdownEntry>> {
+        let mut breakdown: HashMap<String, Vec<ScoreBreakdownEntry>> = self
+            .players
+            .iter()
+            .map(|player| (player.nickname.to_string(), vec![]))
+            .collect();
+        for (index, round) in self.rounds.iter().enumerate() {
+            for (nickname, words) in &round.player_words {
+                let Some(entries) = breakdown.get_mut(nickname) else {
+                    continue;
+                };
+                entries.extend(words.iter().map(|word| ScoreBreakdownEntry {
+                    round: index + 1,
+                    word: Some(word.word.to_string()),
+                    score: word.score,
+                }));
+                if let Some(participation_points) = self.settings.participation_points {
+                    if round.is_participant(nickname) {
+                        entries.push(ScoreBreakdownEntry {
+                            round: index + 1,
+                            word: None,
+                            score: participation_points as isize,
+                        });
+                    }
+                }
+            }
+        }
+        breakdown
+    }
+
+    // Highest total score first, ties broken by nickname
+    pub fn ranking(&self) -> Vec<(String, isize)> {
+        let mut ranking: Vec<(String, isize)> = self.player_total_scores().into_iter().collect();
+        ranking.sort_by(|(nickname_a, score_a), (nickname_b, score_b)| {
+            score_b
+                .cmp(score_a)
+                .then_with(|| nickname_a.cmp(nickname_b))
+        });
+        ranking
+    }
+
+    pub fn round_start_message(&self) -> Option<String> {
+        let template = self.settings.round_start_message_template.as_ref()?;
+        if self.state() != &GameFsmState::PlayersSubmittingWords {
+            return None;
+        }
+        self.rounds.last().map(|round| {
+            template
+                .replace("{round}", &self.rounds.len().to_string())
+                // Masked while the word is revealed in stages, the chat would give it away
+                .replace("{word}", &round.masked_word())
+        })
+    }
+
+    pub fn reveal_next_word_stage(&mut self, round: usize) -> Option<String> {
+        if self.state() != &GameFsmState::PlayersSubmittingWords || self.rounds.len() != round {
+            return None;
+        }
+        self.rounds.last_mut()?.reveal_next_word_stage()
+    }
+
+    // Connected players that haven't submitted yet submit no words, disconnected players are then
+    // handled as if the last connected player had just submitted
+    pub fn end_word_submission(&mut self, round: usize) -> Result<(), Error> {
+        if self.state() != &GameFsmState::PlayersSubmittingWords || self.rounds.len() != round {
+            return Ok(());
+        }
+        let current_round = self
+            .rounds
+            .last_mut()
+            .expect("Missing round, there is a bug in the code.");
+        for player in self.players.iter().filter(|player| player.is_connected) {
+            if !current_round.player_words.contains_key(&player.nickname) {
+                current_round.fill_player_words(&player.nickname, Vec::default())?;
+            }
+        }
+        self.check_transition_to_voting()
+    }
+
+    // Ends the pause before the voting of the round, showing its first voting item
+    pub fn start_voting(&mut self, round: usize) -> Result<(), Error> {
+        if self.state() != &GameFsmState::PreparingVoting || self.rounds.len() != round {
+            return Ok(());
+        }
+        self.process_event(&GameFsmInput::StartVoting)
+    }
+
     pub fn all_players_are_disconnected(&self) -> bool {
         self.get_connected_players().is_empty()
     }
@@ -94,9 +466,17 @@ impl Game {
             .collect()
     }
 
-    pub fn add_player(&mut self, nickname: &str) -> Result<(), Error> {
+    // Returns the player as the game knows it, a player rejoining with a different casing keeps
+    // their original nickname
+    pub fn add_player(&mut self, nickname: &str) -> Result<Player, Error> {
         let state = self.state().clone();
 
+        if self.is_banned(nickname) {
+            return Err(Error::Domain(DomainError::PlayerWasKicked(
+                nickname.to_string(),
+            )));
+        }
+
         if let Some(player) = self.get_player_mut(nickname) {
             if player.is_connected {
                 return Err(Error::Domain(DomainError::PlayerAlreadyExists(
@@ -104,6 +484,12 @@ impl Game {
                 )));
             } else {
                 player.is_connected = true;
+                let player = player.clone();
+                self.missed_rounds.remove(&player.nickname);
+                self.assign_host();
+                // The words of a paused game may all be in already, with nobody left to submit
+                self.check_transition_to_voting()?;
+                return Ok(player);
             }
         } else if state == GameFsmState::Lobby {
             let new_player = Player::new(nickname);
@@ -115,15 +501,58 @@ impl Game {
         }
 
         self.assign_host();
-        Ok(())
+        Ok(self
+            .get_player(nickname)
+            .cloned()
+            .expect("Missing added player, there is a bug in the code."))
+    }
+
+    pub fn reconnection_token_nickname(&self, reconnection_token: &str) -> Result<String, Error> {
+        self.players
+            .iter()
+            .find(|player| player.reconnection_token == reconnection_token)
+            .map(|player| player.nickname.to_string())
+            .ok_or(Error::Domain(DomainError::InvalidReconnectionToken))
+    }
+
+    fn is_banned(&self, nickname: &str) -> bool {
+        self.banned_players.contains(&nickname.to_lowercase())
+    }
+
+    // Only a known player that is currently disconnected can rejoin the game, and only with the
+    // reconnection token it was sent when it first joined
+    pub fn validate_rejoin(&self, nickname: &str, reconnection_token: &str) -> Result<(), Error> {
+        if self.is_banned(nickname) {
+            return Err(Error::Domain(DomainError::PlayerWasKicked(
+                nickname.to_string(),
+            )));
+        }
+        match self.get_player(nickname) {
+            Some(player) if player.is_connected => Err(Error::Domain(
+                DomainError::PlayerAlreadyExists(nickname.to_string()),
+            )),
+            Some(player) if player.reconnection_token != reconnection_token => {
+                Err(Error::Domain(DomainError::InvalidReconnectionToken))
+            }
+            Some(_) => Ok(()),
+            None => Err(Error::Domain(DomainError::RejoiningPlayerDoesNotExist(
+                nickname.to_string(),
+            ))),
+        }
     }
 
-    pub fn disconnect_player(&mut self, nickname: &str) -> Result<(), Error> {
+    // Returns the resolution of the voting item when the disconnect completed it
+    pub fn disconnect_player(
+        &mut self,
+        nickname: &str,
+        now: Instant,
+    ) -> Result<Option<VotingItemResolution>, Error> {
         if let Some(player) = self.get_player_mut(nickname) {
             player.is_connected = false;
             player.is_host = false;
             self.assign_host();
-            self.check_transition_to_voting()
+            self.check_transition_to_voting()?;
+            self.check_voting_completion(nickname, now)
         } else {
             Err(Error::log_and_create_internal(&format!(
                 "Tried to disconnect player '{nickname}' but it does not exist."
@@ -131,17 +560,154 @@ impl Game {
         }
     }
 
+    // Returns the nickname of the kicked player as the game knows it
+    pub fn kick_player(
+        &mut self,
+        host_nickname: &str,
+        target_nickname: &str,
+    ) -> Result<String, Error> {
+        if !self.is_host(host_nickname) {
+            return Err(Error::Domain(DomainError::NonHostPlayerCannotKick(
+                host_nickname.to_string(),
+            )));
+        }
+        if self
+            .get_player(host_nickname)
+            .is_some_and(|host| host.has_nickname(target_nickname))
+        {
+            return Err(Error::Domain(DomainError::CannotKickSelf(
+                host_nickname.to_string(),
+            )));
+        }
+        let Some(target_nickname) = self
+            .get_player(target_nickname)
+            .map(|player| player.nickname.clone())
+        else {
+            return Err(Error::Domain(DomainError::KickedPlayerDoesNotExist(
+                target_nickname.to_string(),
+            )));
+        };
+
+        self.players
+            .retain(|player| player.nickname != target_nickname);
+        self.missed_rounds.remove(&target_nickname);
+        self.banned_players.insert(target_nickname.to_lowercase());
+        self.assign_host();
+        // Their words are not voted, a voting item of theirs is skipped without scoring it
+        let is_round_in_progress = matches!(
+            self.state(),
+            GameFsmState::PlayersSubmittingWords
+                | GameFsmState::PreparingVoting
+                | GameFsmState::PlayersSubmittingVotingWord
+        );
+        if is_round_in_progress && self.get_current_round_mut().remove_player(&target_nickname) {
+            self.process_event(&GameFsmInput::AcceptPlayersVotingWords)?;
+        }
+        self.check_transition_to_voting()?;
+        Ok(target_nickname)
+    }
+
+    pub fn merge_players(
+        &mut self,
+        host_nickname: &str,
+        source_nickname: &str,
+        target_nickname: &str,
+    ) -> Result<String, Error> {
+        if !self.is_host(host_nickname) {
+            return Err(Error::Domain(DomainError::NonHostPlayerCannotMergePlayers(
+                host_nickname.to_string(),
+            )));
+        }
+        if self.state() != &GameFsmState::Lobby {
+            return Err(Error::Domain(DomainError::InvalidStateForMergingPlayers(
+                self.state().clone(),
+                GameFsmState::Lobby,
+            )));
+        }
+        let Some(source) = self.get_player(source_nickname).cloned() else {
+            return Err(Error::Domain(DomainError::MergedPlayerDoesNotExist(
+                source_nickname.to_string(),
+            )));
+        };
+        let Some(target_nickname) = self
+            .get_player(target_nickname)
+            .map(|player| player.nickname.clone())
+        else {
+            return Err(Error::Domain(DomainError::MergedPlayerDoesNotExist(
+                target_nickname.to_string(),
+            )));
+        };
+        if source.nickname == target_nickname {
+            return Err(Error::Domain(DomainError::CannotMergePlayerWithItself(
+                source.nickname,
+            )));
+        }
+
+        if let Some(words) = self.last_words.remove(&source.nickname) {
+            let target_words = self.last_words.entry(target_nickname.clone()).or_default();
+            for word in words {
+                if !target_words.contains(&word) {
+                    target_words.push(word);
+                }
+            }
+        }
+        self.missed_rounds.remove(&source.nickname);
+        self.players
+            .retain(|player| player.nickname != source.nickname);
+        if source.is_host {
+            if let Some(target) = self
+                .players
+                .iter_mut()
+                .find(|player| player.nickname == target_nickname && player.is_connected)
+            {
+                target.is_host = true;
+            }
+        }
+        self.assign_host();
+        Ok(source.nickname)
+    }
+
+    pub fn transfer_host(&mut self, current_host: &str, new_host: &str) -> Result<(), Error> {
+        if !self.is_host(current_host) {
+            return Err(Error::Domain(DomainError::NonHostPlayerCannotTransferHost(
+                current_host.to_string(),
+            )));
+        }
+        if !self
+            .get_player(new_host)
+            .is_some_and(|player| player.is_connected)
+        {
+            return Err(Error::Domain(DomainError::NewHostIsNotAvailable(
+                new_host.to_string(),
+            )));
+        }
+
+        for player in self.players.iter_mut() {
+            player.is_host = player.has_nickname(new_host);
+        }
+        Ok(())
+    }
+
     pub fn start_game(&mut self, nickname: &str, amount_of_rounds: u8) -> Result<(), Error> {
         if self.is_host(nickname) {
-            if amount_of_rounds < Game::MINIMUM_ROUNDS {
+            if self.state() != &GameFsmState::Lobby {
+                Err(Error::Domain(DomainError::GameAlreadyStarted(
+                    self.state().clone(),
+                )))
+            } else if amount_of_rounds < Game::MINIMUM_ROUNDS {
                 Err(Error::Domain(DomainError::NotEnoughRounds(
                     amount_of_rounds.into(),
                     Game::MINIMUM_ROUNDS.into(),
                 )))
-            } else if self.get_connected_players().len() < Game::MINIMUM_PLAYERS.into() {
+            } else if amount_of_rounds > self.settings.maximum_rounds {
+                Err(Error::Domain(DomainError::TooManyRounds(
+                    amount_of_rounds.into(),
+                    self.settings.maximum_rounds.into(),
+                )))
+            } else if self.get_connected_players().len() < self.settings.minimum_players.into() {
                 Err(Error::Domain(DomainError::NotEnoughPlayers(
                     self.get_connected_players().len(),
-                    Game::MINIMUM_PLAYERS.into(),
+                    self.settings.minimum_players.into(),
                 )))
             } else {
                 self.amount_of_rounds = Some(amount_of_rounds);
@@ -157,13 +723,13 @@ impl Game {
     fn get_player(&self, nickname: &str) -> Option<&Player> {
         self.players
             .iter()
-            .find(|player| player.nickname == nickname)
+            .find(|player| player.has_nickname(nickname))
     }
 
     fn get_player_mut(&mut self, nickname: &str) -> Option<&mut Player> {
         self.players
             .iter_mut()
-            .find(|player| player.nickname == nickname)
+            .find(|player| player.has_nickname(nickname))
     }
 
     fn assign_host(&mut self) {
@@ -194,10 +760,12 @@ impl Game {
                         self.process_event(&GameFsmInput::NoMoreRounds)
                     } else {
                         self.start_new_round();
+                        self.round_started_at = Some(Instant::now());
                         self.process_event(&GameFsmInput::StartRound)
                     }
                 }
                 GameFsmState::PlayersSubmittingWords => Ok(()),
+                GameFsmState::PreparingVoting => Ok(()),
                 GameFsmState::Lobby => Ok(()),
                 GameFsmState::PlayersSubmittingVotingWord => Ok(()),
                 GameFsmState::ChooseNextVotingItem => {
@@ -207,7 +775,15 @@ impl Game {
                         self.process_event(&GameFsmInput::NoMoreVotingItems)
                     }
                 }
-                GameFsmState::EndOfRound => Ok(()),
+                GameFsmState::EndOfRound => {
+                    self.remember_last_words();
+                    // Not get_current_round_mut, the round and the random generator are borrowed at once
+                    self.rounds
+                        .last_mut()
+                        .unwrap()
+                        .compute_review_order(&mut self.rng);
+                    Ok(())
+                }
                 GameFsmState::EndOfGame => Ok(()),
             },
             Err(error) => Err(Error::log_and_create_internal(&format!(
@@ -218,14 +794,121 @@ impl Game {
         }
     }
 
+    fn remember_last_words(&mut self) {
+        if let Some(round) = self.rounds.last() {
+            for (nickname, words) in &round.player_words {
+                if !words.is_empty() {
+                    self.last_words.insert(
+                        nickname.to_string(),
+                        words.iter().map(|word| word.word.to_string()).collect(),
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn last_words(&self, nickname: &str) -> Vec<String> {
+        self.last_words.get(nickname).cloned().unwrap_or_default()
+    }
+
+    // Everyone can see the owners of the remaining voting items, but only the host can see the
+    // unused words of other players, as long as they are not being voted
+    pub fn remaining_voting_items(&self, nickname: &str) -> Vec<RemainingVotingItem> {
+        let round = match self.rounds.last() {
+            Some(round) if self.state() == &GameFsmState::PlayersSubmittingVotingWord => round,
+            _ => return vec![],
+        };
+        let is_host = self.is_host(nickname);
+        let voting_player = round
+            .voting_item
+            .as_ref()
+            .map(|voting_item| voting_item.player_nickname.as_str());
+        round
+            .remaining_voting_items()
+            .into_iter()
+            .map(|item| {
+                let is_visible = is_host
+                    || item.player_nickname == nickname
+                    || Some(item.player_nickname.as_str()) == voting_player;
+                RemainingVotingItem {
+                    word: is_visible.then_some(item.word),
+                    player_nickname: item.player_nickname,
+                }
+            })
+            .collect()
+    }
+
+    // The next player to be voted, skipping the remaining words of the player being voted
+    pub fn next_voting_player(&self) -> Option<String> {
+        if self.state() != &GameFsmState::PlayersSubmittingVotingWord {
+            return None;
+        }
+        let round = self.rounds.last()?;
+        let voting_player = round
+            .voting_item
+            .as_ref()
+            .map(|voting_item| voting_item.player_nickname.as_str());
+        round
+            .remaining_voting_items()
+            .into_iter()
+            .map(|item| item.player_nickname)
+            .find(|nickname| Some(nickname.as_str()) != voting_player)
+    }
+
+    pub fn take_kicked_players(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.kicked_players)
+    }
+
+    fn count_missed_rounds(&mut self) {
+        let Some(round) = self.rounds.last() else {
+            return;
+        };
+        for player in &self.players {
+            if round.is_participant(&player.nickname) {
+                self.missed_rounds.remove(&player.nickname);
+            } else {
+                *self
+                    .missed_rounds
+                    .entry(player.nickname.clone())
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    fn kick_inactive_players(&mut self) {
+        let Some(threshold) = self.settings.kick_after_missed_rounds else {
+            return;
+        };
+        let (kicked, kept): (Vec<Player>, Vec<Player>) =
+            self.players.drain(..).partition(|player| {
+                self.missed_rounds
+                    .get(&player.nickname)
+                    .is_some_and(|missed_rounds| *missed_rounds >= threshold)
+            });
+        self.players = kept;
+        for player in kicked {
+            self.missed_rounds.remove(&player.nickname);
+            self.kicked_players.push(player.nickname);
+        }
+    }
+
     fn start_new_round(&mut self) {
+        self.kick_inactive_players();
         let word = self.choose_random_word();
+        let mut players: Vec<String> = self
+            .players()
+            .iter()
+            .map(|player| player.nickname.clone())
+            .collect();
+        if self.settings.shuffle_turn_order {
+            players.shuffle(&mut self.rng);
+        }
         let round = Round::new(
+            self.rounds.len() + 1,
             &word,
-            self.players()
-                .iter()
-                .map(|player| player.nickname.clone())
-                .collect(),
+            players,
+            self.settings.clone(),
+            self.blocklist.clone(),
         );
         self.rounds.push(round);
     }
@@ -234,6 +917,7 @@ impl Game {
         match self.words.iter_mut().find(|word| !word.is_used) {
             Some(word) => {
                 word.is_used = true;
+                self.last_chosen_word = Some(word.value.to_string());
                 word.value.to_string()
             }
             None => {
@@ -243,7 +927,18 @@ impl Game {
                         .iter()
                         .map(|word| word.value.to_string())
                         .collect(),
+                    self.settings.word_shuffle_seed,
                 );
+                // Swapping instead of reshuffling keeps the order of a seeded game deterministic
+                if let Some(last_chosen_word) = &self.last_chosen_word {
+                    if let Some(index) = self
+                        .words
+                        .iter()
+                        .position(|word| &word.value != last_chosen_word)
+                    {
+                        self.words.swap(0, index);
+                    }
+                }
                 // This is a recursive call, it will always do just 1 recursive call as we ensure the game is constructed with at least 1 word
                 self.choose_random_word()
             }
@@ -271,7 +966,39 @@ impl Game {
             .set_player_voting_word(nickname, word)
     }
 
-    pub fn add_player_words(&mut self, nickname: &str, words: Vec<String>) -> Result<(), Error> {
+    pub fn clear_player_voting_word(&mut self, nickname: &str) -> Result<(), Error> {
+        if self.state() != &GameFsmState::PlayersSubmittingVotingWord {
+            return Err(Error::Domain(
+                DomainError::InvalidStateForVotingWordSubmission(
+                    self.state().to_owned(),
+                    GameFsmState::PlayersSubmittingVotingWord,
+                ),
+            ));
+        }
+        self.get_current_round_mut()
+            .clear_player_voting_word(nickname)
+    }
+
+    // Returns the words that were recorded for the player, once normalized
+    pub fn add_player_words(
+        &mut self,
+        nickname: &str,
+        words: Vec<String>,
+    ) -> Result<Vec<String>, Error> {
+        // A resubmission that arrives right after the last words moved the round on to voting, the
+        // words that were already submitted are kept
+        if matches!(
+            self.fsm.state(),
+            GameFsmState::PreparingVoting | GameFsmState::PlayersSubmittingVotingWord
+        ) && self
+            .rounds
+            .last()
+            .is_some_and(|round| round.player_words.contains_key(nickname))
+        {
+            return Err(Error::Domain(DomainError::WordsAlreadySubmitted(
+                nickname.to_string(),
+            )));
+        }
         if self.fsm.state() != &GameFsmState::PlayersSubmittingWords {
             return Err(Error::Domain(DomainError::InvalidStateForWordsSubmission(
                 self.fsm.state().to_owned(),
@@ -284,8 +1011,44 @@ impl Game {
             .last_mut()
             .expect("Missing round, there is a bug in the code.");
         round.add_player_words(nickname, words)?;
+        let accepted_words = round.player_words[nickname]
+            .iter()
+            .map(|word| word.word.to_string())
+            .collect();
 
-        self.check_transition_to_voting()
+        self.check_transition_to_voting()?;
+        Ok(accepted_words)
+    }
+
+    // Accepts the voting item in place of the host, so the same rules of accepting it apply
+    fn check_voting_completion(
+        &mut self,
+        nickname: &str,
+        now: Instant,
+    ) -> Result<Option<VotingItemResolution>, Error> {
+        if self.fsm.state() != &GameFsmState::PlayersSubmittingVotingWord
+            || !self.settings.skip_disconnected_voters
+        {
+            return Ok(None);
+        }
+        let connected_players: Vec<String> = self
+            .get_connected_players()
+            .iter()
+            .map(|player| player.nickname.clone())
+            .collect();
+        let round = self.get_current_round_mut();
+        round.skip_pending_voting_word(nickname);
+        // Nobody would see the voting go on, it waits for the players to come back
+        if connected_players.is_empty()
+            || !round.have_all_players_submitted_voting_words(&connected_players)
+            || self.is_voting_item_too_soon(now)
+            || self.count_missing_votes() > 0
+        {
+            return Ok(None);
+        }
+        let resolution = self.get_current_round_mut().compute_score();
+        self.process_event(&GameFsmInput::AcceptPlayersVotingWords)?;
+        Ok(resolution)
     }
 
     fn check_transition_to_voting(&mut self) -> Result<(), Error> {
@@ -300,29 +1063,157 @@ impl Game {
                 .filter(|player| player.is_connected)
                 .map(|player| player.nickname.clone())
                 .collect();
+            // A paused game waits for its players to come back instead of moving on without them
+            if self.settings.pause_when_empty && connected_players.is_empty() {
+                return Ok(());
+            }
             if round.have_all_players_submitted_words(&connected_players) {
                 for disconnected_player in self.players.iter().filter(|player| !player.is_connected)
                 {
-                    round.add_player_words(&disconnected_player.nickname, Vec::default())?;
+                    let words = if self.settings.autofill_disconnected_words {
+                        Game::autofill_words(
+                            &self.words,
+                            &round.word,
+                            &self.settings,
+                            &self.blocklist,
+                            &mut self.rng,
+                        )
+                    } else {
+                        Vec::default()
+                    };
+                    round.fill_player_words(&disconnected_player.nickname, words)?;
                 }
-                return self.process_event(&GameFsmInput::AllPlayersSubmittedWords);
+                round.reveal_word();
+                self.count_missed_rounds();
+                return if self.settings.voting_pause_seconds > 0 {
+                    self.process_event(&GameFsmInput::PrepareVoting)
+                } else {
+                    self.process_event(&GameFsmInput::AllPlayersSubmittedWords)
+                };
             }
         }
         Ok(())
     }
 
-    pub fn accept_players_voting_words(&mut self, nickname: &str) -> Result<(), Error> {
-        if self.is_host(nickname) {
-            self.get_current_round_mut().compute_score();
-            self.process_event(&GameFsmInput::AcceptPlayersVotingWords)
-        } else {
-            Err(Error::Domain(
-                DomainError::NonHostPlayerCannotContinueToNextVotingItem(nickname.to_string()),
-            ))
+    // The autofilled words are checked like the words of a player, so only those that would pass are
+    // candidates, otherwise the round would be stuck waiting for words that are never accepted
+    fn autofill_words(
+        words: &[GameWord],
+        round_word: &str,
+        settings: &GameSettings,
+        blocklist: &HashSet<String>,
+        rng: &mut StdRng,
+    ) -> Vec<String> {
+        // Starting with the word of the round leaves it out too
+        let mut unique_words: HashSet<String> = HashSet::from([round_word.to_lowercase()]);
+        let candidates: Vec<&str> = words
+            .iter()
+            .map(|word| word.value.trim())
+            .filter(|word| !word.is_empty())
+            .filter(|word| word.chars().count() <= settings.max_word_length)
+            .filter(|word| !blocklist.contains(&word.to_lowercase()))
+            .filter(|word| unique_words.insert(word.to_lowercase()))
+            .collect();
+        let amount = Game::AUTOFILLED_WORDS.min(settings.max_words_per_player);
+        candidates
+            .choose_multiple(rng, amount)
+            .map(|word| word.to_string())
+            .collect()
+    }
+
+    // Called with the time of every command once it is processed, so that a voting item is timed
+    // from the first command that showed it
+    pub fn track_voting_item(&mut self, now: Instant) {
+        let voting_item = self
+            .rounds
+            .last()
+            .and_then(|round| round.voting_item.as_ref())
+            .filter(|_| self.state() == &GameFsmState::PlayersSubmittingVotingWord)
+            .map(|voting_item| {
+                (
+                    self.rounds.len(),
+                    voting_item.player_nickname.to_string(),
+                    voting_item.word.to_string(),
+                )
+            });
+        let is_same_voting_item = match (&voting_item, &self.voting_item_shown_at) {
+            (Some(voting_item), Some((shown_voting_item, _))) => voting_item == shown_voting_item,
+            _ => false,
+        };
+        if !is_same_voting_item {
+            self.voting_item_shown_at = voting_item.map(|voting_item| (voting_item, now));
         }
     }
 
-    pub fn continue_to_next_round(&mut self, nickname: &str) -> Result<(), Error> {
+    fn is_voting_item_too_soon(&self, now: Instant) -> bool {
+        let min_voting_item_time = self.settings.min_voting_item_time();
+        self.voting_item_shown_at
+            .as_ref()
+            .is_some_and(|(_, shown_at)| now.duration_since(*shown_at) < min_voting_item_time)
+    }
+
+    pub fn accept_players_voting_words(
+        &mut self,
+        nickname: &str,
+        now: Instant,
+    ) -> Result<Option<VotingItemResolution>, Error> {
+        if self.is_host(nickname) {
+            if self.is_voting_item_too_soon(now) {
+                return Err(Error::Domain(DomainError::VotingItemTooSoon(
+                    self.settings.min_voting_item_seconds,
+                )));
+            }
+            let missing_votes = self.count_missing_votes();
+            if missing_votes > 0 {
+                return Err(Error::Domain(DomainError::NotEveryPlayerHasVoted(
+                    missing_votes,
+                )));
+            }
+            let resolution = self.get_current_round_mut().compute_score();
+            self.process_event(&GameFsmInput::AcceptPlayersVotingWords)?;
+            Ok(resolution)
+        } else {
+            Err(Error::Domain(
+                DomainError::NonHostPlayerCannotContinueToNextVotingItem(nickname.to_string()),
+            ))
+        }
+    }
+
+    // Only counted when explicit votes are required, the disconnected players cannot vote
+    fn count_missing_votes(&self) -> usize {
+        if !self.settings.require_explicit_votes
+            || self.state() != &GameFsmState::PlayersSubmittingVotingWord
+        {
+            return 0;
+        }
+        let Some(round) = self.rounds.last() else {
+            return 0;
+        };
+        self.get_connected_players()
+            .iter()
+            .filter(|player| !round.player_voting_words.contains_key(&player.nickname))
+            .count()
+    }
+
+    pub fn skip_voting_item(&mut self, nickname: &str) -> Result<(), Error> {
+        if !self.is_host(nickname) {
+            return Err(Error::Domain(
+                DomainError::NonHostPlayerCannotSkipVotingItem(nickname.to_string()),
+            ));
+        }
+        if self.state() != &GameFsmState::PlayersSubmittingVotingWord {
+            return Err(Error::Domain(
+                DomainError::InvalidStateForSkippingVotingItem(
+                    self.state().clone(),
+                    GameFsmState::PlayersSubmittingVotingWord,
+                ),
+            ));
+        }
+        self.get_current_round_mut().skip_voting_item();
+        self.process_event(&GameFsmInput::AcceptPlayersVotingWords)
+    }
+
+    pub fn continue_to_next_round(&mut self, nickname: &str) -> Result<(), Error> {
         if self.is_host(nickname) {
             self.process_event(&GameFsmInput::ContinueToNextRound)
         } else {
@@ -332,11 +1223,12 @@ impl Game {
         }
     }
 
+    // Goes back to the lobby, where the host can choose the settings of the next game
     pub fn play_again(&mut self, nickname: &str) -> Result<(), Error> {
         if self.is_host(nickname) {
             self.process_event(&GameFsmInput::PlayAgain)?;
             self.amount_of_rounds = None;
-            self.rounds = Vec::default();
+            self.reset_rounds();
             Ok(())
         } else {
             Err(Error::Domain(
@@ -345,6 +1237,89 @@ impl Game {
         }
     }
 
+    // Starts the next game straight away, with the amount of rounds and words of the last one
+    pub fn play_again_same_settings(&mut self, nickname: &str) -> Result<(), Error> {
+        if !self.is_host(nickname) {
+            return Err(Error::Domain(
+                DomainError::NonHostPlayerCannotSendPlayAgain(nickname.to_string()),
+            ));
+        }
+        if self.state() != &GameFsmState::EndOfGame {
+            return Err(Error::Domain(DomainError::InvalidStateForPostGameAction(
+                self.state().clone(),
+                GameFsmState::EndOfGame,
+            )));
+        }
+        if self.get_connected_players().len() < self.settings.minimum_players.into() {
+            return Err(Error::Domain(DomainError::NotEnoughPlayers(
+                self.get_connected_players().len(),
+                self.settings.minimum_players.into(),
+            )));
+        }
+        // The rounds are reset first, otherwise the new game would find them all played
+        self.reset_rounds();
+        self.process_event(&GameFsmInput::PlayAgainSameSettings)
+    }
+
+    // Nothing changes in the game, the actor stops as soon as it sees the session ended
+    pub fn end_session(&mut self, nickname: &str) -> Result<(), Error> {
+        if !self.is_host(nickname) {
+            return Err(Error::Domain(DomainError::NonHostPlayerCannotEndSession(
+                nickname.to_string(),
+            )));
+        }
+        if self.state() != &GameFsmState::EndOfGame {
+            return Err(Error::Domain(DomainError::InvalidStateForPostGameAction(
+                self.state().clone(),
+                GameFsmState::EndOfGame,
+            )));
+        }
+        self.is_session_ended = true;
+        Ok(())
+    }
+
+    pub fn is_session_ended(&self) -> bool {
+        self.is_session_ended
+    }
+
+    fn reset_rounds(&mut self) {
+        self.rounds = Vec::default();
+        self.missed_rounds = HashMap::default();
+        // The new game starts with the full pool of words, the ones just played are moved to the
+        // back. Not shuffling keeps recorded sessions replayable
+        let (unused_words, used_words): (Vec<GameWord>, Vec<GameWord>) =
+            self.words.drain(..).partition(|word| !word.is_used);
+        self.words = unused_words;
+        self.words.extend(used_words.into_iter().map(|mut word| {
+            word.is_used = false;
+            word
+        }));
+    }
+
+    pub fn remove_word(&mut self, nickname: &str, player: &str, word: &str) -> Result<(), Error> {
+        if self.state() != &GameFsmState::PlayersSubmittingVotingWord {
+            return Err(Error::Domain(DomainError::InvalidStateForRemovingWords(
+                self.state().clone(),
+                GameFsmState::PlayersSubmittingVotingWord,
+            )));
+        }
+
+        if !self.is_host(nickname) {
+            return Err(Error::Domain(DomainError::NonHostCannotRemoveWords));
+        }
+
+        // The round knows the players by the casing they joined with
+        let player = self
+            .get_player(player)
+            .map(|player| player.nickname.clone())
+            .unwrap_or_else(|| player.to_string());
+        if self.get_current_round_mut().remove_word(&player, word)? {
+            self.process_event(&GameFsmInput::AcceptPlayersVotingWords)
+        } else {
+            Ok(())
+        }
+    }
+
     fn reject_player_word(
         &mut self,
         nickname: &str,
@@ -360,16 +1335,19 @@ impl Game {
             ));
         }
 
-        if !self.players.iter().any(|p| p.nickname == rejected_player) {
+        let Some(rejected_player) = self
+            .get_player(rejected_player)
+            .map(|player| player.nickname.clone())
+        else {
             return Err(Error::Domain(
                 DomainError::RejectedMatchedPlayerDoesNotExist,
             ));
-        }
+        };
 
         if self.is_host(nickname) {
             let current_round = self.get_current_round_mut();
 
-            current_round.reject_player_word(rejected_player, rejected_word)
+            current_round.reject_player_word(&rejected_player, rejected_word)
         } else {
             Err(Error::Domain(DomainError::NonHostCannotRejectMatchedWords))
         }
@@ -379,18 +1357,22 @@ impl Game {
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
 
     use super::Game;
     use crate::{
+        config::GameSettings,
         error::{domain_error::DomainError, Error},
         game::game_fsm::GameFsmState,
+        round::Round,
     };
 
     static PLAYER_1: &str = "p1";
     static PLAYER_2: &str = "p2";
     static PLAYER_3: &str = "p3";
     fn players() -> Vec<String> {
-        vec![PLAYER_1, PLAYER_2, PLAYER_3]
+        [PLAYER_1, PLAYER_2, PLAYER_3]
             .iter()
             .map(|player| player.to_string())
             .collect()
@@ -399,7 +1381,7 @@ mod tests {
     static WORD_1: &str = "w1";
     static WORD_2: &str = "w2";
     fn words() -> Vec<String> {
-        vec![WORD_1, WORD_2]
+        [WORD_1, WORD_2]
             .iter()
             .map(|word| word.to_string())
             .collect()
@@ -424,7 +1406,7 @@ mod tests {
         assert!(game.players()[1].is_connected);
         assert!(game.players()[2].is_connected);
 
-        game.disconnect_player(PLAYER_1).unwrap();
+        game.disconnect_player(PLAYER_1, Instant::now()).unwrap();
 
         assert_eq!(game.players().len(), 3);
         assert!(!game.players()[0].is_connected);
@@ -432,11 +1414,55 @@ mod tests {
         assert!(game.players()[2].is_connected);
     }
 
+    #[test]
+    fn disconnected_player_can_rejoin() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.disconnect_player(PLAYER_2, Instant::now()).unwrap();
+        let reconnection_token = game.players()[1].reconnection_token.clone();
+
+        assert_eq!(game.validate_rejoin(PLAYER_2, &reconnection_token), Ok(()));
+    }
+
+    #[test]
+    fn disconnected_player_cannot_rejoin_with_the_reconnection_token_of_another_player() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.disconnect_player(PLAYER_2, Instant::now()).unwrap();
+        let reconnection_token = game.players()[2].reconnection_token.clone();
+
+        assert_eq!(
+            game.validate_rejoin(PLAYER_2, &reconnection_token),
+            Err(Error::Domain(DomainError::InvalidReconnectionToken))
+        );
+        assert_eq!(
+            game.validate_rejoin(PLAYER_2, "stale"),
+            Err(Error::Domain(DomainError::InvalidReconnectionToken))
+        );
+    }
+
+    #[test]
+    fn connected_or_unknown_player_cannot_rejoin() {
+        let game = get_game(&GameFsmState::PlayersSubmittingWords);
+        let reconnection_token = game.players()[1].reconnection_token.clone();
+
+        assert_eq!(
+            game.validate_rejoin(PLAYER_2, &reconnection_token),
+            Err(Error::Domain(DomainError::PlayerAlreadyExists(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert_eq!(
+            game.validate_rejoin("stranger", &reconnection_token),
+            Err(Error::Domain(DomainError::RejoiningPlayerDoesNotExist(
+                "stranger".to_string()
+            )))
+        );
+    }
+
     #[test]
     fn disconnect_non_existing() {
         let mut game = get_game(&GameFsmState::Lobby);
 
-        let result = game.disconnect_player("non_existent_player");
+        let result = game.disconnect_player("non_existent_player", Instant::now());
 
         assert_eq!(
             result,
@@ -448,213 +1474,1442 @@ mod tests {
     }
 
     #[test]
-    fn only_first_player_added_is_host() {
-        let game = get_game(&GameFsmState::Lobby);
+    fn disconnect_of_the_last_pending_voter_accepts_the_voting_words() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.settings.skip_disconnected_voters = true;
+        let voting_item = game.rounds()[0].voting_item.clone().unwrap();
+        let voters: Vec<String> = players()
+            .into_iter()
+            .filter(|player| *player != voting_item.player_nickname)
+            .collect();
+        game.set_player_voting_word(&voters[0], None).unwrap();
 
-        assert!(game.players()[0].is_host);
-        assert!(!game.players()[1].is_host);
+        let result = game.disconnect_player(&voters[1], Instant::now());
+
+        assert!(result.unwrap().is_some());
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert_ne!(game.rounds()[0].voting_item, Some(voting_item));
     }
 
     #[test]
-    fn host_player_is_reelected_when_disconnected() {
-        let mut game = get_game(&GameFsmState::Lobby);
+    fn host_removes_a_word_regardless_of_the_case_of_the_nickname() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        let voting_item = game.rounds()[0].voting_item.clone().unwrap();
 
-        game.disconnect_player(PLAYER_1).unwrap();
+        let result = game.remove_word(
+            PLAYER_1,
+            &voting_item.player_nickname.to_uppercase(),
+            &voting_item.word,
+        );
 
-        assert!(!game.players()[0].is_host);
-        assert!(game.players()[1].is_host);
+        assert_eq!(result, Ok(()));
+        assert!(!game.rounds()[0].player_words[&voting_item.player_nickname]
+            .iter()
+            .any(|word| word.word == voting_item.word));
     }
 
     #[test]
-    fn game_cannot_be_started_with_less_than_three_players() {
-        let mut game = get_empty_game();
-        game.add_player(PLAYER_1).unwrap();
+    fn disconnect_of_the_last_pending_voter_waits_for_the_minimum_voting_item_time() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.settings.skip_disconnected_voters = true;
+        game.settings.min_voting_item_seconds = 1;
+        let shown_at = Instant::now();
+        game.track_voting_item(shown_at);
+        let voting_item = game.rounds()[0].voting_item.clone().unwrap();
+        let voters: Vec<String> = players()
+            .into_iter()
+            .filter(|player| *player != voting_item.player_nickname)
+            .collect();
+        game.set_player_voting_word(&voters[0], None).unwrap();
 
-        let result = game.start_game(PLAYER_1, 3);
+        let result = game.disconnect_player(&voters[1], shown_at);
 
-        assert_eq!(
-            result,
-            Err(Error::Domain(DomainError::NotEnoughPlayers(1, 3)))
-        );
+        assert_eq!(result, Ok(None));
+        assert_eq!(game.rounds()[0].voting_item, Some(voting_item));
     }
 
     #[test]
-    fn game_cannot_be_started_with_less_than_one_round() {
-        let mut game = get_empty_game();
-        game.add_player(PLAYER_1).unwrap();
+    fn disconnect_of_the_last_pending_voter_waits_for_the_host_by_default() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        let voting_item = game.rounds()[0].voting_item.clone().unwrap();
+        let voters: Vec<String> = players()
+            .into_iter()
+            .filter(|player| *player != voting_item.player_nickname)
+            .collect();
+        game.set_player_voting_word(&voters[0], None).unwrap();
 
-        let result = game.start_game(PLAYER_1, 0);
+        let result = game.disconnect_player(&voters[1], Instant::now());
 
-        assert_eq!(
-            result,
-            Err(Error::Domain(DomainError::NotEnoughRounds(0, 1)))
-        );
+        assert_eq!(result, Ok(None));
+        assert_eq!(game.rounds()[0].voting_item, Some(voting_item));
+        assert!(!game.rounds()[0]
+            .player_voting_words
+            .contains_key(&voters[1]));
     }
 
     #[test]
-    fn host_player_can_start_game() {
-        let mut game = get_game(&GameFsmState::Lobby);
+    fn disconnect_of_a_voter_does_not_accept_while_others_are_pending() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.settings.skip_disconnected_voters = true;
+        let voting_item = game.rounds()[0].voting_item.clone().unwrap();
+        let voters: Vec<String> = players()
+            .into_iter()
+            .filter(|player| *player != voting_item.player_nickname)
+            .collect();
 
-        let result = game.start_game(PLAYER_1, 3);
+        let result = game.disconnect_player(&voters[1], Instant::now());
 
-        assert_eq!(result, Ok(()));
+        assert_eq!(result, Ok(None));
+        assert_eq!(game.rounds()[0].voting_item, Some(voting_item));
+        assert_eq!(
+            game.rounds()[0].player_voting_words.get(&voters[1]),
+            Some(&None)
+        );
     }
 
     #[test]
-    fn non_host_player_cannot_start_game() {
-        let mut game = get_game(&GameFsmState::Lobby);
+    fn new_host_can_continue_to_next_round_right_after_the_host_disconnects() {
+        let mut game = get_game(&GameFsmState::EndOfRound);
 
-        let result = game.start_game(PLAYER_2, 3);
+        game.disconnect_player(PLAYER_1, Instant::now()).unwrap();
 
+        assert!(game.players()[1].is_host);
         assert_eq!(
-            result,
-            Err(Error::Domain(DomainError::NonHostPlayerCannotStartGame(
-                PLAYER_2.to_string()
-            )))
+            game.continue_to_next_round(PLAYER_1),
+            Err(Error::Domain(
+                DomainError::NonHostPlayerCannotContinueToNextRound(PLAYER_1.to_string())
+            ))
         );
+        assert_eq!(game.continue_to_next_round(PLAYER_2), Ok(()));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
     }
 
     #[test]
-    fn game_starts_in_lobby() {
-        let game = get_empty_game();
+    fn only_first_player_added_is_host() {
+        let game = get_game(&GameFsmState::Lobby);
 
-        assert_eq!(game.state(), &GameFsmState::Lobby);
+        assert!(game.players()[0].is_host);
+        assert!(!game.players()[1].is_host);
     }
 
     #[test]
-    fn game_initializes_first_round() {
+    fn host_player_can_kick_a_player() {
         let mut game = get_game(&GameFsmState::Lobby);
 
-        game.start_game(PLAYER_1, 3).unwrap();
+        let result = game.kick_player(PLAYER_1, PLAYER_2);
 
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
-        assert_eq!(game.rounds().len(), 1);
-        assert!(!game.rounds().first().unwrap().word.is_empty());
+        assert_eq!(result, Ok(PLAYER_2.to_string()));
+        assert_eq!(game.players().len(), 2);
+        assert_eq!(
+            game.add_player(PLAYER_2),
+            Err(Error::Domain(DomainError::PlayerWasKicked(
+                PLAYER_2.to_string()
+            )))
+        );
     }
 
     #[test]
-    fn all_players_are_disconnected_is_false() {
-        let game = get_game(&GameFsmState::Lobby);
+    fn non_host_player_cannot_kick() {
+        let mut game = get_game(&GameFsmState::Lobby);
 
-        assert!(!game.all_players_are_disconnected());
+        let result = game.kick_player(PLAYER_2, PLAYER_3);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NonHostPlayerCannotKick(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert_eq!(game.players().len(), 3);
     }
 
     #[test]
-    fn all_players_are_disconnected_is_true() {
+    fn host_player_cannot_kick_themselves() {
         let mut game = get_game(&GameFsmState::Lobby);
-        let _ = game.disconnect_player(PLAYER_1);
-        let _ = game.disconnect_player(PLAYER_2);
-        let _ = game.disconnect_player(PLAYER_3);
 
-        assert!(game.all_players_are_disconnected());
+        let result = game.kick_player(PLAYER_1, PLAYER_1);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::CannotKickSelf(
+                PLAYER_1.to_string()
+            )))
+        );
+        assert_eq!(game.players().len(), 3);
     }
 
     #[test]
-    fn all_players_are_disconnected_is_true_when_empty_players() {
-        let game = get_empty_game();
+    fn kicking_the_last_player_without_words_proceeds_to_voting() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
 
-        assert!(game.all_players_are_disconnected());
+        game.kick_player(PLAYER_1, PLAYER_3).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
     }
 
     #[test]
-    fn add_player_words_works() {
-        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+    fn kicking_the_owner_of_the_voting_item_moves_on_without_their_words() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        while game
+            .rounds()
+            .last()
+            .unwrap()
+            .voting_item
+            .as_ref()
+            .unwrap()
+            .player_nickname
+            != PLAYER_2
+        {
+            game.accept_players_voting_words(PLAYER_1, Instant::now())
+                .unwrap();
+        }
 
-        let result = game.add_player_words(PLAYER_1, words());
+        game.kick_player(PLAYER_1, PLAYER_2).unwrap();
 
-        assert_eq!(result, Ok(()));
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        let round = game.rounds().last().unwrap();
+        assert!(round
+            .remaining_voting_items()
+            .iter()
+            .all(|item| item.player_nickname != PLAYER_2));
+        assert!(!round.player_words.contains_key(PLAYER_2));
+        while game.state() == &GameFsmState::PlayersSubmittingVotingWord {
+            game.accept_players_voting_words(PLAYER_1, Instant::now())
+                .unwrap();
+        }
+        assert_eq!(game.state(), &GameFsmState::EndOfRound);
+        assert!(!game.player_total_scores().contains_key(PLAYER_2));
+        assert!(game
+            .ranking()
+            .iter()
+            .all(|(nickname, _)| nickname != PLAYER_2));
     }
 
     #[test]
-    fn add_player_words_transitions_to_players_submitting_voting_word_on_last_player_words() {
-        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+    fn host_player_can_merge_two_players() {
+        let mut game = get_game_with_rounds(&GameFsmState::EndOfGame, 1);
+        game.play_again(PLAYER_1).unwrap();
 
-        game.add_player_words(PLAYER_1, words()).unwrap();
-        game.add_player_words(PLAYER_2, words()).unwrap();
-        game.add_player_words(PLAYER_3, words()).unwrap();
+        let result = game.merge_players(PLAYER_1, PLAYER_3, PLAYER_2);
 
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert_eq!(result, Ok(PLAYER_3.to_string()));
+        assert_eq!(game.players().len(), 2);
+        assert!(game.last_words(PLAYER_3).is_empty());
+        assert_eq!(game.last_words(PLAYER_2), words());
+        assert!(game.add_player(PLAYER_3).is_ok());
     }
 
     #[test]
-    fn add_player_words_transitions_and_sets_default_words_for_disconnected_players() {
-        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
-        game.disconnect_player(PLAYER_2).unwrap();
-        game.disconnect_player(PLAYER_3).unwrap();
+    fn merging_the_host_makes_the_target_the_host() {
+        let mut game = get_game(&GameFsmState::Lobby);
 
-        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.merge_players(PLAYER_1, PLAYER_1, PLAYER_3).unwrap();
 
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
-        let round = game.rounds().last().unwrap();
-        assert!(round.player_words.contains_key(PLAYER_2));
-        assert!(round.player_words[PLAYER_2].is_empty());
-        assert!(round.player_words.contains_key(PLAYER_3));
-        assert!(round.player_words[PLAYER_3].is_empty());
+        assert_eq!(game.players().len(), 2);
+        assert!(!game.players()[0].is_host);
+        assert!(game.players()[1].is_host);
     }
 
     #[test]
-    fn add_player_words_fails_when_state_is_not_players_submitting_words() {
+    fn non_host_player_cannot_merge_players() {
         let mut game = get_game(&GameFsmState::Lobby);
 
-        let result = game.add_player_words(PLAYER_1, words());
+        let result = game.merge_players(PLAYER_2, PLAYER_3, PLAYER_2);
 
         assert_eq!(
             result,
-            Err(Error::Domain(DomainError::InvalidStateForWordsSubmission(
-                GameFsmState::Lobby,
-                GameFsmState::PlayersSubmittingWords
+            Err(Error::Domain(DomainError::NonHostPlayerCannotMergePlayers(
+                PLAYER_2.to_string()
             )))
         );
+        assert_eq!(game.players().len(), 3);
     }
 
     #[test]
-    fn add_player_voting_word_works_with_valid_word() {
-        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+    fn a_player_cannot_be_merged_with_itself() {
+        let mut game = get_game(&GameFsmState::Lobby);
 
-        let result = game.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()));
+        let result = game.merge_players(PLAYER_1, PLAYER_2, PLAYER_2);
 
-        assert_eq!(result, Ok(()));
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::CannotMergePlayerWithItself(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert_eq!(game.players().len(), 3);
     }
 
     #[test]
-    fn add_player_voting_word_works_with_empty_word() {
-        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+    fn merged_players_must_exist() {
+        let mut game = get_game(&GameFsmState::Lobby);
 
-        let result = game.set_player_voting_word(PLAYER_2, None);
+        let result = game.merge_players(PLAYER_1, "unknown", PLAYER_2);
 
-        assert_eq!(result, Ok(()));
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::MergedPlayerDoesNotExist(
+                "unknown".to_string()
+            )))
+        );
+        assert_eq!(game.players().len(), 3);
     }
 
     #[test]
-    fn add_player_voting_word_fails_when_state_is_not_players_submitting_voting_word() {
+    fn players_cannot_be_merged_outside_the_lobby() {
         let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
 
-        let result = game.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()));
+        let result = game.merge_players(PLAYER_1, PLAYER_3, PLAYER_2);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::InvalidStateForMergingPlayers(
+                GameFsmState::PlayersSubmittingWords,
+                GameFsmState::Lobby
+            )))
+        );
+        assert_eq!(game.players().len(), 3);
+    }
+
+    #[test]
+    fn host_player_can_transfer_the_host() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.transfer_host(PLAYER_1, PLAYER_2);
+
+        assert_eq!(result, Ok(()));
+        assert!(!game.players()[0].is_host);
+        assert!(game.players()[1].is_host);
+        assert!(!game.players()[2].is_host);
+    }
+
+    #[test]
+    fn non_host_player_cannot_transfer_the_host() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.transfer_host(PLAYER_2, PLAYER_3);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NonHostPlayerCannotTransferHost(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert!(game.players()[0].is_host);
+    }
+
+    #[test]
+    fn host_cannot_be_transferred_to_a_non_existing_player() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.transfer_host(PLAYER_1, "p4");
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NewHostIsNotAvailable(
+                "p4".to_string()
+            )))
+        );
+        assert!(game.players()[0].is_host);
+    }
+
+    #[test]
+    fn host_cannot_be_transferred_to_a_disconnected_player() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        game.disconnect_player(PLAYER_2, Instant::now()).unwrap();
+
+        let result = game.transfer_host(PLAYER_1, PLAYER_2);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NewHostIsNotAvailable(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert!(game.players()[0].is_host);
+        assert!(!game.players()[1].is_host);
+    }
+
+    #[test]
+    fn host_player_is_reelected_when_disconnected() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        game.disconnect_player(PLAYER_1, Instant::now()).unwrap();
+
+        assert!(!game.players()[0].is_host);
+        assert!(game.players()[1].is_host);
+    }
+
+    #[test]
+    fn game_cannot_be_started_with_less_than_three_players() {
+        let mut game = get_empty_game();
+        game.add_player(PLAYER_1).unwrap();
+
+        let result = game.start_game(PLAYER_1, 3);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NotEnoughPlayers(1, 3)))
+        );
+    }
+
+    #[test]
+    fn game_can_be_started_with_the_maximum_rounds() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let maximum_rounds = game.settings().maximum_rounds;
+
+        let result = game.start_game(PLAYER_1, maximum_rounds);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.amount_of_rounds, Some(maximum_rounds));
+    }
+
+    #[test]
+    fn game_cannot_be_started_with_more_than_the_maximum_rounds() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let maximum_rounds = game.settings().maximum_rounds;
+
+        let result = game.start_game(PLAYER_1, maximum_rounds + 1);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::TooManyRounds(
+                (maximum_rounds + 1).into(),
+                maximum_rounds.into()
+            )))
+        );
+        assert_eq!(game.state(), &GameFsmState::Lobby);
+        assert!(game.amount_of_rounds.is_none());
+    }
+
+    #[test]
+    fn game_can_be_started_with_the_configured_minimum_players() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                minimum_players: 2,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+
+        let result = game.start_game(PLAYER_1, 3);
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NotEnoughPlayers(1, 2)))
+        );
+
+        game.add_player(PLAYER_2).unwrap();
+
+        let result = game.start_game(PLAYER_1, 3);
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+    }
+
+    #[test]
+    fn game_cannot_be_started_with_less_than_one_round() {
+        let mut game = get_empty_game();
+        game.add_player(PLAYER_1).unwrap();
+
+        let result = game.start_game(PLAYER_1, 0);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NotEnoughRounds(0, 1)))
+        );
+    }
+
+    #[test]
+    fn host_player_can_start_game() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.start_game(PLAYER_1, 3);
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn non_host_player_cannot_start_game() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.start_game(PLAYER_2, 3);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NonHostPlayerCannotStartGame(
+                PLAYER_2.to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn game_cannot_be_started_twice() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        let result = game.start_game(PLAYER_1, 3);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::GameAlreadyStarted(
+                GameFsmState::PlayersSubmittingWords
+            )))
+        );
+        assert_eq!(game.rounds().len(), 1);
+    }
+
+    #[test]
+    fn title_defaults_to_the_game_id() {
+        let game = get_empty_game();
+
+        assert_eq!(game.title(), "id");
+    }
+
+    #[test]
+    fn host_player_can_set_title() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.set_title(PLAYER_1, " My game ");
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.title(), "My game");
+    }
+
+    #[test]
+    fn non_host_player_cannot_set_title() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.set_title(PLAYER_2, "My game");
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NonHostPlayerCannotSetTitle(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert_eq!(game.title(), "id");
+    }
+
+    #[test]
+    fn title_cannot_be_empty_or_too_long() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        assert_eq!(
+            game.set_title(PLAYER_1, "  "),
+            Err(Error::Domain(DomainError::InvalidTitleLength(0, 50)))
+        );
+        assert_eq!(
+            game.set_title(PLAYER_1, &"a".repeat(51)),
+            Err(Error::Domain(DomainError::InvalidTitleLength(51, 50)))
+        );
+        assert_eq!(game.set_title(PLAYER_1, &"a".repeat(50)), Ok(()));
+    }
+
+    #[test]
+    fn chat_message_control_characters_are_stripped() {
+        let game = get_empty_game();
+
+        assert_eq!(
+            game.sanitize_chat_message("hello\u{0}\n world\u{7}"),
+            Ok(Some("hello world".to_string()))
+        );
+    }
+
+    #[test]
+    fn chat_message_is_trimmed() {
+        let game = get_empty_game();
+
+        assert_eq!(
+            game.sanitize_chat_message("  hello world \t"),
+            Ok(Some("hello world".to_string()))
+        );
+    }
+
+    #[test]
+    fn whitespace_only_chat_message_is_dropped() {
+        let game = get_empty_game();
+
+        assert_eq!(game.sanitize_chat_message(""), Ok(None));
+        assert_eq!(game.sanitize_chat_message(" \u{0}\t  "), Ok(None));
+    }
+
+    #[test]
+    fn chat_message_cannot_be_too_long() {
+        let game = get_empty_game();
+
+        assert_eq!(
+            game.sanitize_chat_message(&"a".repeat(501)),
+            Err(Error::Domain(DomainError::ChatMessageTooLong { max: 500 }))
+        );
+        assert_eq!(
+            game.sanitize_chat_message(&format!(" {} ", "a".repeat(500))),
+            Ok(Some("a".repeat(500)))
+        );
+    }
+
+    #[test]
+    fn game_starts_in_lobby() {
+        let game = get_empty_game();
+
+        assert_eq!(game.state(), &GameFsmState::Lobby);
+    }
+
+    #[test]
+    fn game_initializes_first_round() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        game.start_game(PLAYER_1, 3).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert_eq!(game.rounds().len(), 1);
+        assert!(!game.rounds().first().unwrap().word.is_empty());
+    }
+
+    #[test]
+    fn game_starts_with_an_unused_prompt_after_playing_again() {
+        let mut game = get_game(&GameFsmState::EndOfGame);
+        let played_words: Vec<String> = game
+            .rounds()
+            .iter()
+            .map(|round| round.word.to_string())
+            .collect();
+
+        game.play_again(PLAYER_1).unwrap();
+        game.start_game(PLAYER_1, 1).unwrap();
+
+        let word = &game.rounds()[0].word;
+        assert!(!word.is_empty());
+        assert!(!played_words.contains(word));
+        assert_eq!(game.words.iter().filter(|word| word.is_used).count(), 1);
+    }
+
+    #[test]
+    fn game_with_the_minimum_words_has_a_prompt_every_time_it_starts() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings::default(),
+            vec!["dog".to_string()],
+            Arc::default(),
+        );
+        for player in [PLAYER_1, PLAYER_2, PLAYER_3] {
+            game.add_player(player).unwrap();
+        }
+
+        for _ in 0..2 {
+            game.start_game(PLAYER_1, 1).unwrap();
+            assert_eq!(game.rounds()[0].word, "dog");
+            complete_round(&mut game);
+            game.continue_to_next_round(PLAYER_1).unwrap();
+            game.play_again(PLAYER_1).unwrap();
+            assert!(game.words.iter().all(|word| !word.is_used));
+        }
+    }
+
+    #[test]
+    fn blank_words_are_never_prompts() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings::default(),
+            vec!["".to_string(), " ".to_string(), "dog".to_string()],
+            Arc::default(),
+        );
+        for player in [PLAYER_1, PLAYER_2, PLAYER_3] {
+            game.add_player(player).unwrap();
+        }
+
+        game.start_game(PLAYER_1, 1).unwrap();
+
+        assert_eq!(game.rounds()[0].word, "dog");
+        assert_eq!(game.words.len(), 1);
+    }
+
+    #[test]
+    fn round_start_message_is_none_without_template() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        game.start_game(PLAYER_1, 3).unwrap();
+
+        assert_eq!(game.round_start_message(), None);
+    }
+
+    #[test]
+    fn round_start_message_is_filled_from_the_template() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                round_start_message_template: Some("Round {round}: {word}".to_string()),
+                ..GameSettings::default()
+            },
+            vec!["pizza".to_string()],
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+
+        assert_eq!(game.round_start_message(), None);
+        game.start_game(PLAYER_1, 3).unwrap();
+
+        assert_eq!(
+            game.round_start_message(),
+            Some("Round 1: pizza".to_string())
+        );
+    }
+
+    #[test]
+    fn round_start_message_masks_the_word_while_it_is_revealed_in_stages() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                round_start_message_template: Some("Round {round}: {word}".to_string()),
+                word_reveal_stages: 2,
+                ..GameSettings::default()
+            },
+            vec!["pizza".to_string()],
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+
+        game.start_game(PLAYER_1, 3).unwrap();
+
+        assert_eq!(
+            game.round_start_message(),
+            Some("Round 1: _____".to_string())
+        );
+    }
+
+    #[test]
+    fn word_reveal_only_applies_to_the_current_round_while_submitting_words() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                word_reveal_stages: 2,
+                ..GameSettings::default()
+            },
+            vec!["pizza".to_string()],
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+
+        assert_eq!(game.reveal_next_word_stage(1), None);
+        game.start_game(PLAYER_1, 3).unwrap();
+
+        assert_eq!(game.reveal_next_word_stage(0), None);
+        assert_eq!(game.reveal_next_word_stage(1), Some("pi___".to_string()));
+        send_players_words(&mut game);
+        assert_eq!(game.reveal_next_word_stage(1), None);
+        assert_eq!(game.rounds().last().unwrap().masked_word(), "pizza");
+    }
+
+    #[test]
+    fn end_word_submission_submits_no_words_for_the_remaining_players() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.add_player_words(PLAYER_1, words()).unwrap();
+
+        game.end_word_submission(1).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        let round = game.rounds().last().unwrap();
+        assert_eq!(round.player_words.get(PLAYER_1).unwrap().len(), 2);
+        assert!(round.player_words.get(PLAYER_2).unwrap().is_empty());
+        assert!(round.player_words.get(PLAYER_3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn voting_starts_after_the_voting_pause() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                voting_pause_seconds: 3,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        for player in players() {
+            game.add_player(&player).unwrap();
+        }
+        game.start_game(PLAYER_1, 3).unwrap();
+        send_players_words(&mut game);
+
+        assert_eq!(game.state(), &GameFsmState::PreparingVoting);
+        assert_eq!(game.rounds().last().unwrap().voting_item, None);
+        assert_eq!(
+            game.add_player_words(PLAYER_1, words()),
+            Err(Error::Domain(DomainError::WordsAlreadySubmitted(
+                PLAYER_1.to_string()
+            )))
+        );
+
+        game.start_voting(2).unwrap();
+        assert_eq!(game.state(), &GameFsmState::PreparingVoting);
+
+        game.start_voting(1).unwrap();
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert!(game.rounds().last().unwrap().voting_item.is_some());
+    }
+
+    #[test]
+    fn end_word_submission_of_a_previous_round_is_ignored() {
+        let mut game = get_game(&GameFsmState::EndOfRound);
+        game.continue_to_next_round(PLAYER_1).unwrap();
+
+        game.end_word_submission(1).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert!(game.rounds().last().unwrap().player_words.is_empty());
+    }
+
+    #[test]
+    fn all_players_are_disconnected_is_false() {
+        let game = get_game(&GameFsmState::Lobby);
+
+        assert!(!game.all_players_are_disconnected());
+    }
+
+    #[test]
+    fn all_players_are_disconnected_is_true() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let _ = game.disconnect_player(PLAYER_1, Instant::now());
+        let _ = game.disconnect_player(PLAYER_2, Instant::now());
+        let _ = game.disconnect_player(PLAYER_3, Instant::now());
+
+        assert!(game.all_players_are_disconnected());
+    }
+
+    #[test]
+    fn all_players_are_disconnected_is_true_when_empty_players() {
+        let game = get_empty_game();
+
+        assert!(game.all_players_are_disconnected());
+    }
+
+    #[test]
+    fn add_player_words_works() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        let result = game.add_player_words(PLAYER_1, words());
+
+        assert_eq!(result, Ok(words()));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+    }
+
+    #[test]
+    fn add_player_words_transitions_to_players_submitting_voting_word_on_last_player_words() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
+        game.add_player_words(PLAYER_3, words()).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+    }
+
+    #[test]
+    fn add_player_words_transitions_and_sets_default_words_for_disconnected_players() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.disconnect_player(PLAYER_2, Instant::now()).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        game.add_player_words(PLAYER_1, words()).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        let round = game.rounds().last().unwrap();
+        assert!(round.player_words.contains_key(PLAYER_2));
+        assert!(round.player_words[PLAYER_2].is_empty());
+        assert!(round.player_words.contains_key(PLAYER_3));
+        assert!(round.player_words[PLAYER_3].is_empty());
+    }
+
+    #[test]
+    fn minimum_words_do_not_apply_to_the_words_of_disconnected_players() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                min_words_per_player: 2,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert!(game.rounds().last().unwrap().player_words[PLAYER_3].is_empty());
+    }
+
+    #[test]
+    fn paused_game_keeps_submitting_words_when_every_player_disconnects() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                pause_when_empty: true,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.disconnect_player(PLAYER_1, Instant::now()).unwrap();
+
+        game.disconnect_player(PLAYER_2, Instant::now()).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+
+        // p1 already submitted, so the round moves on as soon as p1 is back
+        game.add_player(PLAYER_1).unwrap();
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+    }
+
+    #[test]
+    fn autofilled_words_are_unique_and_within_the_words_per_player() {
+        let game_words = ["summer", "summer", "tree", "tree", "Tree", "tree"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                autofill_disconnected_words: true,
+                max_words_per_player: 2,
+                ..GameSettings::default()
+            },
+            game_words,
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        let autofilled_words = &game.rounds().last().unwrap().player_words[PLAYER_3];
+        assert_eq!(autofilled_words.len(), 1);
+    }
+
+    #[test]
+    fn add_player_words_autofills_words_for_disconnected_players() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                autofill_disconnected_words: true,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        game.add_player(PLAYER_1).unwrap();
+        game.add_player(PLAYER_2).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        let round = game.rounds().last().unwrap();
+        let autofilled_words = &round.player_words[PLAYER_3];
+        assert_eq!(autofilled_words.len(), Game::AUTOFILLED_WORDS);
+        assert!(autofilled_words
+            .iter()
+            .all(|word| word.word != round.word && Game::default_words().contains(&word.word)));
+    }
+
+    #[test]
+    fn remaining_voting_items_hide_unused_words_of_others_from_non_hosts() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+
+        let host_items = game.remaining_voting_items(PLAYER_1);
+        let player_items = game.remaining_voting_items(PLAYER_2);
+
+        assert_eq!(host_items.len(), 6);
+        assert!(host_items.iter().all(|item| item.word.is_some()));
+        assert_eq!(player_items.len(), 6);
+        assert!(player_items
+            .iter()
+            .all(|item| item.word.is_some() == (item.player_nickname != PLAYER_3)));
+
+        game.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        game.accept_players_voting_words(PLAYER_1, Instant::now())
+            .unwrap();
+
+        assert_eq!(game.remaining_voting_items(PLAYER_2).len(), 4);
+    }
+
+    #[test]
+    fn next_voting_player_skips_the_player_being_voted() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+
+        assert_eq!(game.next_voting_player(), Some(PLAYER_2.to_string()));
+
+        game.accept_players_voting_words(PLAYER_1, Instant::now())
+            .unwrap();
+        assert_eq!(game.next_voting_player(), Some(PLAYER_2.to_string()));
+
+        game.accept_players_voting_words(PLAYER_1, Instant::now())
+            .unwrap();
+        assert_eq!(game.next_voting_player(), Some(PLAYER_3.to_string()));
+
+        game.accept_players_voting_words(PLAYER_1, Instant::now())
+            .unwrap();
+        game.accept_players_voting_words(PLAYER_1, Instant::now())
+            .unwrap();
+        assert_eq!(game.next_voting_player(), None);
+    }
+
+    #[test]
+    fn remaining_voting_items_are_empty_outside_voting() {
+        let game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        assert!(game.remaining_voting_items(PLAYER_1).is_empty());
+    }
+
+    #[test]
+    fn player_total_scores_add_up_the_scores_of_every_round() {
+        let mut game = get_game(&GameFsmState::EndOfRound);
+        game.continue_to_next_round(PLAYER_1).unwrap();
+        complete_round(&mut game);
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        let total_scores = game.player_total_scores();
+
+        assert_eq!(total_scores.len(), 3);
+        for player in players() {
+            let expected_score: isize = game
+                .rounds()
+                .iter()
+                .flat_map(|round| round.player_words[&player].iter())
+                .map(|word| word.score)
+                .sum();
+            assert_eq!(total_scores[&player], expected_score);
+        }
+        assert!(total_scores[PLAYER_3] > 0);
+    }
+
+    #[test]
+    fn ranking_is_sorted_by_score_and_then_by_nickname() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        // p1_w1 is matched by p3 only, p2 skips
+        game.set_player_voting_word(PLAYER_2, None).unwrap();
+        game.set_player_voting_word(PLAYER_3, Some(WORD_1.to_string()))
+            .unwrap();
+        game.accept_players_voting_words(PLAYER_1, Instant::now())
+            .unwrap();
+
+        assert_eq!(
+            game.ranking(),
+            vec![
+                (PLAYER_1.to_string(), 2),
+                (PLAYER_3.to_string(), 2),
+                (PLAYER_2.to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn score_breakdown_adds_up_to_the_total_scores() {
+        let mut game = get_game(&GameFsmState::EndOfRound);
+        game.continue_to_next_round(PLAYER_1).unwrap();
+        complete_round(&mut game);
+
+        let breakdown = game.score_breakdown();
+        let total_scores = game.player_total_scores();
+
+        assert_eq!(breakdown.len(), 3);
+        for player in players() {
+            let entries = &breakdown[&player];
+            assert_eq!(entries.len(), 2 * words().len());
+            assert!(entries.iter().any(|entry| entry.round == 1));
+            assert!(entries.iter().any(|entry| entry.round == 2));
+            assert_eq!(
+                entries.iter().map(|entry| entry.score).sum::<isize>(),
+                total_scores[&player]
+            );
+        }
+    }
+
+    #[test]
+    fn player_total_scores_include_participation_points() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                participation_points: Some(1),
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        for player in players() {
+            game.add_player(&player).unwrap();
+        }
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
+        game.add_player_words(PLAYER_3, vec!["unmatched".to_string()])
+            .unwrap();
+
+        while game.state() == &GameFsmState::PlayersSubmittingVotingWord {
+            let voting_item = game.rounds().last().unwrap().voting_item.clone().unwrap();
+            for player in players() {
+                // Players that don't have the word can't vote for it, ignore such error
+                let _ = game.set_player_voting_word(&player, Some(voting_item.word.clone()));
+            }
+            game.accept_players_voting_words(PLAYER_1, Instant::now())
+                .unwrap();
+        }
+
+        let total_scores = game.player_total_scores();
+
+        assert_eq!(game.state(), &GameFsmState::EndOfRound);
+        assert_eq!(total_scores[PLAYER_1], 5);
+        assert_eq!(total_scores[PLAYER_2], 5);
+        assert_eq!(total_scores[PLAYER_3], 1);
+        for (nickname, entries) in game.score_breakdown() {
+            assert_eq!(
+                entries.iter().map(|entry| entry.score).sum::<isize>(),
+                total_scores[&nickname]
+            );
+            assert_eq!(
+                entries
+                    .iter()
+                    .filter(|entry| entry.word.is_none())
+                    .map(|entry| entry.score)
+                    .collect::<Vec<isize>>(),
+                vec![1]
+            );
+        }
+    }
+
+    #[test]
+    fn player_total_scores_are_0_before_the_game_starts() {
+        let game = get_game(&GameFsmState::Lobby);
+
+        let total_scores = game.player_total_scores();
+
+        assert_eq!(total_scores.len(), 3);
+        assert!(total_scores.values().all(|score| *score == 0));
+    }
+
+    #[test]
+    fn last_words_are_empty_before_completing_a_round() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        assert!(game.last_words(PLAYER_1).is_empty());
+        complete_round(&mut game);
+
+        assert_eq!(game.last_words(PLAYER_1), words());
+    }
+
+    fn round_words(rounds: &[Round]) -> Vec<&str> {
+        rounds.iter().map(|round| round.word.as_str()).collect()
+    }
+
+    #[test]
+    fn game_state_includes_every_round_by_default() {
+        let game = get_game_with_rounds(&GameFsmState::EndOfGame, 3);
+
+        assert_eq!(game.rounds_in_state().len(), 3);
+        assert!(game.round_history().is_empty());
+    }
+
+    #[test]
+    fn game_state_only_includes_the_configured_trailing_rounds() {
+        let mut game = get_game_with_rounds(&GameFsmState::EndOfGame, 3);
+        game.settings.max_rounds_in_state = Some(2);
+
+        assert_eq!(
+            round_words(game.rounds_in_state()),
+            round_words(&game.rounds()[1..])
+        );
+        assert_eq!(
+            round_words(game.round_history()),
+            round_words(&game.rounds()[..1])
+        );
+    }
+
+    #[test]
+    fn game_state_always_includes_the_current_round() {
+        let mut game = get_game_with_rounds(&GameFsmState::EndOfGame, 3);
+        game.settings.max_rounds_in_state = Some(0);
+
+        assert_eq!(
+            round_words(game.rounds_in_state()),
+            round_words(&game.rounds()[2..])
+        );
+        assert_eq!(game.round_history().len(), 2);
+    }
+
+    #[test]
+    fn round_history_is_paged_up_to_the_page_size() {
+        let mut game = get_game_with_rounds(&GameFsmState::EndOfGame, 3);
+        game.settings.max_rounds_in_state = Some(0);
+        game.settings.round_history_page_size = 1;
+
+        let round_numbers =
+            |rounds: &[Round]| -> Vec<usize> { rounds.iter().map(|round| round.number).collect() };
+        assert_eq!(round_numbers(game.round_history_page(0, None)), vec![1]);
+        assert_eq!(round_numbers(game.round_history_page(1, Some(5))), vec![2]);
+        assert!(game.round_history_page(2, None).is_empty());
+        assert!(game.round_history_page(5, Some(0)).is_empty());
+    }
+
+    #[test]
+    fn last_words_are_kept_when_playing_again() {
+        let mut game = get_game_with_rounds(&GameFsmState::EndOfGame, 1);
+
+        game.play_again(PLAYER_1).unwrap();
+
+        assert_eq!(game.last_words(PLAYER_2), words());
+    }
+
+    #[test]
+    fn add_player_words_can_be_edited_while_submitting_words() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.add_player_words(PLAYER_1, words()).unwrap();
+
+        let result = game.add_player_words(PLAYER_1, vec!["other".to_string()]);
+
+        assert_eq!(result, Ok(vec!["other".to_string()]));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert_eq!(
+            game.rounds().last().unwrap().player_words[PLAYER_1][0].word,
+            "other"
+        );
+    }
+
+    #[test]
+    fn add_player_words_fails_when_resubmitted_after_the_round_moved_on_to_voting() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+
+        let result = game.add_player_words(PLAYER_3, vec!["other".to_string()]);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::WordsAlreadySubmitted(
+                PLAYER_3.to_string()
+            )))
+        );
+        let round = game.rounds().last().unwrap();
+        assert_eq!(
+            round.player_words[PLAYER_3]
+                .iter()
+                .map(|word| word.word.to_string())
+                .collect::<Vec<String>>(),
+            words()
+        );
+    }
+
+    #[test]
+    fn add_player_words_fails_when_state_is_not_players_submitting_words() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.add_player_words(PLAYER_1, words());
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::InvalidStateForWordsSubmission(
+                GameFsmState::Lobby,
+                GameFsmState::PlayersSubmittingWords
+            )))
+        );
+    }
+
+    #[test]
+    fn add_player_voting_word_works_with_valid_word() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+
+        let result = game.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+    }
+
+    #[test]
+    fn add_player_voting_word_works_with_empty_word() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+
+        let result = game.set_player_voting_word(PLAYER_2, None);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+    }
+
+    #[test]
+    fn clear_player_voting_word_makes_the_player_undecided() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.set_player_voting_word(PLAYER_2, None).unwrap();
+
+        let result = game.clear_player_voting_word(PLAYER_2);
+
+        assert_eq!(result, Ok(()));
+        assert!(!game
+            .rounds()
+            .last()
+            .unwrap()
+            .player_voting_words
+            .contains_key(PLAYER_2));
+    }
+
+    #[test]
+    fn clear_player_voting_word_fails_when_state_is_not_players_submitting_voting_word() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        let result = game.clear_player_voting_word(PLAYER_2);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::InvalidStateForVotingWordSubmission(
+                    GameFsmState::PlayersSubmittingWords,
+                    GameFsmState::PlayersSubmittingVotingWord
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn add_player_voting_word_fails_when_state_is_not_players_submitting_voting_word() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        let result = game.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()));
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::InvalidStateForVotingWordSubmission(
+                    GameFsmState::PlayersSubmittingWords,
+                    GameFsmState::PlayersSubmittingVotingWord
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn when_last_player_without_words_submission_is_disonnected_games_proceeds_to_voting() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        game.add_player_words(PLAYER_1, words()).unwrap();
+        game.add_player_words(PLAYER_2, words()).unwrap();
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+    }
+
+    #[test]
+    fn disconnected_player_is_kicked_after_missing_the_configured_rounds() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        game.settings.kick_after_missed_rounds = Some(2);
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
+
+        for _ in 0..2 {
+            assert_eq!(game.players().len(), 3);
+            game.add_player_words(PLAYER_1, words()).unwrap();
+            game.add_player_words(PLAYER_2, words()).unwrap();
+            while game.state() == &GameFsmState::PlayersSubmittingVotingWord {
+                game.accept_players_voting_words(PLAYER_1, Instant::now())
+                    .unwrap();
+            }
+            game.continue_to_next_round(PLAYER_1).unwrap();
+        }
+
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert_eq!(game.take_kicked_players(), vec![PLAYER_3.to_string()]);
+        assert!(game.take_kicked_players().is_empty());
+        assert_eq!(
+            game.players()
+                .iter()
+                .map(|player| player.nickname.as_str())
+                .collect::<Vec<&str>>(),
+            vec![PLAYER_1, PLAYER_2]
+        );
+        assert_eq!(
+            game.add_player(PLAYER_3),
+            Err(Error::Domain(DomainError::GameAlreadyInProgress(
+                "id".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn connected_player_is_kicked_after_not_submitting_words_for_the_configured_rounds() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        game.settings.kick_after_missed_rounds = Some(2);
+        game.start_game(PLAYER_1, 3).unwrap();
+
+        for round in 1..=2 {
+            assert_eq!(game.players().len(), 3);
+            game.add_player_words(PLAYER_1, words()).unwrap();
+            game.add_player_words(PLAYER_2, words()).unwrap();
+            // The time is up before the player submits anything
+            game.end_word_submission(round).unwrap();
+            while game.state() == &GameFsmState::PlayersSubmittingVotingWord {
+                game.accept_players_voting_words(PLAYER_1, Instant::now())
+                    .unwrap();
+            }
+            game.continue_to_next_round(PLAYER_1).unwrap();
+        }
 
-        assert_eq!(
-            result,
-            Err(Error::Domain(
-                DomainError::InvalidStateForVotingWordSubmission(
-                    GameFsmState::PlayersSubmittingWords,
-                    GameFsmState::PlayersSubmittingVotingWord
-                )
-            ))
-        );
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert_eq!(game.take_kicked_players(), vec![PLAYER_3.to_string()]);
+        assert_eq!(game.players().len(), 2);
     }
 
     #[test]
-    fn when_last_player_without_words_submission_is_disonnected_games_proceeds_to_voting() {
-        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+    fn reconnecting_resets_the_missed_rounds() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        game.settings.kick_after_missed_rounds = Some(1);
+        game.start_game(PLAYER_1, 3).unwrap();
+        game.disconnect_player(PLAYER_3, Instant::now()).unwrap();
         game.add_player_words(PLAYER_1, words()).unwrap();
         game.add_player_words(PLAYER_2, words()).unwrap();
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        while game.state() == &GameFsmState::PlayersSubmittingVotingWord {
+            game.accept_players_voting_words(PLAYER_1, Instant::now())
+                .unwrap();
+        }
 
-        game.disconnect_player(PLAYER_3).unwrap();
+        game.add_player(PLAYER_3).unwrap();
+        game.continue_to_next_round(PLAYER_1).unwrap();
 
-        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        assert!(game.take_kicked_players().is_empty());
+        assert_eq!(game.players().len(), 3);
     }
 
     #[test]
@@ -711,7 +2966,7 @@ mod tests {
     fn continue_to_next_voting_item_fails_when_state_is_not_players_submitting_voting_word() {
         let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
 
-        let result = game.accept_players_voting_words(PLAYER_1);
+        let result = game.accept_players_voting_words(PLAYER_1, Instant::now());
 
         assert_eq!(result, Err(Error::Internal("The fsm in state PlayersSubmittingWords can't transition with an event AcceptPlayersVotingWords. Error: 'cannot perform a state transition from the current state with the provided input'.".to_string())));
     }
@@ -720,7 +2975,7 @@ mod tests {
     fn continue_to_next_voting_item_fails_when_player_is_not_host() {
         let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
 
-        let result = game.accept_players_voting_words(PLAYER_2);
+        let result = game.accept_players_voting_words(PLAYER_2, Instant::now());
 
         assert_eq!(
             result,
@@ -730,14 +2985,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn voting_words_cannot_be_accepted_before_the_minimum_voting_item_time() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.settings.min_voting_item_seconds = 1;
+        let shown_at = Instant::now();
+        game.track_voting_item(shown_at);
+
+        let non_host_result = game.accept_players_voting_words(PLAYER_2, shown_at);
+        let too_soon_result = game.accept_players_voting_words(PLAYER_1, shown_at);
+        let later_result =
+            game.accept_players_voting_words(PLAYER_1, shown_at + Duration::from_secs(1));
+
+        assert_eq!(
+            non_host_result,
+            Err(Error::Domain(
+                DomainError::NonHostPlayerCannotContinueToNextVotingItem(PLAYER_2.to_string())
+            ))
+        );
+        assert_eq!(
+            too_soon_result,
+            Err(Error::Domain(DomainError::VotingItemTooSoon(1)))
+        );
+        assert!(later_result.is_ok());
+    }
+
+    #[test]
+    fn explicit_votes_are_required_from_every_connected_player_to_accept() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.settings.require_explicit_votes = true;
+        let voting_player = game.rounds[0].voting_item.clone().unwrap().player_nickname;
+        let voters: Vec<String> = [PLAYER_1, PLAYER_2, PLAYER_3]
+            .into_iter()
+            .filter(|nickname| *nickname != voting_player)
+            .map(String::from)
+            .collect();
+        game.rounds[0]
+            .player_voting_words
+            .retain(|nickname, _| *nickname == voting_player);
+
+        game.set_player_voting_word(&voters[0], None).unwrap();
+        let missing_vote_result = game.accept_players_voting_words(PLAYER_1, Instant::now());
+        game.disconnect_player(&voters[1], Instant::now()).unwrap();
+        let disconnected_voter_result = game.accept_players_voting_words(PLAYER_1, Instant::now());
+
+        assert_eq!(
+            missing_vote_result,
+            Err(Error::Domain(DomainError::NotEveryPlayerHasVoted(1)))
+        );
+        assert!(disconnected_voter_result.is_ok());
+    }
+
     #[test]
     fn continue_to_next_round_proceeds_to_next_voting_item() {
         let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
 
-        let result = game.accept_players_voting_words(PLAYER_1);
+        let result = game.accept_players_voting_words(PLAYER_1, Instant::now());
+
+        assert!(result.is_ok());
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+    }
+
+    #[test]
+    fn skip_voting_item_uses_the_word_without_scoring_the_votes() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+        game.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+
+        let result = game.skip_voting_item(PLAYER_1);
 
         assert_eq!(result, Ok(()));
         assert_eq!(game.state(), &GameFsmState::PlayersSubmittingVotingWord);
+        let round = game.rounds().last().unwrap();
+        let skipped_word = &round.player_words[PLAYER_1][0];
+        assert!(skipped_word.is_used);
+        assert_eq!(skipped_word.score, 0);
+        assert!(!round.player_words[PLAYER_2][0].is_used);
+        assert_eq!(
+            round.voting_item.as_ref().map(|item| item.word.as_str()),
+            Some(WORD_2)
+        );
+    }
+
+    #[test]
+    fn skip_voting_item_fails_when_player_is_not_host() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingVotingWord);
+
+        let result = game.skip_voting_item(PLAYER_2);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::NonHostPlayerCannotSkipVotingItem(PLAYER_2.to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn skip_voting_item_fails_when_state_is_not_players_submitting_voting_word() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+
+        let result = game.skip_voting_item(PLAYER_1);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::InvalidStateForSkippingVotingItem(
+                    GameFsmState::PlayersSubmittingWords,
+                    GameFsmState::PlayersSubmittingVotingWord
+                )
+            ))
+        );
     }
 
     #[test]
@@ -765,16 +3123,92 @@ mod tests {
     fn existing_player_can_rejoin_after_game_is_started() {
         let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
 
-        let _ = game.disconnect_player(PLAYER_2);
+        let _ = game.disconnect_player(PLAYER_2, Instant::now());
 
         let result = game.add_player(PLAYER_2);
 
-        assert_eq!(result, Ok(()));
+        assert_eq!(
+            result.map(|player| player.nickname),
+            Ok(PLAYER_2.to_string())
+        );
+    }
+
+    #[test]
+    fn reconnection_token_belongs_to_the_player_it_was_issued_to() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        let token = game.players()[1].reconnection_token.clone();
+        let _ = game.disconnect_player(PLAYER_2, Instant::now());
+
+        let nickname = game.reconnection_token_nickname(&token);
+
+        assert_eq!(nickname, Ok(PLAYER_2.to_string()));
+        assert_ne!(token, game.players()[0].reconnection_token);
+        assert_eq!(
+            game.add_player(PLAYER_2)
+                .map(|player| player.reconnection_token),
+            Ok(token)
+        );
+    }
+
+    #[test]
+    fn unknown_reconnection_token_is_rejected() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let token = game.players()[1].reconnection_token.clone();
+        game.kick_player(PLAYER_1, PLAYER_2).unwrap();
+
+        assert_eq!(
+            game.reconnection_token_nickname(&token),
+            Err(Error::Domain(DomainError::InvalidReconnectionToken))
+        );
+        assert!(game.reconnection_token_nickname("stale").is_err());
+    }
+
+    #[test]
+    fn rejoining_player_keeps_the_original_nickname_casing() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let _ = game.add_player("Bob");
+        let _ = game.disconnect_player("Bob", Instant::now());
+        let amount_of_players = game.players().len();
+
+        let result = game.add_player("bob");
+
+        assert_eq!(result.map(|player| player.nickname), Ok("Bob".to_string()));
+        assert_eq!(game.players().len(), amount_of_players);
+        assert!(game
+            .players()
+            .iter()
+            .any(|player| player.nickname == "Bob" && player.is_connected));
+    }
+
+    #[test]
+    fn add_player_fails_when_the_nickname_differs_only_in_casing_from_a_connected_player() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let _ = game.add_player("Bob");
+
+        let result = game.add_player("bob");
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::PlayerAlreadyExists(
+                "bob".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn kicked_player_cannot_rejoin_with_a_different_casing() {
+        let mut game = get_game(&GameFsmState::Lobby);
+        let _ = game.add_player("Bob");
+
+        let result = game.kick_player(PLAYER_1, "BOB");
+
+        assert_eq!(result, Ok("Bob".to_string()));
+        assert!(game.add_player("bob").is_err());
     }
 
     #[test]
     fn next_round_chooses_different_word() {
-        let amount_of_rounds: u8 = Game::default_words().len().try_into().unwrap();
+        let amount_of_rounds: u8 = game_words().len().try_into().unwrap();
         let mut used_words: HashSet<String> = HashSet::new();
         let mut game =
             get_game_with_rounds(&GameFsmState::PlayersSubmittingWords, amount_of_rounds);
@@ -790,7 +3224,7 @@ mod tests {
 
     #[test]
     fn word_is_repeated_when_more_rounds_than_words() {
-        let amount_of_rounds: u8 = (Game::default_words().len() + 1).try_into().unwrap();
+        let amount_of_rounds: u8 = (game_words().len() + 1).try_into().unwrap();
         let mut used_words: HashSet<String> = HashSet::new();
         let mut game =
             get_game_with_rounds(&GameFsmState::PlayersSubmittingWords, amount_of_rounds);
@@ -806,9 +3240,105 @@ mod tests {
         assert_eq!(game.state(), &GameFsmState::EndOfGame);
     }
 
+    #[test]
+    fn games_with_the_same_seed_choose_words_in_the_same_order() {
+        let settings = GameSettings {
+            word_shuffle_seed: Some(42),
+            ..GameSettings::default()
+        };
+        let round_words = |settings: &GameSettings| {
+            let mut game = Game::new(
+                "id",
+                None,
+                settings.clone(),
+                Game::default_words(),
+                Arc::default(),
+            );
+            (0..Game::default_words().len())
+                .map(|_| game.choose_random_word())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(round_words(&settings), round_words(&settings));
+    }
+
+    #[test]
+    fn reshuffled_words_never_repeat_the_previous_word() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings::default(),
+            vec!["first".to_string(), "second".to_string()],
+            Arc::default(),
+        );
+
+        let words: Vec<String> = (0..100).map(|_| game.choose_random_word()).collect();
+
+        assert!(words.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn a_single_word_is_chosen_again_after_a_reshuffle() {
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings::default(),
+            vec!["only".to_string()],
+            Arc::default(),
+        );
+
+        assert_eq!(game.choose_random_word(), "only");
+        assert_eq!(game.choose_random_word(), "only");
+    }
+
+    #[test]
+    fn fallback_games_draw_from_the_whole_built_in_list() {
+        let settings = GameSettings {
+            fallback_words_per_game: Some(7),
+            ..GameSettings::default()
+        };
+        let fallback_words = |seed| {
+            let settings = GameSettings {
+                word_shuffle_seed: Some(seed),
+                ..settings.clone()
+            };
+            Game::new("id", None, settings, vec![], Arc::default())
+                .words
+                .into_iter()
+                .map(|word| word.value)
+                .collect::<HashSet<String>>()
+        };
+
+        let game_1_words = fallback_words(1);
+        let game_2_words = fallback_words(2);
+
+        assert_eq!(game_1_words.len(), 7);
+        assert!(game_1_words.is_subset(&Game::default_words().into_iter().collect()));
+        assert_ne!(game_1_words, game_2_words);
+    }
+
+    #[test]
+    fn fallback_games_play_at_least_the_minimum_amount_of_words() {
+        let settings = GameSettings {
+            fallback_words_per_game: Some(0),
+            ..GameSettings::default()
+        };
+        let mut game = Game::new("id", None, settings, vec![], Arc::default());
+
+        assert_eq!(game.words.len(), usize::from(Game::MINIMUM_ROUNDS));
+        assert!(Game::default_words().contains(&game.choose_random_word()));
+    }
+
+    #[test]
+    fn fallback_games_use_every_built_in_word_by_default() {
+        let game = Game::new("id", None, GameSettings::default(), vec![], Arc::default());
+
+        assert_eq!(game.words.len(), Game::default_words().len());
+    }
+
     #[test]
     fn different_games_choose_words_in_different_order() {
-        let amount_of_rounds: u8 = Game::default_words().len().try_into().unwrap();
+        let amount_of_rounds: u8 = game_words().len().try_into().unwrap();
 
         let mut game_1_words: Vec<String> = vec![];
         let mut game_1 =
@@ -839,6 +3369,50 @@ mod tests {
             .any(|word_index| game_1_words[word_index] != game_2_words[word_index]));
     }
 
+    #[test]
+    fn first_voting_item_owner_varies_when_turn_order_is_shuffled() {
+        let amount_of_rounds = 20;
+        let mut game = Game::new(
+            "id",
+            None,
+            GameSettings {
+                shuffle_turn_order: true,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        for player in players() {
+            game.add_player(&player).unwrap();
+        }
+        game.start_game(PLAYER_1, amount_of_rounds).unwrap();
+
+        let mut first_voting_item_owners: HashSet<String> = HashSet::new();
+        for _ in 0..amount_of_rounds {
+            send_players_words(&mut game);
+            let round = game.rounds().last().unwrap();
+            first_voting_item_owners.insert(round.voting_item.clone().unwrap().player_nickname);
+            vote_all_words(&mut game);
+            game.continue_to_next_round(PLAYER_1).unwrap();
+        }
+
+        assert_eq!(game.state(), &GameFsmState::EndOfGame);
+        // Not deterministic, but the chance of the same player going first in every round is negligible
+        assert!(first_voting_item_owners.len() > 1);
+    }
+
+    #[test]
+    fn round_start_is_kept_until_the_round_ends() {
+        let mut game = get_game(&GameFsmState::PlayersSubmittingWords);
+        let round_started_at = game.round_started_at();
+
+        complete_round(&mut game);
+
+        assert_eq!(game.state(), &GameFsmState::EndOfRound);
+        assert!(round_started_at.is_some());
+        assert_eq!(game.round_started_at(), round_started_at);
+    }
+
     #[test]
     fn play_again_fails_when_player_is_not_host() {
         let mut game = get_game(&GameFsmState::EndOfGame);
@@ -856,20 +3430,134 @@ mod tests {
     #[test]
     fn play_again_proceeds_to_lobby() {
         let mut game = get_game(&GameFsmState::EndOfGame);
-        let expected_used_words = game.words.iter().filter(|word| word.is_used).count();
+        let played_words: Vec<String> = game
+            .rounds()
+            .iter()
+            .map(|round| round.word.to_string())
+            .collect();
 
         let result = game.play_again(PLAYER_1);
         let actual_used_words = game.words.iter().filter(|word| word.is_used).count();
+        let last_words: Vec<String> = game.words[game.words.len() - played_words.len()..]
+            .iter()
+            .map(|word| word.value.to_string())
+            .collect();
 
         assert_eq!(result, Ok(()));
         assert_eq!(game.state(), &GameFsmState::Lobby);
-        assert_eq!(actual_used_words, expected_used_words);
+        assert_eq!(actual_used_words, 0);
+        assert_eq!(last_words, played_words);
         assert!(game.rounds().is_empty());
         assert!(game.amount_of_rounds.is_none());
     }
 
+    #[test]
+    fn play_again_same_settings_fails_when_player_is_not_host() {
+        let mut game = get_game(&GameFsmState::EndOfGame);
+
+        let result = game.play_again_same_settings(PLAYER_2);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::NonHostPlayerCannotSendPlayAgain(PLAYER_2.to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn play_again_same_settings_fails_before_the_end_of_the_game() {
+        let mut game = get_game(&GameFsmState::EndOfRound);
+
+        let result = game.play_again_same_settings(PLAYER_1);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::InvalidStateForPostGameAction(
+                GameFsmState::EndOfRound,
+                GameFsmState::EndOfGame
+            )))
+        );
+        assert_eq!(game.state(), &GameFsmState::EndOfRound);
+    }
+
+    #[test]
+    fn play_again_same_settings_starts_a_new_game_with_the_same_rounds() {
+        let mut game = get_game(&GameFsmState::EndOfGame);
+        let amount_of_rounds = game.amount_of_rounds;
+        let played_words: HashSet<String> = game
+            .rounds()
+            .iter()
+            .map(|round| round.word.to_string())
+            .collect();
+
+        let result = game.play_again_same_settings(PLAYER_1);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(game.state(), &GameFsmState::PlayersSubmittingWords);
+        assert_eq!(game.amount_of_rounds, amount_of_rounds);
+        assert_eq!(game.rounds().len(), 1);
+        assert!(!played_words.contains(&game.rounds()[0].word));
+    }
+
+    #[test]
+    fn end_session_fails_when_player_is_not_host() {
+        let mut game = get_game(&GameFsmState::EndOfGame);
+
+        let result = game.end_session(PLAYER_2);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NonHostPlayerCannotEndSession(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert!(!game.is_session_ended());
+    }
+
+    #[test]
+    fn end_session_fails_before_the_end_of_the_game() {
+        let mut game = get_game(&GameFsmState::Lobby);
+
+        let result = game.end_session(PLAYER_1);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::InvalidStateForPostGameAction(
+                GameFsmState::Lobby,
+                GameFsmState::EndOfGame
+            )))
+        );
+        assert!(!game.is_session_ended());
+    }
+
+    #[test]
+    fn end_session_ends_the_session_at_the_end_of_the_game() {
+        let mut game = get_game(&GameFsmState::EndOfGame);
+
+        let result = game.end_session(PLAYER_1);
+
+        assert_eq!(result, Ok(()));
+        assert!(game.is_session_ended());
+        assert_eq!(game.state(), &GameFsmState::EndOfGame);
+    }
+
+    // Fewer words than the maximum rounds, so that a game can run out of them
+    fn game_words() -> Vec<String> {
+        ["summer", "space", "dog", "pizza", "rock", "picnic", "surf"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect()
+    }
+
     fn get_empty_game() -> Game {
-        Game::new("id", Game::default_words())
+        Game::new(
+            "id",
+            None,
+            GameSettings::default(),
+            game_words(),
+            Arc::default(),
+        )
     }
 
     fn get_game(state: &GameFsmState) -> Game {
@@ -917,12 +3605,17 @@ mod tests {
 
     fn complete_round(game: &mut Game) {
         send_players_words(game);
+        vote_all_words(game);
+    }
+
+    fn vote_all_words(game: &mut Game) {
         for word in words() {
             for player in players() {
                 // For simplicity in the test setup, we'll iterate over all the words, even if they are already used, ignore such error
                 let _ = game.set_player_voting_word(&player, Some(word.to_string()));
             }
-            game.accept_players_voting_words(PLAYER_1).unwrap();
+            game.accept_players_voting_words(PLAYER_1, Instant::now())
+                .unwrap();
         }
     }
 }
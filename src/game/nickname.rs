@@ -0,0 +1,71 @@
+use crate::error::domain_error::DomainError;
+use crate::error::Error;
+
+// A nickname as received when a player connects, surrounding whitespace is trimmed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Nickname(String);
+
+impl Nickname {
+    const MINIMUM_LENGTH: usize = 1;
+    const MAXIMUM_LENGTH: usize = 20;
+
+    pub fn try_new(value: &str) -> Result<Nickname, Error> {
+        let value = value.trim();
+        let length = value.chars().count();
+        if !(Nickname::MINIMUM_LENGTH..=Nickname::MAXIMUM_LENGTH).contains(&length)
+            || value.chars().any(|character| character.is_control())
+        {
+            return Err(Error::Domain(DomainError::InvalidNickname(
+                value.to_string(),
+            )));
+        }
+        Ok(Nickname(value.to_string()))
+    }
+}
+
+impl From<Nickname> for String {
+    fn from(val: Nickname) -> Self {
+        val.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::domain_error::DomainError;
+    use crate::error::Error;
+
+    use super::Nickname;
+
+    #[test]
+    fn valid_nickname_is_accepted_and_trimmed() {
+        let nickname = Nickname::try_new("  Bob ");
+
+        assert_eq!(nickname.map(String::from), Ok("Bob".to_string()));
+    }
+
+    #[test]
+    fn empty_or_whitespace_only_nickname_is_rejected() {
+        for value in ["", "   "] {
+            assert_eq!(
+                Nickname::try_new(value),
+                Err(Error::Domain(DomainError::InvalidNickname("".to_string())))
+            );
+        }
+    }
+
+    #[test]
+    fn too_long_nickname_is_rejected() {
+        let value = "a".repeat(Nickname::MAXIMUM_LENGTH + 1);
+
+        assert_eq!(
+            Nickname::try_new(&value),
+            Err(Error::Domain(DomainError::InvalidNickname(value)))
+        );
+        assert!(Nickname::try_new(&"a".repeat(Nickname::MAXIMUM_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn nickname_with_control_characters_is_rejected() {
+        assert!(Nickname::try_new("Bo\u{0007}b").is_err());
+    }
+}
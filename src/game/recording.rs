@@ -0,0 +1,582 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use tokio::task::{self, JoinHandle};
+
+use crate::config::GameSettings;
+use crate::game::Game;
+
+// Every command that changes the state of a Game, recorded one per line so that a session can be
+// replayed to reproduce a bug
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum RecordedCommand {
+    #[serde(rename_all = "camelCase")]
+    GameCreated {
+        title: Option<String>,
+        // Boxed, the settings would otherwise make every recorded command as large as this one
+        settings: Box<GameSettings>,
+        words: Vec<String>,
+        #[serde(default)]
+        blocklist: Vec<String>,
+        // Missing in sessions recorded before it was, their random choices can't be replayed
+        #[serde(default)]
+        rng_seed: Option<u64>,
+    },
+    #[serde(rename_all = "camelCase")]
+    AddPlayer { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    DisconnectPlayer { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    StartGame {
+        nickname: String,
+        amount_of_rounds: u8,
+    },
+    #[serde(rename_all = "camelCase")]
+    AddPlayerWords {
+        nickname: String,
+        words: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    SetPlayerVotingWord {
+        nickname: String,
+        word: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ClearPlayerVotingWord { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    RejectPlayerMatchedWord {
+        nickname: String,
+        rejected_player: String,
+        rejected_word: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    RemoveWord {
+        nickname: String,
+        player: String,
+        word: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    KickPlayer {
+        nickname: String,
+        kicked_player: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    MergePlayers {
+        nickname: String,
+        source: String,
+        target: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    TransferHost { nickname: String, new_host: String },
+    #[serde(rename_all = "camelCase")]
+    AcceptPlayersVotingWords { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    SkipVotingItem { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    SetTitle { nickname: String, title: String },
+    #[serde(rename_all = "camelCase")]
+    ContinueToNextRound { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    PlayAgain { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    PlayAgainSameSettings { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    EndSession { nickname: String },
+    #[serde(rename_all = "camelCase")]
+    RevealWord { round: usize },
+    #[serde(rename_all = "camelCase")]
+    EndWordSubmission { round: usize },
+    #[serde(rename_all = "camelCase")]
+    StartVoting { round: usize },
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    pub timestamp_millis: u128,
+    pub command: RecordedCommand,
+}
+
+impl RecordedCommand {
+    // Errors are ignored, the same command failed in the same way when it was recorded. The time is
+    // the one the command was recorded at, relative to the start of the replay
+    fn apply(&self, game: &mut Game, now: Instant) {
+        let _ = match self {
+            RecordedCommand::GameCreated { .. } => Ok(()),
+            RecordedCommand::AddPlayer { nickname } => game.add_player(nickname).map(|_| ()),
+            RecordedCommand::DisconnectPlayer { nickname } => {
+                game.disconnect_player(nickname, now).map(|_| ())
+            }
+            RecordedCommand::StartGame {
+                nickname,
+                amount_of_rounds,
+            } => game.start_game(nickname, *amount_of_rounds),
+            RecordedCommand::AddPlayerWords { nickname, words } => {
+                game.add_player_words(nickname, words.clone()).map(|_| ())
+            }
+            RecordedCommand::SetPlayerVotingWord { nickname, word } => {
+                game.set_player_voting_word(nickname, word.clone())
+            }
+            RecordedCommand::ClearPlayerVotingWord { nickname } => {
+                game.clear_player_voting_word(nickname)
+            }
+            RecordedCommand::RejectPlayerMatchedWord {
+                nickname,
+                rejected_player,
+                rejected_word,
+            } => game.reject_player_word(nickname, rejected_player, rejected_word),
+            RecordedCommand::RemoveWord {
+                nickname,
+                player,
+                word,
+            } => game.remove_word(nickname, player, word),
+            RecordedCommand::KickPlayer {
+                nickname,
+                kicked_player,
+            } => game.kick_player(nickname, kicked_player).map(|_| ()),
+            RecordedCommand::MergePlayers {
+                nickname,
+                source,
+                target,
+            } => game.merge_players(nickname, source, target).map(|_| ()),
+            RecordedCommand::TransferHost { nickname, new_host } => {
+                game.transfer_host(nickname, new_host)
+            }
+            RecordedCommand::AcceptPlayersVotingWords { nickname } => {
+                game.accept_players_voting_words(nickname, now).map(|_| ())
+            }
+            RecordedCommand::SkipVotingItem { nickname } => game.skip_voting_item(nickname),
+            RecordedCommand::SetTitle { nickname, title } => game.set_title(nickname, title),
+            RecordedCommand::ContinueToNextRound { nickname } => {
+                game.continue_to_next_round(nickname)
+            }
+            RecordedCommand::PlayAgain { nickname } => game.play_again(nickname),
+            RecordedCommand::PlayAgainSameSettings { nickname } => {
+                game.play_again_same_settings(nickname)
+            }
+            RecordedCommand::EndSession { nickname } => game.end_session(nickname),
+            RecordedCommand::RevealWord { round } => {
+                game.reveal_next_word_stage(*round);
+                Ok(())
+            }
+            RecordedCommand::EndWordSubmission { round } => game.end_word_submission(*round),
+            RecordedCommand::StartVoting { round } => game.start_voting(*round),
+        };
+    }
+}
+
+// The records are written on a blocking task, so that the disk never holds up the game actor
+pub struct SessionRecorder {
+    records_tx: mpsc::Sender<Record>,
+    writer: JoinHandle<()>,
+}
+
+impl SessionRecorder {
+    pub fn create(directory: &str, game: &Game) -> Self {
+        let path = SessionRecorder::path(directory, game.id());
+        let directory = directory.to_string();
+        let (records_tx, records_rx) = mpsc::channel();
+        let writer = task::spawn_blocking(move || {
+            SessionRecorder::write_records(&directory, &path, records_rx)
+        });
+        let recorder = SessionRecorder { records_tx, writer };
+        recorder.record(RecordedCommand::GameCreated {
+            title: game.title.clone(),
+            settings: Box::new(game.settings.clone()),
+            words: game.word_order(),
+            blocklist: game.blocklist.iter().cloned().collect(),
+            rng_seed: Some(game.rng_seed()),
+        });
+        recorder
+    }
+
+    pub fn path(directory: &str, game_id: &str) -> PathBuf {
+        Path::new(directory).join(format!("{game_id}.jsonl"))
+    }
+
+    pub fn record(&self, command: RecordedCommand) {
+        let record = Record {
+            timestamp_millis: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis())
+                .unwrap_or_default(),
+            command,
+        };
+        // Only fails when the file could not be opened, which the writer already logged
+        let _ = self.records_tx.send(record);
+    }
+
+    // Waits until every record is written to the file
+    pub async fn close(self) {
+        drop(self.records_tx);
+        if let Err(error) = self.writer.await {
+            log::error!("The session recording writer panicked. Error: '{error}'.");
+        }
+    }
+
+    fn write_records(directory: &str, path: &Path, records_rx: mpsc::Receiver<Record>) {
+        let file = fs::create_dir_all(directory)
+            .and_then(|_| OpenOptions::new().create(true).append(true).open(path));
+        let mut writer = match file {
+            Ok(file) => BufWriter::new(file),
+            Err(error) => {
+                log::error!(
+                    "Could not create the session recording file. Path: '{}', Error: '{error}'.",
+                    path.display()
+                );
+                return;
+            }
+        };
+        while let Ok(record) = records_rx.recv() {
+            // Flushed once the pending records are written, a crash only loses the last burst
+            for record in iter::once(record).chain(records_rx.try_iter()) {
+                let result = serde_json::to_string(&record)
+                    .map_err(std::io::Error::from)
+                    .and_then(|line| writeln!(writer, "{line}"));
+                if let Err(error) = result {
+                    log::error!("Could not write to the session recording file. Error: '{error}'.");
+                }
+            }
+            if let Err(error) = writer.flush() {
+                log::error!("Could not write to the session recording file. Error: '{error}'.");
+            }
+        }
+    }
+}
+
+pub fn read_records(path: &Path) -> Result<Vec<Record>, std::io::Error> {
+    BufReader::new(File::open(path)?)
+        .lines()
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
+
+pub fn replay_file(path: &Path) -> Result<Game, std::io::Error> {
+    let id = path
+        .file_stem()
+        .map(|id| id.to_string_lossy().to_string())
+        .unwrap_or_default();
+    Ok(replay(&id, &read_records(path)?))
+}
+
+pub fn replay(id: &str, records: &[Record]) -> Game {
+    let (title, settings, words, blocklist, rng_seed) = match records
+        .first()
+        .map(|record| &record.command)
+    {
+        Some(RecordedCommand::GameCreated {
+            title,
+            settings,
+            words,
+            blocklist,
+            rng_seed,
+        }) => (
+            title.clone(),
+            settings.as_ref().clone(),
+            words.clone(),
+            blocklist.clone(),
+            *rng_seed,
+        ),
+        _ => {
+            log::warn!("The recorded session doesn't start with GameCreated, replaying with the default settings and words. GameId: '{id}'.");
+            (
+                None,
+                GameSettings::default(),
+                Game::default_words(),
+                Vec::default(),
+                None,
+            )
+        }
+    };
+    let blocklist = Arc::new(blocklist.into_iter().collect());
+    let rng_seed = rng_seed.unwrap_or_else(|| thread_rng().gen());
+    let mut game = Game::with_word_order(id, title, settings, words, blocklist, rng_seed);
+    let replay_started_at = Instant::now();
+    let first_timestamp_millis = records
+        .first()
+        .map(|record| record.timestamp_millis)
+        .unwrap_or_default();
+    for record in records {
+        let elapsed_millis = record
+            .timestamp_millis
+            .saturating_sub(first_timestamp_millis);
+        let now = replay_started_at + Duration::from_millis(elapsed_millis as u64);
+        record.command.apply(&mut game, now);
+        game.track_voting_item(now);
+    }
+    game
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use crate::config::GameSettings;
+    use crate::game::Game;
+
+    use super::{replay_file, RecordedCommand, SessionRecorder};
+
+    static PLAYER_1: &str = "p1";
+    static PLAYER_2: &str = "p2";
+    static PLAYER_3: &str = "p3";
+
+    #[tokio::test]
+    async fn replaying_a_recorded_session_reaches_the_same_state() {
+        let mut game = Game::new(
+            &game_id("replaying_a_recorded_session_reaches_the_same_state"),
+            Some("title".to_string()),
+            GameSettings::default(),
+            Game::default_words(),
+            Arc::default(),
+        );
+        let replayed_game = record_and_replay(
+            &mut game,
+            vec![
+                add_player(PLAYER_1),
+                add_player(PLAYER_2),
+                add_player(PLAYER_3),
+                RecordedCommand::StartGame {
+                    nickname: PLAYER_1.to_string(),
+                    amount_of_rounds: 1,
+                },
+                add_player_words(PLAYER_1, &["w1", "w2"]),
+                add_player_words(PLAYER_2, &["w1", "w3"]),
+                add_player_words(PLAYER_3, &["w4", "w2"]),
+                RecordedCommand::SetPlayerVotingWord {
+                    nickname: PLAYER_2.to_string(),
+                    word: Some("w1".to_string()),
+                },
+                RecordedCommand::SetPlayerVotingWord {
+                    nickname: PLAYER_3.to_string(),
+                    word: None,
+                },
+                RecordedCommand::AcceptPlayersVotingWords {
+                    nickname: PLAYER_1.to_string(),
+                },
+                RecordedCommand::SetPlayerVotingWord {
+                    nickname: PLAYER_3.to_string(),
+                    word: Some("w2".to_string()),
+                },
+                RecordedCommand::AcceptPlayersVotingWords {
+                    nickname: PLAYER_1.to_string(),
+                },
+                RecordedCommand::DisconnectPlayer {
+                    nickname: PLAYER_2.to_string(),
+                },
+            ],
+        )
+        .await;
+
+        assert_eq!(replayed_game.title(), game.title());
+        assert_eq!(replayed_game.state(), game.state());
+        // The reconnection tokens are random, a replay issues its own
+        let players = |game: &Game| {
+            game.players()
+                .iter()
+                .map(|player| (player.nickname.clone(), player.is_host, player.is_connected))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(players(&replayed_game), players(&game));
+        assert_eq!(replayed_game.word_order(), game.word_order());
+        assert_eq!(replayed_game.rounds().len(), game.rounds().len());
+        for (replayed_round, round) in replayed_game.rounds().iter().zip(game.rounds()) {
+            assert_eq!(replayed_round.word, round.word);
+            assert_eq!(scores(replayed_round), scores(round));
+        }
+    }
+
+    #[tokio::test]
+    async fn replayed_game_draws_the_same_next_word() {
+        let mut game = Game::new(
+            &game_id("replayed_game_draws_the_same_next_word"),
+            None,
+            GameSettings::default(),
+            Game::default_words(),
+            Arc::default(),
+        );
+        let mut replayed_game = record_and_replay(
+            &mut game,
+            vec![
+                add_player(PLAYER_1),
+                add_player(PLAYER_2),
+                add_player(PLAYER_3),
+                RecordedCommand::StartGame {
+                    nickname: PLAYER_1.to_string(),
+                    amount_of_rounds: 2,
+                },
+                add_player_words(PLAYER_1, &["w1"]),
+                add_player_words(PLAYER_2, &["w2"]),
+                add_player_words(PLAYER_3, &["w3"]),
+                RecordedCommand::AcceptPlayersVotingWords {
+                    nickname: PLAYER_1.to_string(),
+                },
+                RecordedCommand::AcceptPlayersVotingWords {
+                    nickname: PLAYER_1.to_string(),
+                },
+                RecordedCommand::AcceptPlayersVotingWords {
+                    nickname: PLAYER_1.to_string(),
+                },
+            ],
+        )
+        .await;
+
+        assert_eq!(word_pool(&replayed_game), word_pool(&game));
+        game.continue_to_next_round(PLAYER_1).unwrap();
+        replayed_game.continue_to_next_round(PLAYER_1).unwrap();
+        assert_eq!(
+            replayed_game.rounds().last().unwrap().word,
+            game.rounds().last().unwrap().word
+        );
+        assert_eq!(word_pool(&replayed_game), word_pool(&game));
+    }
+
+    #[tokio::test]
+    async fn replayed_game_shuffles_the_turn_order_the_same_way() {
+        let mut game = Game::new(
+            &game_id("replayed_game_shuffles_the_turn_order_the_same_way"),
+            None,
+            GameSettings {
+                shuffle_turn_order: true,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        let replayed_game = record_and_replay(
+            &mut game,
+            vec![
+                add_player(PLAYER_1),
+                add_player(PLAYER_2),
+                add_player(PLAYER_3),
+                RecordedCommand::StartGame {
+                    nickname: PLAYER_1.to_string(),
+                    amount_of_rounds: 1,
+                },
+                add_player_words(PLAYER_1, &["w1"]),
+                add_player_words(PLAYER_2, &["w2"]),
+                add_player_words(PLAYER_3, &["w3"]),
+            ],
+        )
+        .await;
+
+        assert_eq!(voting_order(&replayed_game), voting_order(&game));
+    }
+
+    #[tokio::test]
+    async fn replayed_game_autofills_the_same_words() {
+        let mut game = Game::new(
+            &game_id("replayed_game_autofills_the_same_words"),
+            None,
+            GameSettings {
+                autofill_disconnected_words: true,
+                ..GameSettings::default()
+            },
+            Game::default_words(),
+            Arc::default(),
+        );
+        let replayed_game = record_and_replay(
+            &mut game,
+            vec![
+                add_player(PLAYER_1),
+                add_player(PLAYER_2),
+                add_player(PLAYER_3),
+                RecordedCommand::StartGame {
+                    nickname: PLAYER_1.to_string(),
+                    amount_of_rounds: 1,
+                },
+                RecordedCommand::DisconnectPlayer {
+                    nickname: PLAYER_3.to_string(),
+                },
+                add_player_words(PLAYER_1, &["w1"]),
+                add_player_words(PLAYER_2, &["w2"]),
+            ],
+        )
+        .await;
+
+        let round = game.rounds().last().unwrap();
+        assert!(!round.player_words[PLAYER_3].is_empty());
+        assert_eq!(
+            scores(replayed_game.rounds().last().unwrap()),
+            scores(round)
+        );
+    }
+
+    // Records the commands while applying them to the game, then replays the recorded session
+    async fn record_and_replay(game: &mut Game, commands: Vec<RecordedCommand>) -> Game {
+        let directory = std::env::temp_dir().join("headcrab_recording_test");
+        let directory = directory.to_str().unwrap();
+        let path = SessionRecorder::path(directory, game.id());
+        let _ = std::fs::remove_file(&path);
+        let recorder = SessionRecorder::create(directory, game);
+
+        for command in commands {
+            recorder.record(command.clone());
+            command.apply(game, Instant::now());
+        }
+        recorder.close().await;
+
+        let replayed_game = replay_file(&path).unwrap();
+        let _ = std::fs::remove_file(path);
+        replayed_game
+    }
+
+    // The tests share the recordings directory, with every process running them
+    fn game_id(test_name: &str) -> String {
+        format!("{test_name}_{}", std::process::id())
+    }
+
+    fn voting_order(game: &Game) -> Vec<String> {
+        game.rounds()
+            .last()
+            .unwrap()
+            .remaining_voting_items()
+            .into_iter()
+            .map(|item| item.player_nickname)
+            .collect()
+    }
+
+    fn word_pool(game: &Game) -> Vec<(String, bool)> {
+        game.words
+            .iter()
+            .map(|word| (word.value.to_string(), word.is_used))
+            .collect()
+    }
+
+    fn add_player(nickname: &str) -> RecordedCommand {
+        RecordedCommand::AddPlayer {
+            nickname: nickname.to_string(),
+        }
+    }
+
+    fn add_player_words(nickname: &str, words: &[&str]) -> RecordedCommand {
+        RecordedCommand::AddPlayerWords {
+            nickname: nickname.to_string(),
+            words: words.iter().map(|word| word.to_string()).collect(),
+        }
+    }
+
+    fn scores(round: &crate::round::Round) -> BTreeMap<String, Vec<(String, isize, bool)>> {
+        round
+            .player_words
+            .iter()
+            .map(|(nickname, words)| {
+                (
+                    nickname.to_string(),
+                    words
+                        .iter()
+                        .map(|word| (word.word.to_string(), word.score, word.is_used))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
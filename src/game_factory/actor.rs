@@ -1,6 +1,8 @@
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio::sync::oneshot::Sender as OneshotSender;
+use tokio::task::JoinSet;
 
 use crate::config::GameSettings;
 use crate::error::Error;
@@ -16,8 +18,12 @@ pub struct GameFactoryActor {
 
 impl GameFactoryActor {
     /// Runs the GameFactory Actor in background and returns a Client to communicate with it
-    pub fn spawn(game_settings: GameSettings, words: Vec<String>) -> GameFactoryClient {
-        let game_factory = GameFactory::new(game_settings, words);
+    pub fn spawn(
+        game_settings: GameSettings,
+        words: HashMap<String, Vec<String>>,
+        blocklist: HashSet<String>,
+    ) -> GameFactoryClient {
+        let game_factory = GameFactory::new(game_settings, words, blocklist);
         let (game_factory_tx, game_factory_rx): (
             Sender<GameFactoryCommand>,
             Receiver<GameFactoryCommand>,
@@ -32,20 +38,28 @@ impl GameFactoryActor {
             .start(),
         );
 
-        GameFactoryClient { game_factory_tx }
+        GameFactoryClient::new(game_factory_tx)
     }
 
     async fn start(mut self) {
         while let Some(message) = self.game_factory_rx.recv().await {
             let response = match message {
-                GameFactoryCommand::CreateGame { response_channel } => {
-                    let game_id = self.game_factory.create_new_game(GameFactoryClient {
-                        game_factory_tx: self.game_factory_tx.clone(),
-                    });
-                    Some((
-                        Ok(GameFactoryEvent::GameCreated { game_id }),
-                        response_channel,
-                    ))
+                GameFactoryCommand::CreateGame {
+                    title,
+                    category,
+                    words,
+                    response_channel,
+                } => {
+                    let result = self
+                        .game_factory
+                        .create_new_game(
+                            title,
+                            category,
+                            words,
+                            GameFactoryClient::new(self.game_factory_tx.clone()),
+                        )
+                        .map(|game_id| GameFactoryEvent::GameCreated { game_id });
+                    Some((result, response_channel))
                 }
                 GameFactoryCommand::RemoveGame { game_id } => {
                     let _ = self.game_factory.remove_game(&game_id);
@@ -61,6 +75,15 @@ impl GameFactoryActor {
                         .map(|game| GameFactoryEvent::GameActor { game: game.clone() });
                     Some((result, response_channel))
                 }
+                GameFactoryCommand::ShutDown { response_channel } => {
+                    // All at once, a game that already stopped on its own has nothing left to close
+                    let mut closing_games = JoinSet::new();
+                    for game in self.game_factory.shut_down() {
+                        closing_games.spawn(async move { game.shut_down().await });
+                    }
+                    while closing_games.join_next().await.is_some() {}
+                    Some((Ok(GameFactoryEvent::GamesClosed), response_channel))
+                }
             };
             if let Some((result, response_tx)) = response {
                 let event = match result {
@@ -78,6 +101,9 @@ impl GameFactoryActor {
 #[derive(Debug)]
 pub(crate) enum GameFactoryCommand {
     CreateGame {
+        title: Option<String>,
+        category: Option<String>,
+        words: Option<Vec<String>>,
         response_channel: OneshotSender<GameFactoryEvent>,
     },
     RemoveGame {
@@ -87,6 +113,9 @@ pub(crate) enum GameFactoryCommand {
         game_id: String,
         response_channel: OneshotSender<GameFactoryEvent>,
     },
+    ShutDown {
+        response_channel: OneshotSender<GameFactoryEvent>,
+    },
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -94,6 +123,7 @@ pub(crate) enum GameFactoryCommand {
 pub(crate) enum GameFactoryEvent {
     GameCreated { game_id: String },
     GameActor { game: GameClient },
+    GamesClosed,
     Error { error: Error },
 }
 
@@ -106,6 +136,7 @@ impl Display for GameFactoryEvent {
                 GameFactoryEvent::GameCreated { game_id } =>
                     format!("GameCreated(game_id: {game_id})"),
                 GameFactoryEvent::GameActor { game: _ } => "GameActor".to_string(),
+                GameFactoryEvent::GamesClosed => "GamesClosed".to_string(),
                 GameFactoryEvent::Error { error } => format!("Error '{error}'").to_string(),
             }
         )
@@ -1,17 +1,37 @@
+use std::time::Duration;
+
 use tokio::sync::mpsc::Sender;
 use tokio::sync::oneshot::error::RecvError;
 use tokio::sync::oneshot::{self, Receiver as OneshotReceiver, Sender as OneshotSender};
+use tokio::time;
 
+use crate::error::external_error::ExternalError;
 use crate::error::Error;
 use crate::game::actor_client::GameClient;
 use crate::game_factory::actor::{GameFactoryCommand, GameFactoryEvent};
 
 pub struct GameFactoryClient {
-    pub(super) game_factory_tx: Sender<GameFactoryCommand>,
+    game_factory_tx: Sender<GameFactoryCommand>,
+    // Bounds how long a request waits on a saturated or stalled GameFactory actor
+    timeout: Duration,
 }
 
 impl GameFactoryClient {
-    pub async fn create_game(&self) -> Result<String, Error> {
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub(super) fn new(game_factory_tx: Sender<GameFactoryCommand>) -> Self {
+        Self {
+            game_factory_tx,
+            timeout: GameFactoryClient::TIMEOUT,
+        }
+    }
+
+    pub async fn create_game(
+        &self,
+        title: Option<String>,
+        category: Option<String>,
+        words: Option<Vec<String>>,
+    ) -> Result<String, Error> {
         let (tx, rx): (
             OneshotSender<GameFactoryEvent>,
             OneshotReceiver<GameFactoryEvent>,
@@ -19,13 +39,19 @@ impl GameFactoryClient {
 
         self.send_command(
             GameFactoryCommand::CreateGame {
+                title,
+                category,
+                words,
                 response_channel: tx,
             },
             "GameFactoryCommand::CreateGame",
         )
         .await?;
 
-        match rx.await {
+        match self
+            .receive_event(rx, "GameFactoryCommand::CreateGame")
+            .await?
+        {
             Ok(GameFactoryEvent::GameCreated { game_id }) => Ok(game_id),
             error => Err(GameFactoryClient::handle_event_error(error)),
         }
@@ -56,20 +82,64 @@ impl GameFactoryClient {
         )
         .await?;
 
-        match rx.await {
+        match self
+            .receive_event(rx, "GameFactoryCommand::GetGameActor")
+            .await?
+        {
             Ok(GameFactoryEvent::GameActor { game }) => Ok(game),
             error => Err(GameFactoryClient::handle_event_error(error)),
         }
     }
 
+    pub async fn shut_down(&self) -> Result<(), Error> {
+        let (tx, rx): (
+            OneshotSender<GameFactoryEvent>,
+            OneshotReceiver<GameFactoryEvent>,
+        ) = oneshot::channel();
+
+        self.send_command(
+            GameFactoryCommand::ShutDown {
+                response_channel: tx,
+            },
+            "GameFactoryCommand::ShutDown",
+        )
+        .await?;
+
+        match self
+            .receive_event(rx, "GameFactoryCommand::ShutDown")
+            .await?
+        {
+            Ok(GameFactoryEvent::GamesClosed) => Ok(()),
+            error => Err(GameFactoryClient::handle_event_error(error)),
+        }
+    }
+
     async fn send_command(
         &self,
         command: GameFactoryCommand,
         command_name: &str,
     ) -> Result<(), Error> {
-        self.game_factory_tx.send(command).await.map_err(|error| {
-            Error::log_and_create_internal(&format!("The GameFactory channel is closed, cloud not send command '{command_name}'. Error: '{error}'"))
-        })
+        match time::timeout(self.timeout, self.game_factory_tx.send(command)).await {
+            Ok(result) => result.map_err(|error| {
+                Error::log_and_create_internal(&format!("The GameFactory channel is closed, cloud not send command '{command_name}'. Error: '{error}'"))
+            }),
+            Err(_) => Err(GameFactoryClient::timeout_error(command_name)),
+        }
+    }
+
+    async fn receive_event(
+        &self,
+        rx: OneshotReceiver<GameFactoryEvent>,
+        command_name: &str,
+    ) -> Result<Result<GameFactoryEvent, RecvError>, Error> {
+        time::timeout(self.timeout, rx)
+            .await
+            .map_err(|_| GameFactoryClient::timeout_error(command_name))
+    }
+
+    fn timeout_error(command_name: &str) -> Error {
+        log::error!("The GameFactory did not respond in time. Command: '{command_name}'.");
+        Error::External(ExternalError::GameFactoryTimeout(command_name.to_string()))
     }
 
     fn handle_event_error(error: Result<GameFactoryEvent, RecvError>) -> Error {
@@ -84,3 +154,34 @@ impl GameFactoryClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use crate::error::{external_error::ExternalError, Error};
+
+    use super::GameFactoryClient;
+
+    #[tokio::test]
+    async fn create_game_times_out_when_game_factory_is_stalled() {
+        // Nobody reads from the receiver, as if the GameFactory actor had fallen behind
+        let (game_factory_tx, _game_factory_rx) = mpsc::channel(1);
+        let client = GameFactoryClient {
+            game_factory_tx,
+            timeout: Duration::from_millis(50),
+        };
+
+        let first_result = client.create_game(None, None, None).await;
+        // The channel is now full, so this one times out while sending the command
+        let second_result = client.create_game(None, None, None).await;
+
+        let expected_error = Err(Error::External(ExternalError::GameFactoryTimeout(
+            "GameFactoryCommand::CreateGame".to_string(),
+        )));
+        assert_eq!(first_result, expected_error);
+        assert_eq!(second_result, expected_error);
+    }
+}
@@ -2,58 +2,176 @@ pub mod actor;
 pub mod actor_client;
 
 use rand::distributions::{Alphanumeric, DistString};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::config::GameSettings;
 use crate::error::domain_error::DomainError;
+use crate::error::external_error::ExternalError;
 use crate::error::Error;
 use crate::game::actor::GameActor;
 use crate::game::actor_client::GameClient;
+use crate::game::Game;
 use crate::game_factory::actor_client::GameFactoryClient;
+use crate::metrics::GAMES_CREATED;
+use crate::startup::normalize_words;
+
+pub const DEFAULT_WORD_CATEGORY: &str = "default";
 
 pub struct GameFactory {
     game_channels: HashMap<String, GameClient>,
+    closed_games: HashMap<String, Instant>,
+    // When the games of the last second were created, to enforce max_creates_per_second
+    recent_creations: VecDeque<Instant>,
     game_settings: GameSettings,
-    words: Vec<String>,
+    // By category, the default category is used when a game does not ask for a known one
+    words: HashMap<String, Vec<String>>,
+    // Shared by every game, it is loaded once at startup
+    blocklist: Arc<HashSet<String>>,
+    // Once set, no more games are created
+    is_shutting_down: bool,
 }
 
 impl GameFactory {
-    pub fn new(game_settings: GameSettings, words: Vec<String>) -> Self {
+    pub fn new(
+        game_settings: GameSettings,
+        words: HashMap<String, Vec<String>>,
+        blocklist: HashSet<String>,
+    ) -> Self {
         GameFactory {
             game_channels: HashMap::default(),
+            closed_games: HashMap::default(),
+            recent_creations: VecDeque::default(),
             game_settings,
             words,
+            blocklist: Arc::new(blocklist),
+            is_shutting_down: false,
         }
     }
 
-    pub fn create_new_game(&mut self, game_factory: GameFactoryClient) -> String {
+    pub fn create_new_game(
+        &mut self,
+        title: Option<String>,
+        category: Option<String>,
+        words: Option<Vec<String>>,
+        game_factory: GameFactoryClient,
+    ) -> Result<String, Error> {
+        if self.is_shutting_down {
+            return Err(Error::External(ExternalError::ServerShuttingDown));
+        }
+        let title = title
+            .map(|title| Game::validate_title(&title))
+            .transpose()?;
+        if let Some(max_creates_per_second) = self.game_settings.max_creates_per_second {
+            if !self.try_record_creation(max_creates_per_second, Instant::now()) {
+                return Err(Error::External(ExternalError::GameCreationThrottled(
+                    max_creates_per_second,
+                )));
+            }
+        }
         let id = self.create_unique_game_id();
         self.game_channels.insert(
             id.clone(),
             GameActor::spawn(
                 &id,
+                title,
                 self.game_settings.clone(),
-                self.words.clone(),
+                self.game_words(category.as_deref(), words),
+                self.blocklist.clone(),
                 game_factory,
             ),
         );
+        GAMES_CREATED.inc();
 
-        id
+        Ok(id)
     }
 
     pub fn remove_game(&mut self, game_id: &str) -> Option<GameClient> {
-        self.game_channels.remove(game_id)
+        let retention = self.game_settings.closed_game_retention();
+        self.closed_games
+            .retain(|_, closed_at| closed_at.elapsed() < retention);
+
+        let game = self.game_channels.remove(game_id);
+        if game.is_some() && !retention.is_zero() {
+            self.closed_games
+                .insert(game_id.to_string(), Instant::now());
+        }
+        game
+    }
+
+    // Returns the games that are still running, so that they can be closed
+    pub fn shut_down(&mut self) -> Vec<GameClient> {
+        self.is_shutting_down = true;
+        self.game_channels.values().cloned().collect()
     }
 
     pub fn get_game(&self, game_id: &str) -> Result<&GameClient, Error> {
         match self.game_channels.get(game_id) {
             Some(game) => Ok(game),
+            None if self.is_recently_closed(game_id) => Err(Error::Domain(
+                DomainError::GameRecentlyClosed(game_id.to_string()),
+            )),
             None => Err(Error::Domain(DomainError::GameDoesNotExist(
                 game_id.to_string(),
             ))),
         }
     }
 
+    // The words supplied for a game replace the ones of its category only when there are enough.
+    // They are normalized like the words files, and a repeated or blocked word is only dropped
+    fn game_words(&self, category: Option<&str>, words: Option<Vec<String>>) -> Vec<String> {
+        let Some(words) = words else {
+            return self.category_words(category);
+        };
+        let mut unique_words: HashSet<String> = HashSet::new();
+        let words: Vec<String> = normalize_words(words.into_iter())
+            .into_iter()
+            .filter(|word| !self.blocklist.contains(word))
+            .filter(|word| unique_words.insert(word.clone()))
+            .collect();
+        if words.len() >= Game::MINIMUM_ROUNDS.into() {
+            words
+        } else {
+            log::info!(
+                "Game created with too few custom words, using the default ones. ActualWords: '{}', MinimumWords: '{}'.",
+                words.len(),
+                Game::MINIMUM_ROUNDS
+            );
+            self.category_words(category)
+        }
+    }
+
+    fn category_words(&self, category: Option<&str>) -> Vec<String> {
+        let words = match category {
+            Some(category) => self.words.get(category).or_else(|| {
+                log::info!("Game created with an unknown word category, using the default one. Category: '{category}'.");
+                self.words.get(DEFAULT_WORD_CATEGORY)
+            }),
+            None => self.words.get(DEFAULT_WORD_CATEGORY),
+        };
+        words.cloned().unwrap_or_default()
+    }
+
+    fn try_record_creation(&mut self, max_creates_per_second: u32, now: Instant) -> bool {
+        while self.recent_creations.front().is_some_and(|created_at| {
+            now.saturating_duration_since(*created_at) >= Duration::from_secs(1)
+        }) {
+            self.recent_creations.pop_front();
+        }
+        if self.recent_creations.len() >= max_creates_per_second as usize {
+            return false;
+        }
+        self.recent_creations.push_back(now);
+        true
+    }
+
+    fn is_recently_closed(&self, game_id: &str) -> bool {
+        self.closed_games.get(game_id).is_some_and(|closed_at| {
+            closed_at.elapsed() < self.game_settings.closed_game_retention()
+        })
+    }
+
     fn create_unique_game_id(&self) -> String {
         loop {
             let id = Alphanumeric
@@ -62,7 +180,7 @@ impl GameFactory {
                 .replace('0', "1")
                 .replace('I', "J")
                 .replace('l', "m");
-            if !self.game_channels.contains_key(&id) {
+            if !self.game_channels.contains_key(&id) && !self.closed_games.contains_key(&id) {
                 return id;
             }
         }
@@ -71,31 +189,34 @@ impl GameFactory {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{HashMap, HashSet};
+    use std::time::{Duration, Instant};
+
+    use tokio::sync::mpsc;
+
     use crate::{
         config::GameSettings,
-        error::{domain_error::DomainError, Error},
+        error::{domain_error::DomainError, external_error::ExternalError, Error},
     };
 
-    use super::GameFactory;
+    use super::{actor_client::GameFactoryClient, GameFactory, DEFAULT_WORD_CATEGORY};
 
     #[test]
     fn add_player_works() {
         let game_factory = GameFactory::new(
             GameSettings {
                 inactivity_timeout_seconds: 1,
+                ..GameSettings::default()
             },
-            vec![],
+            HashMap::default(),
+            HashSet::default(),
         );
 
         let id = game_factory.create_unique_game_id();
 
         assert_eq!(id.len(), 5);
         for char in id.chars() {
-            assert!(
-                ('0'..='9').contains(&char)
-                    || ('A'..='Z').contains(&char)
-                    || ('a'..='z').contains(&char)
-            )
+            assert!(char.is_ascii_digit() || char.is_ascii_uppercase() || char.is_ascii_lowercase())
         }
     }
 
@@ -104,8 +225,10 @@ mod tests {
         let game_factory = GameFactory::new(
             GameSettings {
                 inactivity_timeout_seconds: 1,
+                ..GameSettings::default()
             },
-            vec![],
+            HashMap::default(),
+            HashSet::default(),
         );
 
         let result = game_factory.get_game("invalid_game");
@@ -115,4 +238,110 @@ mod tests {
             Error::Domain(DomainError::GameDoesNotExist("invalid_game".to_string()))
         );
     }
+
+    #[test]
+    fn games_are_not_created_once_shutting_down() {
+        let mut game_factory = GameFactory::new(
+            GameSettings::default(),
+            HashMap::default(),
+            HashSet::default(),
+        );
+        let (game_factory_tx, _game_factory_rx) = mpsc::channel(1);
+
+        let running_games = game_factory.shut_down();
+        let result =
+            game_factory.create_new_game(None, None, None, GameFactoryClient::new(game_factory_tx));
+
+        assert!(running_games.is_empty());
+        assert_eq!(
+            result,
+            Err(Error::External(ExternalError::ServerShuttingDown))
+        );
+    }
+
+    #[test]
+    fn game_creations_are_limited_per_second() {
+        let mut game_factory = GameFactory::new(
+            GameSettings::default(),
+            HashMap::default(),
+            HashSet::default(),
+        );
+        let now = Instant::now();
+
+        assert!(game_factory.try_record_creation(2, now));
+        assert!(game_factory.try_record_creation(2, now + Duration::from_millis(500)));
+        assert!(!game_factory.try_record_creation(2, now + Duration::from_millis(900)));
+        assert!(game_factory.try_record_creation(2, now + Duration::from_secs(1)));
+        assert!(!game_factory.try_record_creation(2, now + Duration::from_millis(1400)));
+        assert!(game_factory.try_record_creation(2, now + Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn custom_words_replace_the_default_words() {
+        let game_factory = GameFactory::new(
+            GameSettings::default(),
+            get_words(),
+            HashSet::from(["darn".to_string()]),
+        );
+
+        assert_eq!(
+            game_factory.game_words(
+                None,
+                Some(
+                    [" Giraffe ", "", "giraffe", "GIRAFFE", "Darn", "cat"]
+                        .iter()
+                        .map(|word| word.to_string())
+                        .collect()
+                )
+            ),
+            vec!["giraffe".to_string(), "cat".to_string()]
+        );
+    }
+
+    #[test]
+    fn too_few_custom_words_fall_back_to_the_default_words() {
+        let game_factory =
+            GameFactory::new(GameSettings::default(), get_words(), HashSet::default());
+
+        assert_eq!(
+            game_factory.game_words(None, None),
+            vec!["default".to_string()]
+        );
+        assert_eq!(
+            game_factory.game_words(None, Some(vec![" ".to_string()])),
+            vec!["default".to_string()]
+        );
+    }
+
+    #[test]
+    fn game_words_are_taken_from_the_requested_category() {
+        let game_factory =
+            GameFactory::new(GameSettings::default(), get_words(), HashSet::default());
+
+        assert_eq!(
+            game_factory.game_words(Some("food"), None),
+            vec!["pizza".to_string()]
+        );
+    }
+
+    #[test]
+    fn unknown_category_falls_back_to_the_default_category() {
+        let game_factory =
+            GameFactory::new(GameSettings::default(), get_words(), HashSet::default());
+
+        assert_eq!(
+            game_factory.game_words(Some("unknown"), None),
+            vec!["default".to_string()]
+        );
+    }
+
+    fn get_words() -> HashMap<String, Vec<String>> {
+        HashMap::from([
+            (
+                DEFAULT_WORD_CATEGORY.to_string(),
+                vec!["default".to_string()],
+            ),
+            ("food".to_string(), vec!["pizza".to_string()]),
+        ])
+    }
 }
@@ -11,3 +11,5 @@ mod round;
 mod routes;
 pub mod startup;
 mod websocket;
+
+pub use game::recording::replay_file;
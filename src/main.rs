@@ -19,5 +19,5 @@ async fn main() -> Result<(), std::io::Error> {
         .await
         .expect("Failed to bind to address");
 
-    startup::create_web_server(config, listener).await
+    startup::create_web_server(config, listener, startup::shutdown_signal()).await
 }
@@ -1,15 +1,61 @@
-use prometheus::{IntGauge, Registry};
+use prometheus::{
+    Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+};
+
+use crate::game::game_fsm::GameFsmState;
+use crate::websocket::message::state_to_string;
 
 lazy_static! {
     pub static ref REGISTRY: Registry = Registry::new();
     pub static ref ACTIVE_GAMES: IntGauge =
         IntGauge::new("repeti2_headcrab_active_games", "Active ongoing games")
             .expect("metric cannot be created");
+    pub static ref GAMES_BY_STATE: IntGaugeVec = IntGaugeVec::new(
+        Opts::new(
+            "repeti2_headcrab_games_by_state",
+            "Active ongoing games in each state"
+        ),
+        &["state"]
+    )
+    .expect("metric cannot be created");
     pub static ref CONNECTED_PLAYERS: IntGauge = IntGauge::new(
         "repeti2_headcrab_connected_players",
         "Amount of players connected"
     )
     .expect("metric cannot be created");
+    pub static ref GAMES_CREATED: IntCounter = IntCounter::new(
+        "repeti2_headcrab_games_created_total",
+        "Amount of games created"
+    )
+    .expect("metric cannot be created");
+    pub static ref ROUNDS_PLAYED: IntCounter = IntCounter::new(
+        "repeti2_headcrab_rounds_played_total",
+        "Amount of rounds started"
+    )
+    .expect("metric cannot be created");
+    pub static ref ROUND_DURATION_SECONDS: Histogram = Histogram::with_opts(
+        HistogramOpts::new(
+            "repeti2_headcrab_round_duration_seconds",
+            "Time from the start of a round until its end"
+        )
+        .buckets(vec![
+            10.0, 20.0, 30.0, 60.0, 90.0, 120.0, 180.0, 300.0, 450.0, 600.0
+        ])
+    )
+    .expect("metric cannot be created");
+    pub static ref WORDS_SUBMITTED: IntCounter = IntCounter::new(
+        "repeti2_headcrab_words_submitted_total",
+        "Amount of words submitted by players"
+    )
+    .expect("metric cannot be created");
+    pub static ref ERRORS: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "repeti2_headcrab_errors_total",
+            "Amount of errors sent to players"
+        ),
+        &["error_type"]
+    )
+    .expect("metric cannot be created");
 }
 
 pub fn register_metrics() {
@@ -17,7 +63,95 @@ pub fn register_metrics() {
         .register(Box::new(ACTIVE_GAMES.clone()))
         .expect("collector cannot be registered");
 
+    REGISTRY
+        .register(Box::new(GAMES_BY_STATE.clone()))
+        .expect("collector cannot be registered");
+
     REGISTRY
         .register(Box::new(CONNECTED_PLAYERS.clone()))
         .expect("collector cannot be registered");
+
+    REGISTRY
+        .register(Box::new(GAMES_CREATED.clone()))
+        .expect("collector cannot be registered");
+
+    REGISTRY
+        .register(Box::new(ROUNDS_PLAYED.clone()))
+        .expect("collector cannot be registered");
+
+    REGISTRY
+        .register(Box::new(ROUND_DURATION_SECONDS.clone()))
+        .expect("collector cannot be registered");
+
+    REGISTRY
+        .register(Box::new(WORDS_SUBMITTED.clone()))
+        .expect("collector cannot be registered");
+
+    REGISTRY
+        .register(Box::new(ERRORS.clone()))
+        .expect("collector cannot be registered");
+}
+
+// Moves a game from the state it was counted in to its current one, None when it starts or stops
+pub fn record_game_state_change(
+    games_by_state: &IntGaugeVec,
+    previous: Option<&GameFsmState>,
+    current: Option<&GameFsmState>,
+) {
+    if previous == current {
+        return;
+    }
+    if let Some(previous) = previous {
+        games_by_state
+            .with_label_values(&[&state_to_string(previous.clone())])
+            .dec();
+    }
+    if let Some(current) = current {
+        games_by_state
+            .with_label_values(&[&state_to_string(current.clone())])
+            .inc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus::{IntGaugeVec, Opts};
+
+    use crate::game::game_fsm::GameFsmState;
+
+    use super::record_game_state_change;
+
+    fn games_by_state() -> IntGaugeVec {
+        IntGaugeVec::new(Opts::new("games_by_state", "Games by state"), &["state"]).unwrap()
+    }
+
+    #[test]
+    fn state_change_moves_the_game_to_the_new_state() {
+        let games_by_state = games_by_state();
+        record_game_state_change(&games_by_state, None, Some(&GameFsmState::Lobby));
+
+        record_game_state_change(
+            &games_by_state,
+            Some(&GameFsmState::Lobby),
+            Some(&GameFsmState::PlayersSubmittingWords),
+        );
+
+        assert_eq!(games_by_state.with_label_values(&["Lobby"]).get(), 0);
+        assert_eq!(
+            games_by_state
+                .with_label_values(&["PlayersSubmittingWords"])
+                .get(),
+            1
+        );
+    }
+
+    #[test]
+    fn stopped_game_is_no_longer_counted() {
+        let games_by_state = games_by_state();
+        record_game_state_change(&games_by_state, None, Some(&GameFsmState::EndOfGame));
+
+        record_game_state_change(&games_by_state, Some(&GameFsmState::EndOfGame), None);
+
+        assert_eq!(games_by_state.with_label_values(&["EndOfGame"]).get(), 0);
+    }
 }
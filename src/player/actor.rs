@@ -3,19 +3,22 @@ use std::collections::HashMap;
 use std::time::Duration;
 use tokio::select;
 use tokio::time::error::Elapsed;
-use tokio::time::timeout;
+use tokio::time::{interval_at, timeout_at, Instant, Interval};
 
 use crate::error::domain_error::DomainError;
 use crate::error::external_error::ExternalError;
 use crate::error::Error;
-use crate::game::actor::GameWideEvent;
+use crate::game::actor::{ChatHistoryMessage, GameWideEvent, KICKED_REASON};
 use crate::game::actor_client::GameClient;
 use crate::game::actor_client::GameWideEventReceiver;
+use crate::game::actor_client::JoinedPlayer;
 use crate::game::game_fsm::GameFsmState;
 use crate::metrics::CONNECTED_PLAYERS;
 use crate::player::Player;
+use crate::player::Subscription;
 use crate::round::Round;
 use crate::round::Word;
+use crate::websocket::catalog::Language;
 use crate::websocket::close;
 use crate::websocket::message::state_to_string;
 use crate::websocket::message::RoundDto;
@@ -23,9 +26,12 @@ use crate::websocket::message::WordDto;
 use crate::websocket::message::WsMessageIn;
 use crate::websocket::message::WsMessageOut;
 use crate::websocket::parse_message;
+use crate::websocket::parse_request_id;
 use crate::websocket::send_error;
 use crate::websocket::send_message;
 use crate::websocket::send_message_string;
+use crate::websocket::send_ping;
+use crate::websocket::send_request_error;
 
 pub struct PlayerActor {
     nickname: String,
@@ -33,64 +39,215 @@ pub struct PlayerActor {
     game_wide_event_receiver: GameWideEventReceiver,
     websocket: WebSocket,
     inactivity_timeout: Duration,
+    heartbeat_interval: Option<Duration>,
+    subscription: Subscription,
+    // The language of the error titles sent to the player
+    language: Language,
+    // Kicked, or the session ended, the game has nothing left to disconnect
+    is_removed: bool,
 }
 
 impl PlayerActor {
-    pub async fn create(nickname: String, game: GameClient, mut websocket: WebSocket) {
-        match game.add_player(&nickname).await {
-            Ok(game_wide_event_receiver) => {
-                PlayerActor {
-                    nickname,
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        nickname: String,
+        reconnection_token: Option<String>,
+        rejoin_ticket: Option<String>,
+        game: GameClient,
+        mut websocket: WebSocket,
+        subscription: Subscription,
+        language: Language,
+        inactivity_timeout: Duration,
+        heartbeat_interval: Option<Duration>,
+    ) {
+        match game
+            .add_player(&nickname, reconnection_token, rejoin_ticket)
+            .await
+        {
+            Ok(JoinedPlayer {
+                player,
+                chat_history,
+                reconnect_state,
+                game_wide_event_receiver,
+            }) => {
+                // Sent before any game wide event, so that it is the first message the player gets
+                if send_message(
+                    &mut websocket,
+                    &WsMessageOut::ReconnectionToken {
+                        reconnection_token: player.reconnection_token.clone(),
+                    },
+                )
+                .await
+                .is_err()
+                {
+                    let _ = game.remove_player(&player.nickname).await;
+                    close(websocket).await;
+                    return;
+                }
+                let mut player_actor = PlayerActor {
+                    nickname: player.nickname.clone(),
                     game,
                     game_wide_event_receiver,
                     websocket,
-                    inactivity_timeout: Duration::from_millis(5000),
-                }
-                .start()
-                .await
+                    inactivity_timeout,
+                    heartbeat_interval,
+                    subscription,
+                    language,
+                    is_removed: false,
+                };
+                // A failure closes the websocket, which the actor notices as soon as it starts
+                let _ = match reconnect_state {
+                    Some(game_state) if player_actor.subscription.includes(&game_state) => {
+                        player_actor
+                            .send_reconnect_snapshot(player, game_state, chat_history)
+                            .await
+                    }
+                    _ => player_actor.replay_chat_history(chat_history).await,
+                };
+                player_actor.start().await
             }
             Err(error) => {
-                send_error(&mut websocket, &error).await;
+                send_error(&mut websocket, &error, language).await;
                 close(websocket).await;
             }
         }
     }
 
+    // Sent as the chat messages would have been, so that the history follows the subscription
+    async fn replay_chat_history(
+        &mut self,
+        chat_history: Vec<ChatHistoryMessage>,
+    ) -> Result<(), Error> {
+        for message in chat_history {
+            self.receive_game_wide_message(Ok(GameWideEvent::ChatMessage {
+                sender: message.sender,
+                content: message.content,
+            }))
+            .await?;
+        }
+        Ok(())
+    }
+
+    // Everything a reconnecting player needs to render the game in a single message, so that it
+    // does not depend on the order of several ones
+    async fn send_reconnect_snapshot(
+        &mut self,
+        player: Player,
+        game_state: GameWideEvent,
+        chat_history: Vec<ChatHistoryMessage>,
+    ) -> Result<(), Error> {
+        let GameWideEvent::GameState {
+            title,
+            state,
+            players,
+            rounds,
+            amount_of_rounds,
+            player_total_scores,
+        } = game_state
+        else {
+            return Ok(());
+        };
+        let my_words = rounds
+            .last()
+            .and_then(|round| round.player_words.get(&self.nickname))
+            .map(|words| words.iter().map(|word| word.clone().into()).collect())
+            .unwrap_or_default();
+        let chat_history = if self.subscription.includes_chat() {
+            chat_history
+                .into_iter()
+                .map(|message| message.into())
+                .collect()
+        } else {
+            vec![]
+        };
+        send_message(
+            &mut self.websocket,
+            &WsMessageOut::ReconnectSnapshot {
+                welcome: player.into(),
+                state: Box::new(PlayerActor::serialize_game_state(
+                    Some(&self.nickname),
+                    title,
+                    state,
+                    players,
+                    rounds,
+                    amount_of_rounds,
+                    player_total_scores,
+                )),
+                chat_history,
+                my_words,
+            },
+        )
+        .await
+    }
+
     async fn start(mut self) {
         CONNECTED_PLAYERS.inc();
+        let mut heartbeat = self
+            .heartbeat_interval
+            .map(|period| interval_at(Instant::now() + period, period));
+        // Only what the player sends moves it forward, the heartbeat pings and the game wide
+        // events sent to the player do not
+        let mut inactivity_deadline = Instant::now() + self.inactivity_timeout;
 
         loop {
             select! {
+                _ = PlayerActor::next_heartbeat(&mut heartbeat) => {
+                    if let Err(error) = send_ping(&mut self.websocket).await {
+                        self.log_connection_lost_with_player("could not send the heartbeat 'Ping'");
+                        if PlayerActor::should_close_websocket(error) {
+                            break;
+                        }
+                    }
+                },
                 game_wide_message = self.game_wide_event_receiver.next() => {
                     if let Err(error) = self.receive_game_wide_message(game_wide_message).await {
-                        send_error(&mut self.websocket, &error).await;
+                        send_error(&mut self.websocket, &error, self.language).await;
                         if PlayerActor::should_close_websocket(error) {
                             break;
                         }
                     }
                 },
-                websocket_message = timeout(self.inactivity_timeout, self.websocket.recv()) => {
+                websocket_message = timeout_at(inactivity_deadline, self.websocket.recv()) => {
+                    if matches!(websocket_message, Ok(Some(Ok(_)))) {
+                        inactivity_deadline = Instant::now() + self.inactivity_timeout;
+                    }
                     if let Err(error) = self.receive_websocket_message(websocket_message).await {
-                        send_error(&mut self.websocket, &error).await;
+                        send_error(&mut self.websocket, &error, self.language).await;
                         if PlayerActor::should_close_websocket(error) {
                             break;
                         }
                     }
                 },
             }
+            if self.is_removed {
+                break;
+            }
         }
 
-        let _ = self.game.remove_player(&self.nickname).await;
+        if !self.is_removed {
+            let _ = self.game.remove_player(&self.nickname).await;
+        }
         close(self.websocket).await;
         CONNECTED_PLAYERS.dec();
     }
 
+    async fn next_heartbeat(heartbeat: &mut Option<Interval>) {
+        match heartbeat {
+            Some(heartbeat) => {
+                heartbeat.tick().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+
     fn should_close_websocket(error: Error) -> bool {
         matches!(
             error,
             Error::Domain(DomainError::GameAlreadyInProgress(_))
                 | Error::Domain(DomainError::GameDoesNotExist(_))
+                | Error::Domain(DomainError::GameRecentlyClosed(_))
                 | Error::Domain(DomainError::PlayerAlreadyExists(_))
+                | Error::Domain(DomainError::PlayerWasKicked(_))
                 | Error::External(ExternalError::WebsocketClosed(_))
                 | Error::Internal(_)
         )
@@ -100,21 +257,31 @@ impl PlayerActor {
         &mut self,
         game_wide_message: Result<GameWideEvent, Error>,
     ) -> Result<(), Error> {
+        if game_wide_message
+            .as_ref()
+            .is_ok_and(|event| !self.subscription.includes(event))
+        {
+            return Ok(());
+        }
         match game_wide_message {
             Ok(GameWideEvent::GameState {
+                title,
                 state,
                 players,
                 rounds,
                 amount_of_rounds,
+                player_total_scores,
             }) => {
                 send_message(
                     &mut self.websocket,
                     &PlayerActor::serialize_game_state(
-                        &self.nickname,
+                        Some(&self.nickname),
+                        title,
                         state,
                         players,
                         rounds,
                         amount_of_rounds,
+                        player_total_scores,
                     ),
                 )
                 .await
@@ -129,16 +296,149 @@ impl PlayerActor {
                 )
                 .await
             }
+            Ok(GameWideEvent::PlayerTyping {
+                nickname,
+                is_typing,
+            }) => {
+                // The player typing already knows it is
+                if nickname == self.nickname {
+                    Ok(())
+                } else {
+                    send_message(
+                        &mut self.websocket,
+                        &WsMessageOut::PlayerTyping {
+                            nickname,
+                            is_typing,
+                        },
+                    )
+                    .await
+                }
+            }
+            Ok(GameWideEvent::WordScored { owner, word, score }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::WordScored { owner, word, score },
+                )
+                .await
+            }
+            Ok(GameWideEvent::VotingItemResolved {
+                player_nickname,
+                word,
+                score,
+                matched_players,
+            }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::VotingItemResolved {
+                        player_nickname,
+                        word,
+                        score,
+                        matched_players,
+                    },
+                )
+                .await
+            }
+            Ok(GameWideEvent::SystemMessage { text }) => {
+                send_message(&mut self.websocket, &WsMessageOut::SystemMessage { text }).await
+            }
+            Ok(GameWideEvent::WordReveal { masked }) => {
+                send_message(&mut self.websocket, &WsMessageOut::WordReveal { masked }).await
+            }
+            Ok(GameWideEvent::FinalScoreboard { ranking }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::FinalScoreboard { ranking },
+                )
+                .await
+            }
+            Ok(GameWideEvent::PlayerJoined { nickname }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::PlayerJoined { nickname },
+                )
+                .await
+            }
+            Ok(GameWideEvent::PlayerReconnected { nickname }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::PlayerReconnected { nickname },
+                )
+                .await
+            }
+            Ok(GameWideEvent::PlayerLeft { nickname, reason }) => {
+                let is_us = nickname == self.nickname;
+                let is_kicked = reason == KICKED_REASON;
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::PlayerLeft { nickname, reason },
+                )
+                .await?;
+                if is_us {
+                    // The game already removed us, there is nothing to disconnect
+                    self.is_removed = true;
+                    if is_kicked {
+                        Err(Error::Domain(DomainError::PlayerWasKicked(
+                            self.nickname.to_string(),
+                        )))
+                    } else {
+                        // A merged player lives on in another one, the notice is all it gets
+                        Ok(())
+                    }
+                } else {
+                    Ok(())
+                }
+            }
+            Ok(GameWideEvent::PhaseDeadline {
+                state,
+                seconds_remaining,
+            }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::PhaseDeadline {
+                        state: state_to_string(state),
+                        seconds_remaining,
+                    },
+                )
+                .await
+            }
+            Ok(GameWideEvent::PreparingVoting { seconds_remaining }) => {
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::PreparingVoting { seconds_remaining },
+                )
+                .await
+            }
+            // Not an error of the player either, the game is over and closes the websocket
+            Ok(GameWideEvent::SessionEnded) => {
+                self.is_removed = true;
+                send_message(&mut self.websocket, &WsMessageOut::SessionEnded).await
+            }
+            // Not an error of the player, the websocket is closed right after the notice
+            Ok(GameWideEvent::ServerShuttingDown) => {
+                self.is_removed = true;
+                send_message(&mut self.websocket, &WsMessageOut::ServerShuttingDown).await
+            }
+            Ok(GameWideEvent::NextVotingPlayer { nickname }) => {
+                if nickname == self.nickname {
+                    send_message(&mut self.websocket, &WsMessageOut::YouAreNext).await
+                } else {
+                    Ok(())
+                }
+            }
             Err(error) => Err(error),
         }
     }
 
-    fn serialize_game_state(
-        our_nickname: &str,
+    // Without a nickname, only the used words and those of the player being voted are shown. With the
+    // submission progress, the words of the others are hidden until everyone submitted them
+    pub(crate) fn serialize_game_state(
+        our_nickname: Option<&str>,
+        title: String,
         state: GameFsmState,
         players: Vec<Player>,
         rounds: Vec<Round>,
         amount_of_rounds: Option<u8>,
+        player_total_scores: HashMap<String, isize>,
     ) -> WsMessageOut {
         let rounds: Option<Vec<RoundDto>> = rounds.split_last().map(|(last_round, rest)| {
             let last_round = last_round.clone();
@@ -146,11 +446,24 @@ impl PlayerActor {
                 .voting_item
                 .clone()
                 .map(|voting_item| voting_item.player_nickname);
+            let submission_progress = (state == GameFsmState::PlayersSubmittingWords
+                && last_round.shows_submission_progress())
+            .then(|| {
+                let connected_players: Vec<String> = players
+                    .iter()
+                    .filter(|player| player.is_connected)
+                    .map(|player| player.nickname.clone())
+                    .collect();
+                last_round.submission_progress(&connected_players)
+            });
             let filtered_words: HashMap<String, Vec<WordDto>> = last_round
                 .player_words
                 .iter()
+                .filter(|(nickname, _)| {
+                    submission_progress.is_none() || our_nickname == Some(nickname.as_str())
+                })
                 .map(|(nickname, words)| {
-                    let words: Vec<Word> = if our_nickname == nickname
+                    let words: Vec<Word> = if our_nickname == Some(nickname.as_str())
                         || current_voting_player_nickname == Some(nickname.to_string())
                     {
                         words.to_vec()
@@ -165,16 +478,22 @@ impl PlayerActor {
                 .collect();
             let mut last_round: RoundDto = last_round.into();
             last_round.player_words = filtered_words;
+            if let Some((submitted_count, expected_count)) = submission_progress {
+                last_round.submitted_count = Some(submitted_count);
+                last_round.expected_count = Some(expected_count);
+            }
             let mut rest: Vec<RoundDto> = rest.iter().map(|round| round.clone().into()).collect();
             rest.push(last_round);
             rest
         });
 
         WsMessageOut::GameState {
+            title,
             state: state_to_string(state),
             players: players.into_iter().map(|player| player.into()).collect(),
             rounds: rounds.unwrap_or_default(),
             amount_of_rounds,
+            player_total_scores,
         }
     }
 
@@ -185,41 +504,19 @@ impl PlayerActor {
         match websocket_message {
             Ok(Some(Ok(Message::Text(txt)))) => match txt.as_str() {
                 "ping" => send_message_string(&mut self.websocket, "pong").await,
-                message => match parse_message(message) {
-                    Ok(WsMessageIn::StartGame { amount_of_rounds }) => {
-                        self.game
-                            .start_game(&self.nickname, amount_of_rounds)
-                            .await?;
-                        log::info!("Started game with amount of rounds {amount_of_rounds}");
-                        Ok(())
-                    }
-                    Ok(WsMessageIn::ChatMessage { content }) => {
-                        self.game.send_chat_message(&self.nickname, &content).await
-                    }
-                    Ok(WsMessageIn::PlayerWords { words }) => {
-                        self.game.add_player_words(&self.nickname, words).await
-                    }
-                    Ok(WsMessageIn::PlayerVotingWord { word }) => {
-                        self.game.add_player_voting_word(&self.nickname, word).await
+                message => {
+                    let request_id = parse_request_id(message);
+                    let result = self.handle_message(message).await;
+                    match request_id {
+                        Some(request_id) => self.acknowledge(request_id, result).await,
+                        None => result,
                     }
-                    Ok(WsMessageIn::AcceptPlayersVotingWords) => {
-                        self.game.accept_players_voting_words(&self.nickname).await
-                    }
-                    Ok(WsMessageIn::ContinueToNextRound) => {
-                        self.game.continue_to_next_round(&self.nickname).await
-                    }
-                    Ok(WsMessageIn::PlayAgain) => self.game.play_again(&self.nickname).await,
-                    Ok(WsMessageIn::RejectMatchedWord {
-                        rejected_player,
-                        rejected_word,
-                    }) => {
-                        self.game
-                            .reject_matched_word(&self.nickname, rejected_player, rejected_word)
-                            .await
-                    }
-                    Err(error) => Err(error),
-                },
+                }
             },
+            // answer to a heartbeat 'Ping', receiving it is enough to keep the player connected
+            Ok(Some(Ok(Message::Pong(_)))) => Ok(()),
+            // the websocket answers it with a 'Pong' on its own
+            Ok(Some(Ok(Message::Ping(_)))) => Ok(()),
             // browser said "close"
             Ok(Some(Ok(Message::Close(_)))) => {
                 self.log_connection_lost_with_player("browser sent 'Close' websocket frame");
@@ -258,6 +555,161 @@ impl PlayerActor {
         }
     }
 
+    async fn handle_message(&mut self, message: &str) -> Result<(), Error> {
+        match parse_message(message) {
+            Ok(WsMessageIn::StartGame { amount_of_rounds }) => {
+                self.game
+                    .start_game(&self.nickname, amount_of_rounds)
+                    .await?;
+                log::info!("Started game with amount of rounds {amount_of_rounds}");
+                Ok(())
+            }
+            Ok(WsMessageIn::ChatMessage { content }) => {
+                self.game.send_chat_message(&self.nickname, &content).await
+            }
+            Ok(WsMessageIn::TypingStarted) => {
+                self.game.set_player_typing(&self.nickname, true).await
+            }
+            Ok(WsMessageIn::TypingStopped) => {
+                self.game.set_player_typing(&self.nickname, false).await
+            }
+            Ok(WsMessageIn::PlayerWords { words }) => {
+                let amount_of_words = words.len();
+                let accepted = self.game.add_player_words(&self.nickname, words).await?;
+                let dropped = amount_of_words - accepted.len();
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::WordsAccepted { accepted, dropped },
+                )
+                .await
+            }
+            Ok(WsMessageIn::PlayerVotingWord { word }) => {
+                self.game.add_player_voting_word(&self.nickname, word).await
+            }
+            Ok(WsMessageIn::ClearVotingWord) => {
+                self.game.clear_player_voting_word(&self.nickname).await
+            }
+            Ok(WsMessageIn::AcceptPlayersVotingWords) => {
+                self.game.accept_players_voting_words(&self.nickname).await
+            }
+            Ok(WsMessageIn::SkipVotingItem) => self.game.skip_voting_item(&self.nickname).await,
+            Ok(WsMessageIn::ContinueToNextRound) => {
+                self.game.continue_to_next_round(&self.nickname).await
+            }
+            Ok(WsMessageIn::SetTitle { title }) => self.game.set_title(&self.nickname, title).await,
+            Ok(WsMessageIn::PlayAgain) => self.game.play_again(&self.nickname).await,
+            Ok(WsMessageIn::PlayAgainSameSettings) => {
+                self.game.play_again_same_settings(&self.nickname).await
+            }
+            Ok(WsMessageIn::EndSession) => self.game.end_session(&self.nickname).await,
+            Ok(WsMessageIn::GetPlayers) => {
+                let players = self.game.get_players().await?;
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::Players {
+                        players: players.into_iter().map(|player| player.into()).collect(),
+                    },
+                )
+                .await
+            }
+            Ok(WsMessageIn::GetLastWords) => {
+                let words = self.game.get_last_words(&self.nickname).await?;
+                send_message(&mut self.websocket, &WsMessageOut::LastWords { words }).await
+            }
+            Ok(WsMessageIn::GetRemainingVotingItems) => {
+                let items = self.game.get_remaining_voting_items(&self.nickname).await?;
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::RemainingVotingItems {
+                        items: items.into_iter().map(|item| item.into()).collect(),
+                    },
+                )
+                .await
+            }
+            Ok(WsMessageIn::GetRoundHistory { offset, limit }) => {
+                let (rounds, total) = self.game.get_round_history(offset, limit).await?;
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::RoundHistory {
+                        rounds: rounds.into_iter().map(|round| round.into()).collect(),
+                        total,
+                    },
+                )
+                .await
+            }
+            Ok(WsMessageIn::GetScoreBreakdown) => {
+                let breakdown = self.game.get_score_breakdown().await?;
+                send_message(
+                    &mut self.websocket,
+                    &WsMessageOut::ScoreBreakdown {
+                        breakdown: breakdown
+                            .into_iter()
+                            .map(|(nickname, entries)| {
+                                (
+                                    nickname,
+                                    entries.into_iter().map(|entry| entry.into()).collect(),
+                                )
+                            })
+                            .collect(),
+                    },
+                )
+                .await
+            }
+            Ok(WsMessageIn::RemoveWord { player, word }) => {
+                self.game.remove_word(&self.nickname, player, word).await
+            }
+            Ok(WsMessageIn::KickPlayer { nickname }) => {
+                self.game.kick_player(&self.nickname, nickname).await
+            }
+            Ok(WsMessageIn::MergePlayers { source, target }) => {
+                self.game
+                    .merge_players(&self.nickname, source, target)
+                    .await
+            }
+            Ok(WsMessageIn::TransferHost { nickname }) => {
+                self.game.transfer_host(&self.nickname, nickname).await
+            }
+            Ok(WsMessageIn::RejectMatchedWord {
+                rejected_player,
+                rejected_word,
+            }) => {
+                self.game
+                    .reject_matched_word(&self.nickname, rejected_player, rejected_word)
+                    .await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    // Errors that close the websocket are left to the main loop, the rest are sent along with the
+    // request id before acknowledging the command
+    async fn acknowledge(
+        &mut self,
+        request_id: String,
+        result: Result<(), Error>,
+    ) -> Result<(), Error> {
+        if let Err(error) = &result {
+            if PlayerActor::should_close_websocket(error.clone()) {
+                return result;
+            }
+            send_request_error(
+                &mut self.websocket,
+                error,
+                Some(request_id.clone()),
+                self.language,
+            )
+            .await;
+        }
+        send_message(
+            &mut self.websocket,
+            &WsMessageOut::Ack {
+                request_id,
+                ok: result.is_ok(),
+            },
+        )
+        .await
+    }
+
     fn log_connection_lost_with_player(&self, reason: &str) {
         log::info!(
             "Connection with player {} lost due to: {}. Stopping player actor.",
@@ -295,6 +747,9 @@ mod tests {
         assert!(PlayerActor::should_close_websocket(Error::Domain(
             DomainError::GameDoesNotExist("".to_string())
         )));
+        assert!(PlayerActor::should_close_websocket(Error::Domain(
+            DomainError::GameRecentlyClosed("".to_string())
+        )));
         assert!(PlayerActor::should_close_websocket(Error::Domain(
             DomainError::PlayerAlreadyExists("".to_string())
         )));
@@ -1,12 +1,19 @@
 pub mod actor;
+pub mod tasks;
 
+use rand::distributions::{Alphanumeric, DistString};
 use serde::{Deserialize, Serialize};
 
+use crate::game::actor::GameWideEvent;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Player {
     pub nickname: String,
     pub is_host: bool,
     pub is_connected: bool,
+    // Only handed to the player when joining, it reclaims this player even under another nickname
+    #[serde(skip)]
+    pub reconnection_token: String,
 }
 
 impl Player {
@@ -15,6 +22,50 @@ impl Player {
             nickname: String::from(nickname),
             is_host: false,
             is_connected: true,
+            reconnection_token: Alphanumeric.sample_string(&mut rand::thread_rng(), 24),
+        }
+    }
+
+    // Nicknames are unique regardless of case, the player keeps the casing they joined with
+    pub fn has_nickname(&self, nickname: &str) -> bool {
+        self.nickname.to_lowercase() == nickname.to_lowercase()
+    }
+}
+
+// Which game wide events are forwarded to the websocket of a player, a player receiving only chat
+// is still connected to the game
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Subscription {
+    Chat,
+    State,
+    #[default]
+    All,
+}
+
+impl Subscription {
+    pub fn includes_chat(&self) -> bool {
+        self != &Subscription::State
+    }
+
+    pub fn includes(&self, event: &GameWideEvent) -> bool {
+        // Every subscription needs to know that the game is over
+        if matches!(
+            event,
+            GameWideEvent::SessionEnded | GameWideEvent::ServerShuttingDown
+        ) {
+            return true;
+        }
+        let is_chat = matches!(
+            event,
+            GameWideEvent::ChatMessage { .. }
+                | GameWideEvent::PlayerTyping { .. }
+                | GameWideEvent::SystemMessage { .. }
+        );
+        match self {
+            Subscription::Chat => is_chat,
+            Subscription::State => !is_chat,
+            Subscription::All => true,
         }
     }
 }
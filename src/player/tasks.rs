@@ -0,0 +1,78 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+// The player actors still running, so that shutting down can wait for them to tell their players
+// and close the websockets. They run in the detached tasks of the websocket upgrades
+#[derive(Clone, Default)]
+pub struct PlayerTasks {
+    inner: Arc<PlayerTasksInner>,
+}
+
+#[derive(Default)]
+struct PlayerTasksInner {
+    running: AtomicUsize,
+    all_stopped: Notify,
+}
+
+// Counts a player actor as running until it is dropped
+pub struct PlayerTask {
+    inner: Arc<PlayerTasksInner>,
+}
+
+impl PlayerTasks {
+    pub fn start(&self) -> PlayerTask {
+        self.inner.running.fetch_add(1, Ordering::SeqCst);
+        PlayerTask {
+            inner: self.inner.clone(),
+        }
+    }
+
+    pub async fn wait_until_stopped(&self) {
+        loop {
+            // Created before checking, so that a task stopping in between is not missed
+            let all_stopped = self.inner.all_stopped.notified();
+            if self.inner.running.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            all_stopped.await;
+        }
+    }
+}
+
+impl Drop for PlayerTask {
+    fn drop(&mut self) {
+        if self.inner.running.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.all_stopped.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::PlayerTasks;
+
+    #[tokio::test]
+    async fn waits_until_every_player_task_is_dropped() {
+        let player_tasks = PlayerTasks::default();
+        let player_task_1 = player_tasks.start();
+        let player_task_2 = player_tasks.start();
+
+        drop(player_task_1);
+        assert!(
+            timeout(Duration::from_millis(50), player_tasks.wait_until_stopped())
+                .await
+                .is_err()
+        );
+        drop(player_task_2);
+
+        timeout(Duration::from_millis(50), player_tasks.wait_until_stopped())
+            .await
+            .unwrap();
+    }
+}
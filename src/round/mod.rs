@@ -1,12 +1,17 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
+use rand::{seq::SliceRandom, Rng};
+
+use crate::config::{AllSkipPolicy, GameSettings, RoundReviewOrder};
 use crate::error::{domain_error::DomainError, Error};
 
 #[derive(Debug, Clone)]
 pub struct Word {
     pub word: String,
     pub is_used: bool,
-    pub score: usize,
+    pub score: isize,
+    pub is_flagged: bool,
 }
 
 impl Word {
@@ -15,6 +20,7 @@ impl Word {
             word,
             is_used: false,
             score: 0,
+            is_flagged: false,
         }
     }
 }
@@ -36,32 +42,170 @@ impl VotingItem {
     }
 }
 
+// A voting item as seen by a given player, the word is hidden unless the player can see it
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemainingVotingItem {
+    pub player_nickname: String,
+    pub word: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewItem {
+    pub player_nickname: String,
+    pub word: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredWord {
+    pub nickname: String,
+    pub word: String,
+    pub score: isize,
+}
+
+// The outcome of scoring a voting item
+#[derive(Debug, Clone, PartialEq)]
+pub struct VotingItemResolution {
+    pub player_nickname: String,
+    pub word: String,
+    pub score: isize,
+    // The other players whose word matched the voting item
+    pub matched_players: Vec<String>,
+    pub scored_words: Vec<ScoredWord>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Round {
     pub word: String,
     players: Vec<String>,
+    settings: GameSettings,
+    blocklist: Arc<HashSet<String>>,
+    word_reveal_stage: u8,
+    // Starting at 1, the same as the round of the score breakdown
+    pub number: usize,
     pub player_words: HashMap<String, Vec<Word>>,
     pub player_voting_words: HashMap<String, Option<String>>,
     pub voting_item: Option<VotingItem>,
+    // How many voting words each player submitted for the current voting item
+    voting_word_submissions: HashMap<String, u8>,
+    // Empty until the round ends, it is computed once so that a shuffled order stays the same
+    pub review_order: Vec<ReviewItem>,
+    // Players that submitted at least one word themselves, the words filled in for them don't count
+    participants: HashSet<String>,
 }
 
 impl Round {
-    pub fn new(word: &str, players: Vec<String>) -> Self {
+    pub fn new(
+        number: usize,
+        word: &str,
+        players: Vec<String>,
+        settings: GameSettings,
+        blocklist: Arc<HashSet<String>>,
+    ) -> Self {
         Round {
             word: word.to_string(),
             players,
+            settings,
+            blocklist,
+            word_reveal_stage: 0,
+            number,
             player_words: HashMap::new(),
             player_voting_words: HashMap::new(),
             voting_item: None,
+            voting_word_submissions: HashMap::default(),
+            review_order: Vec::default(),
+            participants: HashSet::default(),
         }
     }
 
+    pub fn masked_word(&self) -> String {
+        let stages = self.settings.word_reveal_stages;
+        if self.word_reveal_stage >= stages {
+            return self.word.to_string();
+        }
+        let length = self.word.chars().count();
+        let revealed_letters = length * usize::from(self.word_reveal_stage) / usize::from(stages);
+        self.word
+            .chars()
+            .enumerate()
+            .map(|(index, letter)| {
+                if index < revealed_letters {
+                    letter
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    pub fn reveal_next_word_stage(&mut self) -> Option<String> {
+        if self.word_reveal_stage >= self.settings.word_reveal_stages {
+            return None;
+        }
+        self.word_reveal_stage += 1;
+        Some(self.masked_word())
+    }
+
+    pub fn has_word_reveal_stages_left(&self) -> bool {
+        self.word_reveal_stage < self.settings.word_reveal_stages
+    }
+
+    pub fn reveal_word(&mut self) {
+        self.word_reveal_stage = self.settings.word_reveal_stages;
+    }
+
+    // Submitting again replaces the earlier words, they are kept if the new words are rejected
     pub fn add_player_words(&mut self, nickname: &str, words: Vec<String>) -> Result<(), Error> {
+        self.insert_player_words(nickname, words, self.settings.min_words_per_player)?;
+        if self
+            .player_words
+            .get(nickname)
+            .is_some_and(|words| !words.is_empty())
+        {
+            self.participants.insert(nickname.to_string());
+        } else {
+            self.participants.remove(nickname);
+        }
+        Ok(())
+    }
+
+    pub fn is_participant(&self, nickname: &str) -> bool {
+        self.participants.contains(nickname)
+    }
+
+    // The words the game submits on behalf of a player that did not, they have no minimum
+    pub fn fill_player_words(&mut self, nickname: &str, words: Vec<String>) -> Result<(), Error> {
+        self.insert_player_words(nickname, words, 0)
+    }
+
+    fn insert_player_words(
+        &mut self,
+        nickname: &str,
+        words: Vec<String>,
+        min_words: usize,
+    ) -> Result<(), Error> {
         let normalized_words: Vec<String> = words
             .iter()
-            .map(|word| word.trim().to_string())
+            .map(|word| self.normalize_word(word))
             .filter(|word| !word.is_empty())
             .collect();
+        if let Some(word) = normalized_words
+            .iter()
+            .find(|word| word.chars().count() > self.settings.max_word_length)
+        {
+            return Err(Error::Domain(DomainError::WordTooLong {
+                nickname: nickname.to_string(),
+                word: word.to_string(),
+            }));
+        }
+        if let Some(word) = normalized_words
+            .iter()
+            .find(|word| self.blocklist.contains(&word.to_lowercase()))
+        {
+            return Err(Error::Domain(DomainError::BlockedWord {
+                nickname: nickname.to_string(),
+                word: word.to_string(),
+            }));
+        }
         let mut word_count: HashMap<String, u8> = HashMap::new();
         for word in normalized_words.clone() {
             let count = word_count.get(&word).unwrap_or(&0).to_owned();
@@ -73,18 +217,53 @@ impl Round {
             .map(|(word, _)| word.to_string())
             .collect();
 
-        if repeated_words.is_empty() {
-            self.player_words.insert(
-                nickname.to_string(),
-                normalized_words.into_iter().map(Word::new).collect(),
-            );
-            Ok(())
-        } else {
-            Err(Error::Domain(DomainError::RepeatedWords {
+        if !repeated_words.is_empty() {
+            return Err(Error::Domain(DomainError::RepeatedWords {
                 nickname: nickname.to_string(),
                 repeated_words,
-            }))
+            }));
         }
+        if normalized_words.len() > self.settings.max_words_per_player {
+            return Err(Error::Domain(DomainError::TooManyWords {
+                nickname: nickname.to_string(),
+                count: normalized_words.len(),
+                max: self.settings.max_words_per_player,
+            }));
+        }
+        if normalized_words.len() < min_words {
+            return Err(Error::Domain(DomainError::NotEnoughWords {
+                nickname: nickname.to_string(),
+                count: normalized_words.len(),
+                min: min_words,
+            }));
+        }
+
+        self.player_words.insert(
+            nickname.to_string(),
+            normalized_words.into_iter().map(Word::new).collect(),
+        );
+        Ok(())
+    }
+
+    fn normalize_word(&self, word: &str) -> String {
+        if self.settings.collapse_internal_whitespace {
+            word.split_whitespace().collect::<Vec<&str>>().join(" ")
+        } else {
+            word.trim().to_string()
+        }
+    }
+
+    pub fn shows_submission_progress(&self) -> bool {
+        self.settings.show_submission_progress
+    }
+
+    // How many of the given players submitted their words, out of all of them
+    pub fn submission_progress(&self, players: &[String]) -> (usize, usize) {
+        let submitted = players
+            .iter()
+            .filter(|player| self.player_words.contains_key(*player))
+            .count();
+        (submitted, players.len())
     }
 
     pub fn have_all_players_submitted_words(&self, players: &[String]) -> bool {
@@ -95,14 +274,24 @@ impl Round {
 
     pub fn next_voting_item(&mut self) -> Option<VotingItem> {
         self.voting_item = self.find_next_voting_item();
+        self.voting_word_submissions = HashMap::default();
 
         if let Some(ref voting_item) = self.voting_item {
             self.player_voting_words.insert(
                 voting_item.player_nickname.to_string(),
                 Some(voting_item.word.to_string()),
             );
-            for nickname in self.get_players_to_auto_skip(voting_item.clone()) {
-                self.player_voting_words.insert(nickname, None);
+            if !self.settings.require_explicit_votes {
+                for nickname in self.get_players_to_auto_skip(voting_item.clone()) {
+                    self.player_voting_words.insert(nickname, None);
+                }
+            }
+            // The players can still change these votes, like any other before it is accepted
+            if self.settings.group_identical_words {
+                for nickname in self.get_players_with_identical_word(voting_item) {
+                    self.player_voting_words
+                        .insert(nickname, Some(voting_item.word.to_string()));
+                }
             }
         }
 
@@ -110,6 +299,11 @@ impl Round {
     }
 
     fn find_next_voting_item(&self) -> Option<VotingItem> {
+        self.remaining_voting_items().into_iter().next()
+    }
+
+    // The unused words in voting order, starting with the one being voted
+    pub fn remaining_voting_items(&self) -> Vec<VotingItem> {
         self.players
             .iter()
             .flat_map(|nickname| {
@@ -121,7 +315,7 @@ impl Round {
                     .map(|word| VotingItem::new(nickname.to_string(), word.word.to_string()))
                     .collect::<Vec<_>>()
             })
-            .next()
+            .collect()
     }
 
     fn get_players_to_auto_skip(&self, voting_item: VotingItem) -> Vec<String> {
@@ -134,13 +328,55 @@ impl Round {
             .collect()
     }
 
+    fn get_players_with_identical_word(&self, voting_item: &VotingItem) -> Vec<String> {
+        self.player_words
+            .iter()
+            .filter(|(nickname, words)| {
+                **nickname != voting_item.player_nickname
+                    && words
+                        .iter()
+                        .any(|word| !word.is_used && word.word == voting_item.word)
+            })
+            .map(|(nickname, _)| nickname.to_string())
+            .collect()
+    }
+
+    // The first voting word of a player for the voting item is not a change, every later one is
     pub fn set_player_voting_word(
         &mut self,
         nickname: &str,
         word: Option<String>,
     ) -> Result<(), Error> {
+        let submissions = self
+            .voting_word_submissions
+            .get(nickname)
+            .copied()
+            .unwrap_or_default();
+        if self
+            .settings
+            .max_vote_changes_per_item
+            .is_some_and(|max_vote_changes| submissions > max_vote_changes)
+        {
+            return Err(Error::Domain(DomainError::TooManyVoteChanges(
+                nickname.to_string(),
+            )));
+        }
+        self.update_player_voting_word(nickname, word)?;
+        self.voting_word_submissions
+            .insert(nickname.to_string(), submissions.saturating_add(1));
+        Ok(())
+    }
+
+    fn update_player_voting_word(
+        &mut self,
+        nickname: &str,
+        word: Option<String>,
+    ) -> Result<(), Error> {
+        let word = word.map(|word| self.resolve_voting_word(nickname, word));
         match &self.voting_item {
             Some(voting_item) => {
+                // The owner of the voting item already counts towards its score, letting them submit
+                // another of their words would allow them to "match" themselves
                 if voting_item.player_nickname == nickname {
                     return Err(Error::Domain(
                         DomainError::VotingItemPlayerCannotSubmitVotingWord(nickname.to_string()),
@@ -148,10 +384,11 @@ impl Round {
                 }
 
                 if let Some(word) = &word {
-                    if voting_item
-                        .rejected_matches
-                        .get(nickname)
-                        .is_some_and(|rejected_words| rejected_words.contains(word))
+                    if !self.settings.allow_resubmit_after_reject
+                        && voting_item
+                            .rejected_matches
+                            .get(nickname)
+                            .is_some_and(|rejected_words| rejected_words.contains(word))
                     {
                         return Err(Error::Domain(
                             DomainError::CannotResubmitRejectedMatchedWord,
@@ -174,10 +411,73 @@ impl Round {
             ));
         }
 
+        if let (Some(word), Some(voting_item)) = (&word, &mut self.voting_item) {
+            if let Some(rejected_words) = voting_item.rejected_matches.get_mut(nickname) {
+                rejected_words.remove(word);
+                if rejected_words.is_empty() {
+                    voting_item.rejected_matches.remove(nickname);
+                }
+            }
+        }
+
         self.player_voting_words.insert(nickname.to_string(), word);
         Ok(())
     }
 
+    // Keeps the voting word of a player that already voted, the owner of the voting item never skips
+    pub fn skip_pending_voting_word(&mut self, nickname: &str) {
+        if self
+            .voting_item
+            .as_ref()
+            .is_some_and(|voting_item| voting_item.player_nickname != nickname)
+        {
+            self.player_voting_words
+                .entry(nickname.to_string())
+                .or_insert(None);
+        }
+    }
+
+    pub fn have_all_players_submitted_voting_words(&self, players: &[String]) -> bool {
+        self.voting_item.is_some()
+            && players
+                .iter()
+                .all(|nickname| self.player_voting_words.contains_key(nickname))
+    }
+
+    // Takes the player back to undecided, unlike a None voting word which says they don't have the word
+    pub fn clear_player_voting_word(&mut self, nickname: &str) -> Result<(), Error> {
+        match &self.voting_item {
+            Some(voting_item) if voting_item.player_nickname == nickname => Err(Error::Domain(
+                DomainError::VotingItemPlayerCannotSubmitVotingWord(nickname.to_string()),
+            )),
+            Some(_) => {
+                self.player_voting_words.remove(nickname);
+                Ok(())
+            }
+            None => Err(Error::Domain(
+                DomainError::PlayerCannotSubmitVotingWordWhenVotingItemIsNone(nickname.to_string()),
+            )),
+        }
+    }
+
+    // Returns the player's own spelling of the voting word, so that the rest of the voting can
+    // keep comparing exact strings
+    fn resolve_voting_word(&self, nickname: &str, voting_word: String) -> String {
+        if !self.settings.case_insensitive_voting_words {
+            return voting_word;
+        }
+        let lowercase_voting_word = voting_word.to_lowercase();
+        self.player_words
+            .get(nickname)
+            .and_then(|words| {
+                words
+                    .iter()
+                    .find(|word| !word.is_used && word.word.to_lowercase() == lowercase_voting_word)
+            })
+            .map(|word| word.word.to_string())
+            .unwrap_or(voting_word)
+    }
+
     fn voting_word_exists_and_is_unused(
         &self,
         nickname: &str,
@@ -197,26 +497,169 @@ impl Round {
         }
     }
 
-    pub fn compute_score(&mut self) {
+    // Shuffled with the random generator of the game, so that a replay suggests the same order
+    pub fn compute_review_order(&mut self, rng: &mut impl Rng) {
+        let mut words: Vec<(&String, &Word)> = self
+            .players
+            .iter()
+            .flat_map(|nickname| {
+                self.player_words
+                    .get(nickname)
+                    .into_iter()
+                    .flatten()
+                    .map(move |word| (nickname, word))
+            })
+            .collect();
+        match self.settings.round_review_order {
+            RoundReviewOrder::Natural => {}
+            RoundReviewOrder::ScoreDescending => {
+                words.sort_by_key(|(_, word)| std::cmp::Reverse(word.score))
+            }
+            RoundReviewOrder::Shuffled => words.shuffle(rng),
+        }
+        self.review_order = words
+            .into_iter()
+            .map(|(nickname, word)| ReviewItem {
+                player_nickname: nickname.to_string(),
+                word: word.word.to_string(),
+            })
+            .collect();
+    }
+
+    // The word of the voting item is used without scoring, the votes on it are discarded
+    pub fn skip_voting_item(&mut self) {
+        if let Some(voting_item) = &self.voting_item {
+            if let Some(word) = self
+                .player_words
+                .get_mut(&voting_item.player_nickname)
+                .and_then(|words| {
+                    words
+                        .iter_mut()
+                        .find(|word| word.word == voting_item.word && !word.is_used)
+                })
+            {
+                word.is_used = true;
+                word.score = 0;
+            }
+        }
+        self.player_voting_words = HashMap::default();
+    }
+
+    pub fn compute_score(&mut self) -> Option<VotingItemResolution> {
+        // The voting words are cleared once the score is computed, so calling this again (e.g. a
+        // repeated accept) must be a no-op instead of overwriting the scores of the used words
+        if self.player_voting_words.is_empty() {
+            return None;
+        }
+
+        // Every player counts at most once towards the score: the owner of the voting item with the
+        // voting item word itself, and every other player with one of their own unused words. A
+        // player that skipped (None) on an earlier voting item can still score their word later on,
+        // but only through other players matching it
+        let voting_item = self.voting_item.clone();
         let score = self
             .player_voting_words
             .iter()
-            .filter(|(_, submission_word)| submission_word.is_some())
+            .filter(
+                |(submission_nickname, submission_word)| match &voting_item {
+                    Some(voting_item) if voting_item.player_nickname == **submission_nickname => {
+                        submission_word.as_ref() == Some(&voting_item.word)
+                    }
+                    _ => submission_word.is_some(),
+                },
+            )
             .count();
-        let score = if score > 1 { score } else { 0 };
+        let score = if score >= self.settings.min_matchers_to_score {
+            score as isize
+        } else {
+            0
+        };
+        let all_players_skipped = voting_item.as_ref().is_some_and(|voting_item| {
+            self.players
+                .iter()
+                .filter(|nickname| **nickname != voting_item.player_nickname)
+                .all(|nickname| self.player_voting_words.get(nickname) == Some(&None))
+        });
+        let (score, is_flagged) = match (all_players_skipped, &self.settings.all_skip_policy) {
+            (true, AllSkipPolicy::NegativeOne) => (-1, false),
+            (true, AllSkipPolicy::FlagWord) => (score, true),
+            _ => (score, false),
+        };
 
-        for (submission_nickname, submission_word) in &self.player_voting_words {
-            if let Some(submission_word) = submission_word {
+        let mut scored_words = Vec::new();
+        for submission_nickname in &self.players {
+            if let Some(Some(submission_word)) = self.player_voting_words.get(submission_nickname) {
                 if let Some(words) = self.player_words.get_mut(submission_nickname) {
                     if let Some(word) = words.iter_mut().find(|word| &word.word == submission_word)
                     {
                         word.score = score;
                         word.is_used = true;
+                        word.is_flagged = is_flagged;
+                        scored_words.push(ScoredWord {
+                            nickname: submission_nickname.to_string(),
+                            word: word.word.to_string(),
+                            score,
+                        });
                     }
                 }
             }
         }
         self.player_voting_words = HashMap::default();
+        voting_item.map(|voting_item| VotingItemResolution {
+            matched_players: scored_words
+                .iter()
+                .filter(|scored_word| scored_word.nickname != voting_item.player_nickname)
+                .map(|scored_word| scored_word.nickname.to_string())
+                .collect(),
+            player_nickname: voting_item.player_nickname,
+            word: voting_item.word,
+            score,
+            scored_words,
+        })
+    }
+
+    // Returns whether the player owned the current voting item, which then has to be skipped
+    pub fn remove_player(&mut self, nickname: &str) -> bool {
+        self.players.retain(|player| player != nickname);
+        self.player_words.remove(nickname);
+        self.player_voting_words.remove(nickname);
+        self.voting_word_submissions.remove(nickname);
+        self.participants.remove(nickname);
+
+        let is_voting_item = self
+            .voting_item
+            .as_ref()
+            .is_some_and(|voting_item| voting_item.player_nickname == nickname);
+        if is_voting_item {
+            self.player_voting_words = HashMap::default();
+        }
+        is_voting_item
+    }
+
+    // Returns whether the removed word was the current voting item, which then has to be skipped
+    pub fn remove_word(&mut self, nickname: &str, word: &str) -> Result<bool, Error> {
+        let words = self
+            .player_words
+            .get_mut(nickname)
+            .ok_or(Error::Domain(DomainError::RemovedWordDoesNotExist))?;
+        let index = words
+            .iter()
+            .position(|player_word| player_word.word == word)
+            .ok_or(Error::Domain(DomainError::RemovedWordDoesNotExist))?;
+        if words[index].is_used {
+            return Err(Error::Domain(DomainError::RemovedWordIsAlreadyUsed));
+        }
+        words.remove(index);
+
+        let is_voting_item = self.voting_item.as_ref().is_some_and(|voting_item| {
+            voting_item.player_nickname == nickname && voting_item.word == word
+        });
+        if is_voting_item {
+            self.player_voting_words = HashMap::default();
+        } else if self.player_voting_words.get(nickname) == Some(&Some(word.to_string())) {
+            self.player_voting_words.remove(nickname);
+        }
+        Ok(is_voting_item)
     }
 
     pub fn reject_player_word(
@@ -257,18 +700,25 @@ impl Round {
             ));
         }
 
-        self.set_player_voting_word(rejected_player, None)
+        // The host rejecting the word is not the player changing their mind
+        self.update_player_voting_word(rejected_player, None)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
     use crate::{
+        config::{AllSkipPolicy, GameSettings, RoundReviewOrder},
         error::{domain_error::DomainError, Error},
         round::VotingItem,
     };
 
-    use super::{Round, Word};
+    use super::{ReviewItem, Round, ScoredWord, Word};
 
     static PLAYER_1: &str = "p1";
     static PLAYER_2: &str = "p2";
@@ -277,12 +727,129 @@ mod tests {
     static WORD_1: &str = "w1";
     static WORD_2: &str = "w2";
     fn words() -> Vec<String> {
-        vec![WORD_1, WORD_2]
+        [WORD_1, WORD_2]
             .iter()
             .map(|word| word.to_string())
             .collect()
     }
 
+    #[test]
+    fn word_is_not_masked_without_reveal_stages() {
+        let mut round = get_round_on_writing_state();
+
+        assert_eq!(round.masked_word(), "word");
+        assert_eq!(round.reveal_next_word_stage(), None);
+    }
+
+    #[test]
+    fn word_is_revealed_in_stages() {
+        let mut round = Round::new(
+            1,
+            "pizza",
+            vec![PLAYER_1.to_string()],
+            GameSettings {
+                word_reveal_stages: 5,
+                ..GameSettings::default()
+            },
+            Arc::default(),
+        );
+
+        assert_eq!(round.masked_word(), "_____");
+        assert_eq!(round.reveal_next_word_stage(), Some("p____".to_string()));
+        assert_eq!(round.reveal_next_word_stage(), Some("pi___".to_string()));
+        assert_eq!(round.reveal_next_word_stage(), Some("piz__".to_string()));
+        assert_eq!(round.reveal_next_word_stage(), Some("pizz_".to_string()));
+        assert_eq!(round.reveal_next_word_stage(), Some("pizza".to_string()));
+        assert_eq!(round.reveal_next_word_stage(), None);
+        assert_eq!(round.masked_word(), "pizza");
+    }
+
+    #[test]
+    fn reveal_word_reveals_the_whole_word() {
+        let mut round = get_round_with_settings(GameSettings {
+            word_reveal_stages: 3,
+            ..GameSettings::default()
+        });
+
+        round.reveal_word();
+
+        assert_eq!(round.masked_word(), "word");
+        assert_eq!(round.reveal_next_word_stage(), None);
+    }
+
+    #[test]
+    fn remove_word_removes_an_unused_word() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+
+        let result = round.remove_word(PLAYER_2, WORD_1);
+
+        assert_eq!(result, Ok(false));
+        assert_eq!(round.player_words[PLAYER_2].len(), 1);
+        assert!(!round.player_voting_words.contains_key(PLAYER_2));
+    }
+
+    #[test]
+    fn remove_word_of_the_voting_item_clears_the_voting_words() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+
+        let result = round.remove_word(PLAYER_1, WORD_1);
+
+        assert_eq!(result, Ok(true));
+        assert!(round.player_voting_words.is_empty());
+    }
+
+    #[test]
+    fn remove_word_fails_when_word_does_not_exist_or_is_used() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round.compute_score();
+
+        assert_eq!(
+            round.remove_word(PLAYER_1, "non_existing"),
+            Err(Error::Domain(DomainError::RemovedWordDoesNotExist))
+        );
+        assert_eq!(
+            round.remove_word("non_existing", WORD_1),
+            Err(Error::Domain(DomainError::RemovedWordDoesNotExist))
+        );
+        assert_eq!(
+            round.remove_word(PLAYER_1, WORD_1),
+            Err(Error::Domain(DomainError::RemovedWordIsAlreadyUsed))
+        );
+    }
+
+    #[test]
+    fn remaining_voting_items_shrink_as_words_are_used() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+
+        assert_eq!(round.remaining_voting_items().len(), 6);
+        assert_eq!(
+            round.remaining_voting_items().first(),
+            round.voting_item.as_ref()
+        );
+
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round.compute_score();
+        round.next_voting_item();
+
+        let remaining_voting_items = round.remaining_voting_items();
+        assert_eq!(remaining_voting_items.len(), 4);
+        assert!(!remaining_voting_items
+            .iter()
+            .any(|item| item.word == WORD_1 && item.player_nickname != PLAYER_3));
+    }
+
     #[test]
     fn round_voting_words_are_initialized_to_empty() {
         let round = get_round_on_writing_state();
@@ -300,6 +867,52 @@ mod tests {
         assert_eq!(result, Ok(()));
     }
 
+    #[test]
+    fn player_can_submit_voting_word_regardless_of_case_when_case_insensitive() {
+        let mut round = get_round_with_settings(GameSettings {
+            case_insensitive_voting_words: true,
+            ..GameSettings::default()
+        });
+        round
+            .add_player_words(PLAYER_1, vec!["Dog".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["Cat".to_string()])
+            .unwrap();
+        round.next_voting_item();
+
+        let result = round.set_player_voting_word(PLAYER_2, Some("cat".to_string()));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            round.player_voting_words.get(PLAYER_2),
+            Some(&Some("Cat".to_string()))
+        );
+        round.compute_score();
+        assert!(get_word(&round, PLAYER_2, "Cat").is_used);
+    }
+
+    #[test]
+    fn player_cannot_submit_voting_word_with_different_case_by_default() {
+        let mut round = get_round_on_writing_state();
+        round
+            .add_player_words(PLAYER_1, vec!["Dog".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["Cat".to_string()])
+            .unwrap();
+        round.next_voting_item();
+
+        let result = round.set_player_voting_word(PLAYER_2, Some("cat".to_string()));
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::PlayerCannotSubmitNonExistingOrUsedVotingWord(PLAYER_2.to_string())
+            ))
+        );
+    }
+
     #[test]
     fn player_cannot_submit_voting_word_when_current_voting_item_is_not_chosen() {
         let mut round = get_round_on_writing_state();
@@ -365,42 +978,447 @@ mod tests {
     }
 
     #[test]
-    fn compute_score_works() {
+    fn player_can_clear_voting_word() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+
+        let result = round.clear_player_voting_word(PLAYER_2);
+
+        assert_eq!(result, Ok(()));
+        assert!(!round.player_voting_words.contains_key(PLAYER_2));
+        assert_eq!(
+            round.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string())),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn current_player_cannot_clear_voting_word() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+
+        let result = round.clear_player_voting_word(PLAYER_1);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::VotingItemPlayerCannotSubmitVotingWord(PLAYER_1.to_string())
+            ))
+        );
+        assert!(round.player_voting_words.contains_key(PLAYER_1));
+    }
+
+    #[test]
+    fn compute_score_ignores_players_that_cleared_their_voting_word() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round
+            .set_player_voting_word(PLAYER_3, Some(WORD_1.to_string()))
+            .unwrap();
+        round.clear_player_voting_word(PLAYER_3).unwrap();
+
+        round.compute_score();
+
+        assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, 2);
+        assert_eq!(get_word(&round, PLAYER_2, WORD_1).score, 2);
+        assert!(!get_word(&round, PLAYER_3, WORD_1).is_used);
+    }
+
+    #[test]
+    fn set_player_voting_word_fails_after_too_many_changes() {
+        let mut round = get_round_with_settings(GameSettings {
+            max_vote_changes_per_item: Some(1),
+            ..GameSettings::default()
+        });
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round.add_player_words(PLAYER_2, words()).unwrap();
+        round.add_player_words(PLAYER_3, words()).unwrap();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_2.to_string()))
+            .unwrap();
+
+        let result = round.set_player_voting_word(PLAYER_2, None);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::TooManyVoteChanges(
+                PLAYER_2.to_string()
+            )))
+        );
+        assert_eq!(
+            round.player_voting_words.get(PLAYER_2),
+            Some(&Some(WORD_2.to_string()))
+        );
+        round.compute_score();
+        round.next_voting_item();
+        assert_eq!(round.set_player_voting_word(PLAYER_2, None), Ok(()));
+    }
+
+    #[test]
+    fn compute_score_works() {
+        let mut round = get_round_on_writing_state();
+        round
+            .add_player_words(PLAYER_1, vec!["p1_w1".to_string(), "p1_w2".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["p2_w1".to_string(), "p2_w2".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_3, vec!["p3_w1".to_string(), "p3_w2".to_string()])
+            .unwrap();
+
+        let voting_item = round.next_voting_item().unwrap();
+
+        assert_eq!(&voting_item.word, "p1_w1");
+
+        round
+            .set_player_voting_word(PLAYER_2, Some("p2_w1".to_string()))
+            .unwrap();
+        round.set_player_voting_word(PLAYER_3, None).unwrap();
+
+        let scored_words = round.compute_score().unwrap().scored_words;
+
+        assert_eq!(
+            scored_words,
+            vec![
+                ScoredWord {
+                    nickname: PLAYER_1.to_string(),
+                    word: "p1_w1".to_string(),
+                    score: 2
+                },
+                ScoredWord {
+                    nickname: PLAYER_2.to_string(),
+                    word: "p2_w1".to_string(),
+                    score: 2
+                }
+            ]
+        );
+        assert_eq!(get_word(&round, PLAYER_1, "p1_w1").score, 2);
+        assert_eq!(get_word(&round, PLAYER_1, "p1_w2").score, 0);
+        assert_eq!(get_word(&round, PLAYER_2, "p2_w1").score, 2);
+        assert_eq!(get_word(&round, PLAYER_2, "p2_w2").score, 0);
+        assert_eq!(get_word(&round, PLAYER_3, "p3_w1").score, 0);
+        assert_eq!(get_word(&round, PLAYER_3, "p3_w2").score, 0);
+        assert!(round.player_voting_words.is_empty());
+        assert!(get_word(&round, PLAYER_1, "p1_w1").is_used);
+        assert!(!get_word(&round, PLAYER_1, "p1_w2").is_used);
+        assert!(get_word(&round, PLAYER_2, "p2_w1").is_used);
+        assert!(!get_word(&round, PLAYER_2, "p2_w2").is_used);
+        assert!(!get_word(&round, PLAYER_3, "p3_w1").is_used);
+        assert!(!get_word(&round, PLAYER_3, "p3_w2").is_used);
+    }
+
+    #[test]
+    fn compute_score_resolves_the_voting_item_with_its_matched_players() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round.set_player_voting_word(PLAYER_3, None).unwrap();
+
+        let resolution = round.compute_score().unwrap();
+
+        assert_eq!(resolution.player_nickname, PLAYER_1);
+        assert_eq!(resolution.word, WORD_1);
+        assert_eq!(resolution.score, 2);
+        assert_eq!(resolution.matched_players, vec![PLAYER_2.to_string()]);
+        assert_eq!(resolution.scored_words.len(), 2);
+    }
+
+    #[test]
+    fn identical_words_are_voted_as_a_group_when_enabled() {
+        let mut round = get_round_with_settings(GameSettings {
+            group_identical_words: true,
+            ..GameSettings::default()
+        });
+        round
+            .add_player_words(PLAYER_1, vec!["apple".to_string(), "p1_w2".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["p2_w1".to_string(), "apple".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_3, vec!["apple".to_string()])
+            .unwrap();
+
+        round.next_voting_item();
+
+        assert_eq!(
+            round.player_voting_words.get(PLAYER_2),
+            Some(&Some("apple".to_string()))
+        );
+        assert_eq!(
+            round.player_voting_words.get(PLAYER_3),
+            Some(&Some("apple".to_string()))
+        );
+        let scored_words = round.compute_score().unwrap().scored_words;
+        assert_eq!(scored_words.len(), 3);
+        assert!(scored_words
+            .iter()
+            .all(|scored_word| scored_word.score == 3));
+        assert!(get_word(&round, PLAYER_2, "apple").is_used);
+        assert!(get_word(&round, PLAYER_3, "apple").is_used);
+        let remaining_words: Vec<String> = round
+            .remaining_voting_items()
+            .into_iter()
+            .map(|voting_item| voting_item.word)
+            .collect();
+        assert_eq!(remaining_words, vec!["p1_w2", "p2_w1"]);
+    }
+
+    #[test]
+    fn identical_words_are_voted_separately_by_default() {
+        let mut round = get_round_on_voting_state();
+
+        round.next_voting_item();
+
+        assert_eq!(round.player_voting_words.get(PLAYER_2), None);
+        assert_eq!(round.player_voting_words.get(PLAYER_3), None);
+        round.compute_score();
+        assert!(!get_word(&round, PLAYER_2, WORD_1).is_used);
+        assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, 0);
+    }
+
+    #[test]
+    fn review_order_follows_the_players_by_default() {
+        let mut round = get_round_on_voting_state();
+
+        round.compute_review_order(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(
+            round.review_order.first(),
+            Some(&ReviewItem {
+                player_nickname: PLAYER_1.to_string(),
+                word: WORD_1.to_string(),
+            })
+        );
+        assert_eq!(round.review_order.len(), 6);
+    }
+
+    #[test]
+    fn review_order_lists_the_highest_scoring_word_first() {
+        let mut round = get_round_with_settings(GameSettings {
+            round_review_order: RoundReviewOrder::ScoreDescending,
+            ..GameSettings::default()
+        });
+        round
+            .add_player_words(PLAYER_1, vec!["p1_w1".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["p2_w1".to_string()])
+            .unwrap();
+        round
+            .add_player_words(PLAYER_3, vec!["p3_w1".to_string(), "p1_w1".to_string()])
+            .unwrap();
+        round.next_voting_item();
+        round.set_player_voting_word(PLAYER_2, None).unwrap();
+        round
+            .set_player_voting_word(PLAYER_3, Some("p1_w1".to_string()))
+            .unwrap();
+        round.compute_score();
+
+        round.compute_review_order(&mut StdRng::seed_from_u64(0));
+
+        assert_eq!(
+            round.review_order.first(),
+            Some(&ReviewItem {
+                player_nickname: PLAYER_1.to_string(),
+                word: "p1_w1".to_string(),
+            })
+        );
+        assert_eq!(
+            round.review_order.get(1),
+            Some(&ReviewItem {
+                player_nickname: PLAYER_3.to_string(),
+                word: "p1_w1".to_string(),
+            })
+        );
+        assert_eq!(
+            round.review_order.get(2),
+            Some(&ReviewItem {
+                player_nickname: PLAYER_2.to_string(),
+                word: "p2_w1".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn shuffled_review_order_is_the_same_for_the_same_seed() {
+        let review_order = || {
+            let mut round = get_round_with_settings(GameSettings {
+                round_review_order: RoundReviewOrder::Shuffled,
+                ..GameSettings::default()
+            });
+            for player in [PLAYER_1, PLAYER_2, PLAYER_3] {
+                round
+                    .add_player_words(
+                        player,
+                        (1..=5).map(|index| format!("{player}_w{index}")).collect(),
+                    )
+                    .unwrap();
+            }
+            round.compute_review_order(&mut StdRng::seed_from_u64(42));
+            round.review_order
+        };
+
+        let review_order_1 = review_order();
+
+        assert_eq!(review_order_1.len(), 15);
+        assert_eq!(review_order_1, review_order());
+    }
+
+    #[test]
+    fn compute_score_is_a_no_op_when_called_twice() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round.compute_score();
+        let expected_player_words = round.player_words.clone();
+
+        let resolution = round.compute_score();
+
+        assert_eq!(resolution, None);
+        for (nickname, expected_words) in expected_player_words {
+            for expected_word in expected_words {
+                let word = get_word(&round, &nickname, &expected_word.word);
+                assert_eq!(word.score, expected_word.score);
+                assert_eq!(word.is_used, expected_word.is_used);
+            }
+        }
+        assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, 2);
+        assert_eq!(get_word(&round, PLAYER_2, WORD_1).score, 2);
+        assert!(round.player_voting_words.is_empty());
+    }
+
+    #[test]
+    fn player_cannot_match_their_own_previously_skipped_word() {
         let mut round = get_round_on_writing_state();
         round
-            .add_player_words(PLAYER_1, vec!["p1_w1".to_string(), "p1_w2".to_string()])
+            .add_player_words(PLAYER_1, vec![WORD_1.to_string()])
             .unwrap();
         round
-            .add_player_words(PLAYER_2, vec!["p2_w1".to_string(), "p2_w2".to_string()])
+            .add_player_words(PLAYER_2, vec![WORD_1.to_string(), WORD_2.to_string()])
             .unwrap();
         round
-            .add_player_words(PLAYER_3, vec!["p3_w1".to_string(), "p3_w2".to_string()])
+            .add_player_words(PLAYER_3, vec![WORD_2.to_string()])
             .unwrap();
 
         let voting_item = round.next_voting_item().unwrap();
+        assert_eq!(voting_item.player_nickname, PLAYER_1);
+        round.set_player_voting_word(PLAYER_2, None).unwrap();
+        round.set_player_voting_word(PLAYER_3, None).unwrap();
+        round.compute_score();
 
-        assert_eq!(&voting_item.word, "p1_w1");
+        let voting_item = round.next_voting_item().unwrap();
+        assert_eq!(voting_item.player_nickname, PLAYER_2);
+        assert_eq!(voting_item.word, WORD_1);
+        assert_eq!(
+            round.set_player_voting_word(PLAYER_2, Some(WORD_2.to_string())),
+            Err(Error::Domain(
+                DomainError::VotingItemPlayerCannotSubmitVotingWord(PLAYER_2.to_string())
+            ))
+        );
+        assert_eq!(
+            round.set_player_voting_word(PLAYER_1, Some(WORD_1.to_string())),
+            Err(Error::Domain(
+                DomainError::PlayerCannotSubmitNonExistingOrUsedVotingWord(PLAYER_1.to_string())
+            ))
+        );
+        round.set_player_voting_word(PLAYER_3, None).unwrap();
+        round.compute_score();
+
+        assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, 0);
+        assert_eq!(get_word(&round, PLAYER_2, WORD_1).score, 0);
+        assert!(get_word(&round, PLAYER_2, WORD_1).is_used);
+        assert!(!get_word(&round, PLAYER_2, WORD_2).is_used);
+    }
 
+    #[test]
+    fn computed_score_is_0_when_matchers_are_below_the_minimum() {
+        let mut round = get_round_with_settings(GameSettings {
+            min_matchers_to_score: 3,
+            ..GameSettings::default()
+        });
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round.add_player_words(PLAYER_2, words()).unwrap();
+        round.add_player_words(PLAYER_3, words()).unwrap();
+        round.next_voting_item();
         round
-            .set_player_voting_word(PLAYER_2, Some("p2_w1".to_string()))
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
             .unwrap();
         round.set_player_voting_word(PLAYER_3, None).unwrap();
 
         round.compute_score();
 
-        assert_eq!(get_word(&round, PLAYER_1, "p1_w1").score, 2);
-        assert_eq!(get_word(&round, PLAYER_1, "p1_w2").score, 0);
-        assert_eq!(get_word(&round, PLAYER_2, "p2_w1").score, 2);
-        assert_eq!(get_word(&round, PLAYER_2, "p2_w2").score, 0);
-        assert_eq!(get_word(&round, PLAYER_3, "p3_w1").score, 0);
-        assert_eq!(get_word(&round, PLAYER_3, "p3_w2").score, 0);
-        assert!(round.player_voting_words.is_empty());
-        assert!(get_word(&round, PLAYER_1, "p1_w1").is_used);
-        assert!(!get_word(&round, PLAYER_1, "p1_w2").is_used);
-        assert!(get_word(&round, PLAYER_2, "p2_w1").is_used);
-        assert!(!get_word(&round, PLAYER_2, "p2_w2").is_used);
-        assert!(!get_word(&round, PLAYER_3, "p3_w1").is_used);
-        assert!(!get_word(&round, PLAYER_3, "p3_w2").is_used);
+        assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, 0);
+        assert_eq!(get_word(&round, PLAYER_2, WORD_1).score, 0);
+        assert!(get_word(&round, PLAYER_2, WORD_1).is_used);
+    }
+
+    #[test]
+    fn all_skip_policy_is_applied_when_all_players_skip_voting() {
+        for (all_skip_policy, expected_score, expected_is_flagged) in [
+            (AllSkipPolicy::ZeroScore, 0, false),
+            (AllSkipPolicy::NegativeOne, -1, false),
+            (AllSkipPolicy::FlagWord, 0, true),
+        ] {
+            let mut round = get_round_with_settings(GameSettings {
+                all_skip_policy,
+                ..GameSettings::default()
+            });
+            round.add_player_words(PLAYER_1, words()).unwrap();
+            round.add_player_words(PLAYER_2, words()).unwrap();
+            round.add_player_words(PLAYER_3, words()).unwrap();
+            round.next_voting_item();
+            round.set_player_voting_word(PLAYER_2, None).unwrap();
+            round.set_player_voting_word(PLAYER_3, None).unwrap();
+
+            let scored_words = round.compute_score().unwrap().scored_words;
+
+            assert_eq!(
+                scored_words,
+                vec![ScoredWord {
+                    nickname: PLAYER_1.to_string(),
+                    word: WORD_1.to_string(),
+                    score: expected_score
+                }]
+            );
+            assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, expected_score);
+            assert_eq!(
+                get_word(&round, PLAYER_1, WORD_1).is_flagged,
+                expected_is_flagged
+            );
+        }
+    }
+
+    #[test]
+    fn all_skip_policy_is_not_applied_when_a_player_has_not_voted() {
+        let mut round = get_round_with_settings(GameSettings {
+            all_skip_policy: AllSkipPolicy::NegativeOne,
+            ..GameSettings::default()
+        });
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round.add_player_words(PLAYER_2, words()).unwrap();
+        round.add_player_words(PLAYER_3, words()).unwrap();
+        round.next_voting_item();
+        round.set_player_voting_word(PLAYER_2, None).unwrap();
+
+        round.compute_score();
+
+        assert_eq!(get_word(&round, PLAYER_1, WORD_1).score, 0);
     }
 
     #[test]
@@ -494,8 +1512,9 @@ mod tests {
         round.add_player_words(PLAYER_1, words()).unwrap();
         round.add_player_words(PLAYER_2, words()).unwrap();
 
-        assert!(round
-            .have_all_players_submitted_words(&vec![PLAYER_1.to_string(), PLAYER_2.to_string()]));
+        assert!(
+            round.have_all_players_submitted_words(&[PLAYER_1.to_string(), PLAYER_2.to_string()])
+        );
     }
 
     #[test]
@@ -508,8 +1527,9 @@ mod tests {
             .add_player_words(PLAYER_2, vec!["".to_string()])
             .unwrap();
 
-        assert!(round
-            .have_all_players_submitted_words(&vec![PLAYER_1.to_string(), PLAYER_2.to_string()]));
+        assert!(
+            round.have_all_players_submitted_words(&[PLAYER_1.to_string(), PLAYER_2.to_string()])
+        );
     }
 
     #[test]
@@ -517,8 +1537,22 @@ mod tests {
         let mut round = get_round_on_writing_state();
         round.add_player_words(PLAYER_1, words()).unwrap();
 
-        assert!(!round
-            .have_all_players_submitted_words(&vec![PLAYER_1.to_string(), PLAYER_2.to_string()]));
+        assert!(
+            !round.have_all_players_submitted_words(&[PLAYER_1.to_string(), PLAYER_2.to_string()])
+        );
+    }
+
+    #[test]
+    fn submission_progress_counts_the_given_players_that_submitted_words() {
+        let mut round = get_round_on_writing_state();
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round.add_player_words(PLAYER_2, words()).unwrap();
+
+        assert_eq!(round.submission_progress(&[PLAYER_1.to_string()]), (1, 1));
+        assert_eq!(
+            round.submission_progress(&[PLAYER_1.to_string(), "p3".to_string()]),
+            (1, 2)
+        );
     }
 
     #[test]
@@ -538,6 +1572,156 @@ mod tests {
         assert_eq!(result, Ok(()));
     }
 
+    #[test]
+    fn add_words_again_replaces_the_previous_words() {
+        let mut round = get_round_on_writing_state();
+        round.add_player_words(PLAYER_1, words()).unwrap();
+
+        let result = round.add_player_words(PLAYER_1, vec![" word3 ".to_string()]);
+
+        assert_eq!(result, Ok(()));
+        let player_words: Vec<&str> = round.player_words[PLAYER_1]
+            .iter()
+            .map(|word| word.word.as_str())
+            .collect();
+        assert_eq!(player_words, vec!["word3"]);
+        assert!(round.have_all_players_submitted_words(&[PLAYER_1.to_string()]));
+    }
+
+    #[test]
+    fn add_words_again_with_repeated_words_keeps_the_previous_words() {
+        let mut round = get_round_on_writing_state();
+        round.add_player_words(PLAYER_1, words()).unwrap();
+
+        let result =
+            round.add_player_words(PLAYER_1, vec!["word3".to_string(), " word3".to_string()]);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::RepeatedWords {
+                nickname: PLAYER_1.to_string(),
+                repeated_words: vec!["word3".to_string()]
+            }))
+        );
+        let player_words: Vec<&str> = round.player_words[PLAYER_1]
+            .iter()
+            .map(|word| word.word.as_str())
+            .collect();
+        assert_eq!(player_words, vec![WORD_1, WORD_2]);
+    }
+
+    #[test]
+    fn add_words_succeeds_when_word_is_at_the_maximum_length() {
+        let mut round = get_round_on_writing_state();
+        let word = "a".repeat(GameSettings::default().max_word_length);
+
+        let result = round.add_player_words(PLAYER_1, vec![format!("  {word} ")]);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(round.player_words[PLAYER_1][0].word, word);
+    }
+
+    #[test]
+    fn add_words_fails_when_word_is_over_the_maximum_length() {
+        let mut round = get_round_on_writing_state();
+        let word = "a".repeat(GameSettings::default().max_word_length + 1);
+
+        let result = round.add_player_words(PLAYER_1, vec![WORD_1.to_string(), word.clone()]);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::WordTooLong {
+                nickname: PLAYER_1.to_string(),
+                word
+            }))
+        );
+        assert!(!round.player_words.contains_key(PLAYER_1));
+    }
+
+    #[test]
+    fn add_words_succeeds_when_words_are_at_the_maximum_amount() {
+        let mut round = get_round_on_writing_state();
+        let words: Vec<String> = (0..GameSettings::default().max_words_per_player)
+            .map(|index| format!("word{index}"))
+            .chain([" ".to_string()])
+            .collect();
+
+        let result = round.add_player_words(PLAYER_1, words);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            round.player_words[PLAYER_1].len(),
+            GameSettings::default().max_words_per_player
+        );
+    }
+
+    #[test]
+    fn add_words_fails_when_words_are_over_the_maximum_amount() {
+        let mut round = get_round_on_writing_state();
+        let max = GameSettings::default().max_words_per_player;
+        let words: Vec<String> = (0..=max).map(|index| format!("word{index}")).collect();
+
+        let result = round.add_player_words(PLAYER_1, words);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::TooManyWords {
+                nickname: PLAYER_1.to_string(),
+                count: max + 1,
+                max
+            }))
+        );
+        assert!(!round.player_words.contains_key(PLAYER_1));
+    }
+
+    #[test]
+    fn add_words_fails_when_words_are_under_the_minimum_amount() {
+        let mut round = get_round_with_settings(GameSettings {
+            min_words_per_player: 2,
+            ..GameSettings::default()
+        });
+
+        let result = round.add_player_words(PLAYER_1, vec![WORD_1.to_string(), " ".to_string()]);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::NotEnoughWords {
+                nickname: PLAYER_1.to_string(),
+                count: 1,
+                min: 2
+            }))
+        );
+        assert_eq!(round.add_player_words(PLAYER_1, words()), Ok(()));
+        assert_eq!(round.fill_player_words(PLAYER_2, vec![]), Ok(()));
+    }
+
+    #[test]
+    fn add_words_fails_when_a_word_is_blocked() {
+        let mut round = get_round_with_blocklist(&["darn"]);
+
+        let result =
+            round.add_player_words(PLAYER_1, vec![WORD_1.to_string(), " DaRn".to_string()]);
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(DomainError::BlockedWord {
+                nickname: PLAYER_1.to_string(),
+                word: "DaRn".to_string()
+            }))
+        );
+        assert!(!round.player_words.contains_key(PLAYER_1));
+    }
+
+    #[test]
+    fn add_words_succeeds_when_no_word_is_blocked() {
+        let mut round = get_round_with_blocklist(&["darn"]);
+
+        let result = round.add_player_words(PLAYER_1, words());
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(round.player_words[PLAYER_1].len(), 2);
+    }
+
     #[test]
     fn add_words_fails_when_repeated_words_before_normalization() {
         let mut round = get_round_on_writing_state();
@@ -624,6 +1808,46 @@ mod tests {
         assert_eq!(words[2].word, "word  34");
     }
 
+    #[test]
+    fn internal_whitespace_is_collapsed_when_enabled() {
+        let mut round = get_round_with_settings(GameSettings {
+            collapse_internal_whitespace: true,
+            ..GameSettings::default()
+        });
+
+        round
+            .add_player_words(
+                PLAYER_1,
+                vec![" word  34 ".to_string(), "word \t 56".to_string()],
+            )
+            .unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["word 34".to_string()])
+            .unwrap();
+
+        assert_eq!(round.player_words[PLAYER_1][0].word, "word 34");
+        assert_eq!(round.player_words[PLAYER_1][1].word, "word 56");
+        assert_eq!(
+            round.player_words[PLAYER_1][0].word,
+            round.player_words[PLAYER_2][0].word
+        );
+    }
+
+    #[test]
+    fn words_with_collapsed_whitespace_are_repeated() {
+        let mut round = get_round_with_settings(GameSettings {
+            collapse_internal_whitespace: true,
+            ..GameSettings::default()
+        });
+
+        let result = round.add_player_words(
+            PLAYER_1,
+            vec!["word 34".to_string(), "word  34".to_string()],
+        );
+
+        assert_repeated_words_error(result, PLAYER_1, vec!["word 34".to_string()]);
+    }
+
     #[test]
     fn empty_words_are_filtered() {
         let mut round = get_round_on_writing_state();
@@ -648,6 +1872,21 @@ mod tests {
         assert_eq!(words[2].word, "word3");
     }
 
+    #[test]
+    fn only_players_that_submitted_words_themselves_are_participants() {
+        let mut round = get_round_on_writing_state();
+
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round
+            .add_player_words(PLAYER_2, vec!["   ".to_string()])
+            .unwrap();
+        round.fill_player_words(PLAYER_3, words()).unwrap();
+
+        assert!(round.is_participant(PLAYER_1));
+        assert!(!round.is_participant(PLAYER_2));
+        assert!(!round.is_participant(PLAYER_3));
+    }
+
     #[test]
     fn on_next_voting_item_players_without_words_auto_skip() {
         let mut round = get_round_on_writing_state();
@@ -666,6 +1905,31 @@ mod tests {
         assert_eq!(round.player_voting_words.get(PLAYER_3), None);
     }
 
+    #[test]
+    fn on_next_voting_item_nobody_auto_skips_when_explicit_votes_are_required() {
+        let mut round = get_round_with_settings(GameSettings {
+            require_explicit_votes: true,
+            ..GameSettings::default()
+        });
+
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round.add_player_words(PLAYER_2, vec![]).unwrap();
+        round.add_player_words(PLAYER_3, words()).unwrap();
+
+        let _ = round.next_voting_item().unwrap();
+
+        assert_eq!(
+            round.player_voting_words.get(PLAYER_1),
+            Some(&Some(WORD_1.to_string()))
+        );
+        assert_eq!(round.player_voting_words.get(PLAYER_2), None);
+        assert_eq!(round.player_voting_words.get(PLAYER_3), None);
+
+        round.set_player_voting_word(PLAYER_2, None).unwrap();
+
+        assert_eq!(round.player_voting_words.get(PLAYER_2), Some(&None));
+    }
+
     #[test]
     fn on_next_voting_item_players_without_words_auto_skip_for_first_players() {
         let mut round = get_round_on_writing_state();
@@ -693,14 +1957,14 @@ mod tests {
         round.add_player_words(PLAYER_3, words()).unwrap();
 
         let _ = round.next_voting_item().unwrap();
-        let _ = round.set_player_voting_word(PLAYER_2, None).unwrap();
-        let _ = round
+        round.set_player_voting_word(PLAYER_2, None).unwrap();
+        round
             .set_player_voting_word(PLAYER_3, Some(WORD_1.to_string()))
             .unwrap();
         round.compute_score();
         let _ = round.next_voting_item().unwrap();
-        let _ = round.set_player_voting_word(PLAYER_2, None).unwrap();
-        let _ = round
+        round.set_player_voting_word(PLAYER_2, None).unwrap();
+        round
             .set_player_voting_word(PLAYER_3, Some(WORD_2.to_string()))
             .unwrap();
         round.compute_score();
@@ -735,14 +1999,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejected_word_cannot_be_resubmitted() {
+        let mut round = get_round_on_voting_state();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round.reject_player_word(PLAYER_2, WORD_1).unwrap();
+
+        let result = round.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()));
+
+        assert_eq!(
+            result,
+            Err(Error::Domain(
+                DomainError::CannotResubmitRejectedMatchedWord
+            ))
+        );
+    }
+
+    #[test]
+    fn rejected_word_can_be_resubmitted_when_allowed() {
+        let mut round = get_round_with_settings(GameSettings {
+            allow_resubmit_after_reject: true,
+            ..GameSettings::default()
+        });
+        round.add_player_words(PLAYER_1, words()).unwrap();
+        round.add_player_words(PLAYER_2, words()).unwrap();
+        round.add_player_words(PLAYER_3, words()).unwrap();
+        round.next_voting_item();
+        round
+            .set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()))
+            .unwrap();
+        round.reject_player_word(PLAYER_2, WORD_1).unwrap();
+
+        let result = round.set_player_voting_word(PLAYER_2, Some(WORD_1.to_string()));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            round.player_voting_words.get(PLAYER_2),
+            Some(&Some(WORD_1.to_string()))
+        );
+        assert!(round.voting_item.unwrap().rejected_matches.is_empty());
+    }
+
     fn get_round_on_writing_state() -> Round {
+        get_round_with_settings(GameSettings::default())
+    }
+
+    fn get_round_with_settings(settings: GameSettings) -> Round {
         Round::new(
+            1,
             "word",
             vec![
                 PLAYER_1.to_string(),
                 PLAYER_2.to_string(),
                 PLAYER_3.to_string(),
             ],
+            settings,
+            Arc::default(),
+        )
+    }
+
+    fn get_round_with_blocklist(blocklist: &[&str]) -> Round {
+        Round::new(
+            1,
+            "word",
+            vec![PLAYER_1.to_string()],
+            GameSettings::default(),
+            Arc::new(HashSet::from_iter(
+                blocklist.iter().map(|word| word.to_string()),
+            )),
         )
     }
 
@@ -1,42 +1,214 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
 
-use axum::extract::{Path, WebSocketUpgrade};
-use axum::http::StatusCode;
+use axum::body::Bytes;
+use axum::extract::{ConnectInfo, Path, Query, WebSocketUpgrade};
+use axum::http::{HeaderMap, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::{extract::State, Json};
 use serde::{Deserialize, Serialize};
 
+use crate::config::GameSettings;
+use crate::error::external_error::ExternalError;
+use crate::error::Error;
+use crate::game::actor::PublicGameState;
+use crate::game::nickname::Nickname;
 use crate::game_factory::actor_client::GameFactoryClient;
 use crate::player::actor::PlayerActor;
+use crate::player::tasks::PlayerTasks;
+use crate::player::Subscription;
+use crate::websocket::catalog::Language;
 use crate::websocket::{close, send_error};
 
-#[derive(Deserialize)]
-pub struct CreateGameRequest {}
+use super::rate_limiter::RateLimiter;
+
+#[derive(Default, Deserialize)]
+pub struct CreateGameRequest {
+    title: Option<String>,
+    // The name of a configured list of words, the default one when missing or unknown
+    category: Option<String>,
+    // Used instead of the words of the category when there are enough of them
+    words: Option<Vec<String>>,
+}
 
 #[derive(Serialize)]
 pub struct CreateGameResponse {
     id: String,
 }
 
-pub async fn create(State(game_factory): State<Arc<GameFactoryClient>>) -> Response {
-    match game_factory.create_game().await {
+#[derive(Deserialize)]
+pub struct ConnectPlayerQuery {
+    #[serde(default)]
+    subscribe: Subscription,
+    // Issued by the rejoin endpoint, needed to reclaim a disconnected player by its nickname
+    ticket: Option<String>,
+    // Sent to the player when first joining, it reclaims the same player under any nickname
+    reconnection_token: Option<String>,
+    // The language of the error titles, English when missing or unknown
+    lang: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct RejoinRequest {
+    nickname: String,
+    // Sent to the player when first joining, nobody else can rejoin as that player
+    reconnection_token: String,
+}
+
+#[derive(Serialize)]
+pub struct RejoinResponse {
+    ticket: String,
+}
+
+pub async fn create(
+    State(game_factory): State<Arc<GameFactoryClient>>,
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let ip = rate_limiter.client_ip(&headers, address);
+    if !rate_limiter.try_acquire(ip) {
+        log::warn!("Game creation rate limit reached. Ip: '{ip}'.");
+        return StatusCode::TOO_MANY_REQUESTS.into_response();
+    }
+    // The body is optional, but a body that is sent has to be a valid request
+    let request = if body.is_empty() {
+        CreateGameRequest::default()
+    } else {
+        match serde_json::from_slice::<CreateGameRequest>(&body) {
+            Ok(request) => request,
+            Err(error) => {
+                log::info!("Invalid create game request. Error: '{error}'.");
+                return StatusCode::BAD_REQUEST.into_response();
+            }
+        }
+    };
+    match game_factory
+        .create_game(request.title, request.category, request.words)
+        .await
+    {
         Ok(game_id) => (StatusCode::OK, Json(CreateGameResponse { id: game_id })).into_response(),
+        Err(Error::Domain(_)) => StatusCode::BAD_REQUEST.into_response(),
+        Err(Error::External(ExternalError::GameFactoryTimeout(_))) => {
+            StatusCode::SERVICE_UNAVAILABLE.into_response()
+        }
+        Err(Error::External(ExternalError::GameCreationThrottled(max_creates_per_second))) => {
+            log::warn!(
+                "Global game creation limit reached. MaximumPerSecond: '{max_creates_per_second}'."
+            );
+            StatusCode::SERVICE_UNAVAILABLE.into_response()
+        }
+        Err(Error::External(ExternalError::ServerShuttingDown)) => {
+            StatusCode::SERVICE_UNAVAILABLE.into_response()
+        }
         Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
     }
 }
 
 pub async fn connect_player_to_websocket(
     State(game_factory): State<Arc<GameFactoryClient>>,
+    State(game_settings): State<Arc<GameSettings>>,
+    State(player_tasks): State<PlayerTasks>,
     Path((game_id, nickname)): Path<(String, String)>,
+    Query(query): Query<ConnectPlayerQuery>,
     websocket_upgrade: WebSocketUpgrade,
 ) -> Response {
+    let player_task = player_tasks.start();
     websocket_upgrade.on_upgrade(move |mut websocket| async move {
+        let _player_task = player_task;
+        let language = query
+            .lang
+            .as_deref()
+            .map(Language::from_code)
+            .unwrap_or_default();
+        let nickname = match Nickname::try_new(&nickname) {
+            Ok(nickname) => String::from(nickname),
+            Err(error) => {
+                send_error(&mut websocket, &error, language).await;
+                close(websocket).await;
+                return;
+            }
+        };
         match game_factory.get_game(&game_id).await {
-            Ok(game) => PlayerActor::create(nickname, game, websocket).await,
+            Ok(game) => {
+                PlayerActor::create(
+                    nickname,
+                    query.reconnection_token,
+                    query.ticket,
+                    game,
+                    websocket,
+                    query.subscribe,
+                    language,
+                    game_settings.player_ping_timeout(),
+                    game_settings.player_heartbeat_interval(),
+                )
+                .await
+            }
             Err(error) => {
-                send_error(&mut websocket, &error).await;
+                send_error(&mut websocket, &error, language).await;
                 close(websocket).await;
             }
         }
     })
 }
+
+pub async fn rejoin(
+    State(game_factory): State<Arc<GameFactoryClient>>,
+    Path(game_id): Path<String>,
+    Json(request): Json<RejoinRequest>,
+) -> Response {
+    let game = match game_factory.get_game(&game_id).await {
+        Ok(game) => game,
+        Err(Error::Domain(_)) => return StatusCode::NOT_FOUND.into_response(),
+        Err(Error::External(ExternalError::GameFactoryTimeout(_))) => {
+            return StatusCode::SERVICE_UNAVAILABLE.into_response()
+        }
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    match game
+        .issue_rejoin_ticket(&request.nickname, request.reconnection_token)
+        .await
+    {
+        Ok(ticket) => (StatusCode::OK, Json(RejoinResponse { ticket })).into_response(),
+        Err(Error::Domain(_)) => StatusCode::FORBIDDEN.into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+pub async fn get_public_state(
+    State(game_factory): State<Arc<GameFactoryClient>>,
+    Path(game_id): Path<String>,
+) -> Response {
+    let game_state = match game_factory.get_game(&game_id).await {
+        Ok(game) => game.get_game_state().await,
+        Err(error) => Err(error),
+    };
+    match game_state {
+        Ok(PublicGameState {
+            title,
+            state,
+            players,
+            rounds,
+            amount_of_rounds,
+            player_total_scores,
+        }) => (
+            StatusCode::OK,
+            Json(PlayerActor::serialize_game_state(
+                None,
+                title,
+                state,
+                players,
+                rounds,
+                amount_of_rounds,
+                player_total_scores,
+            )),
+        )
+            .into_response(),
+        Err(Error::Domain(_)) => StatusCode::NOT_FOUND.into_response(),
+        Err(Error::External(ExternalError::GameFactoryTimeout(_))) => {
+            StatusCode::SERVICE_UNAVAILABLE.into_response()
+        }
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
@@ -1,28 +1,81 @@
+use axum::extract::FromRef;
 use axum::routing::{get, post};
 use axum::Router;
 use std::sync::Arc;
 use tower_http::cors::CorsLayer;
 
-use crate::config::Config;
+use crate::config::{Config, GameSettings};
 use crate::game_factory::actor_client::GameFactoryClient;
+use crate::player::tasks::PlayerTasks;
+
+use self::rate_limiter::RateLimiter;
 
 mod game;
 mod health;
 mod metrics;
+mod protocol;
+mod rate_limiter;
+mod stats;
+
+#[derive(Clone)]
+pub struct AppState {
+    game_factory: Arc<GameFactoryClient>,
+    game_creation_rate_limiter: Arc<RateLimiter>,
+    game_settings: Arc<GameSettings>,
+    player_tasks: PlayerTasks,
+}
+
+impl FromRef<AppState> for Arc<GameFactoryClient> {
+    fn from_ref(state: &AppState) -> Self {
+        state.game_factory.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<RateLimiter> {
+    fn from_ref(state: &AppState) -> Self {
+        state.game_creation_rate_limiter.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<GameSettings> {
+    fn from_ref(state: &AppState) -> Self {
+        state.game_settings.clone()
+    }
+}
 
-pub fn create_router(config: Config) -> Router<Arc<GameFactoryClient>> {
+impl FromRef<AppState> for PlayerTasks {
+    fn from_ref(state: &AppState) -> Self {
+        state.player_tasks.clone()
+    }
+}
+
+pub fn create_router(
+    config: Config,
+    game_factory: Arc<GameFactoryClient>,
+    player_tasks: PlayerTasks,
+) -> Router {
     Router::new()
         .route("/health", get(health::get))
         .route("/metrics", get(metrics::metrics_handler))
+        .route("/stats", get(stats::get))
+        .route("/protocol/states", get(protocol::get_states))
         .route("/game", post(game::create))
         .route(
             "/game/:game_id/player/:nickname/ws",
             get(game::connect_player_to_websocket),
         )
+        .route("/game/:game_id/rejoin", post(game::rejoin))
+        .route("/game/:game_id/public-state", get(game::get_public_state))
         .layer(if config.allow_cors {
             log::info!("CorsLayer Permissive");
             CorsLayer::permissive()
         } else {
             CorsLayer::default()
         })
+        .with_state(AppState {
+            game_factory,
+            game_creation_rate_limiter: Arc::new(RateLimiter::new(config.game_creation_rate_limit)),
+            game_settings: Arc::new(config.game),
+            player_tasks,
+        })
 }
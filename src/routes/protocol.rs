@@ -0,0 +1,22 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+
+use crate::websocket::message::{state_to_string, GAME_FSM_STATES};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatesResponse {
+    states: Vec<String>,
+}
+
+pub async fn get_states() -> Response {
+    (
+        StatusCode::OK,
+        Json(StatesResponse {
+            states: GAME_FSM_STATES.into_iter().map(state_to_string).collect(),
+        }),
+    )
+        .into_response()
+}
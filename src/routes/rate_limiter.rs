@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use axum::http::HeaderMap;
+
+use crate::config::RateLimitSettings;
+
+// Token bucket per IP: each IP can spend up to `burst` tokens at once, and gets one token back
+// every refill interval
+pub struct RateLimiter {
+    settings: RateLimitSettings,
+    buckets: Mutex<Buckets>,
+}
+
+struct Buckets {
+    by_ip: HashMap<IpAddr, Bucket>,
+    last_eviction: Instant,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant, burst: f64, refill_interval: f64) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() / refill_interval).min(burst);
+        self.last_refill = now;
+    }
+}
+
+impl RateLimiter {
+    pub fn new(settings: RateLimitSettings) -> Self {
+        Self {
+            settings,
+            buckets: Mutex::new(Buckets {
+                by_ip: HashMap::new(),
+                last_eviction: Instant::now(),
+            }),
+        }
+    }
+
+    // Behind a proxy every connection comes from the proxy, so the header it sets is used instead
+    pub fn client_ip(&self, headers: &HeaderMap, address: SocketAddr) -> IpAddr {
+        self.settings
+            .client_ip_header
+            .as_ref()
+            .and_then(|header| headers.get(header))
+            .and_then(|value| value.to_str().ok())
+            // The proxy appends the address it saw, the entries before it come from the client
+            .and_then(|value| value.rsplit(',').next())
+            .and_then(|ip| ip.trim().parse().ok())
+            .unwrap_or_else(|| address.ip())
+    }
+
+    pub fn try_acquire(&self, ip: IpAddr) -> bool {
+        self.try_acquire_at(ip, Instant::now())
+    }
+
+    fn try_acquire_at(&self, ip: IpAddr, now: Instant) -> bool {
+        if !self.settings.is_enabled() {
+            return true;
+        }
+
+        let burst = f64::from(self.settings.burst);
+        let refill_interval = self.settings.refill_interval().as_secs_f64();
+        let mut buckets = self.buckets.lock().expect("RateLimiter mutex is poisoned.");
+
+        // Only the bucket of this IP is refilled, the others catch up when they are used again
+        let bucket = buckets.by_ip.entry(ip).or_insert(Bucket {
+            tokens: burst,
+            last_refill: now,
+        });
+        bucket.refill(now, burst, refill_interval);
+        let is_acquired = bucket.tokens >= 1.0;
+        if is_acquired {
+            bucket.tokens -= 1.0;
+        }
+
+        // A full bucket behaves exactly like a missing one, dropping them keeps the map bounded.
+        // Every bucket is full again after refilling a whole burst, so they are swept that often
+        let time_to_fill = Duration::from_secs_f64(refill_interval * burst);
+        if now.saturating_duration_since(buckets.last_eviction) >= time_to_fill {
+            buckets.by_ip.retain(|_, bucket| {
+                bucket.refill(now, burst, refill_interval);
+                bucket.tokens < burst
+            });
+            buckets.last_eviction = now;
+        }
+        is_acquired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::time::{Duration, Instant};
+
+    use axum::http::HeaderMap;
+
+    use crate::config::RateLimitSettings;
+
+    use super::RateLimiter;
+
+    static IP_1: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    static IP_2: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+
+    #[test]
+    fn disabled_rate_limiter_always_acquires() {
+        let rate_limiter = get_rate_limiter(0, 60);
+        let now = Instant::now();
+
+        assert!((0..100).all(|_| rate_limiter.try_acquire_at(IP_1, now)));
+    }
+
+    #[test]
+    fn rate_limiter_rejects_when_burst_is_spent() {
+        let rate_limiter = get_rate_limiter(2, 60);
+        let now = Instant::now();
+
+        assert!(rate_limiter.try_acquire_at(IP_1, now));
+        assert!(rate_limiter.try_acquire_at(IP_1, now));
+        assert!(!rate_limiter.try_acquire_at(IP_1, now));
+    }
+
+    #[test]
+    fn rate_limiter_is_keyed_by_ip() {
+        let rate_limiter = get_rate_limiter(1, 60);
+        let now = Instant::now();
+
+        assert!(rate_limiter.try_acquire_at(IP_1, now));
+        assert!(!rate_limiter.try_acquire_at(IP_1, now));
+        assert!(rate_limiter.try_acquire_at(IP_2, now));
+    }
+
+    #[test]
+    fn rate_limiter_refills_over_time() {
+        let rate_limiter = get_rate_limiter(1, 60);
+        let now = Instant::now();
+
+        assert!(rate_limiter.try_acquire_at(IP_1, now));
+        assert!(!rate_limiter.try_acquire_at(IP_1, now + Duration::from_secs(30)));
+        assert!(rate_limiter.try_acquire_at(IP_1, now + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn full_buckets_are_evicted_once_a_burst_is_refilled() {
+        let rate_limiter = get_rate_limiter(2, 60);
+        let now = Instant::now();
+
+        assert!(rate_limiter.try_acquire_at(IP_1, now));
+        assert!(rate_limiter.try_acquire_at(IP_2, now + Duration::from_secs(120)));
+
+        let buckets = rate_limiter.buckets.lock().unwrap();
+        assert_eq!(buckets.by_ip.keys().collect::<Vec<_>>(), vec![&IP_2]);
+    }
+
+    #[test]
+    fn client_ip_is_the_last_address_of_the_configured_header() {
+        let rate_limiter = RateLimiter::new(RateLimitSettings {
+            client_ip_header: Some("x-forwarded-for".to_string()),
+            ..RateLimitSettings::default()
+        });
+        let proxy_address = SocketAddr::new(IP_1, 80);
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(rate_limiter.client_ip(&headers, proxy_address), IP_1);
+        headers.insert("x-forwarded-for", "1.2.3.4, 10.0.0.2".parse().unwrap());
+        assert_eq!(rate_limiter.client_ip(&headers, proxy_address), IP_2);
+    }
+
+    #[test]
+    fn client_ip_is_the_connection_address_without_a_configured_header() {
+        let rate_limiter = get_rate_limiter(1, 60);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "10.0.0.2".parse().unwrap());
+
+        assert_eq!(
+            rate_limiter.client_ip(&headers, SocketAddr::new(IP_1, 80)),
+            IP_1
+        );
+    }
+
+    fn get_rate_limiter(burst: u32, refill_interval_seconds: u64) -> RateLimiter {
+        RateLimiter::new(RateLimitSettings {
+            burst,
+            refill_interval_seconds,
+            client_ip_header: None,
+        })
+    }
+}
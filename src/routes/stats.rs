@@ -0,0 +1,32 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+
+use crate::metrics::{
+    ACTIVE_GAMES, CONNECTED_PLAYERS, GAMES_CREATED, ROUNDS_PLAYED, WORDS_SUBMITTED,
+};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsResponse {
+    games_created: u64,
+    rounds_played: u64,
+    words_submitted: u64,
+    active_games: i64,
+    connected_players: i64,
+}
+
+pub async fn get() -> Response {
+    (
+        StatusCode::OK,
+        Json(StatsResponse {
+            games_created: GAMES_CREATED.get(),
+            rounds_played: ROUNDS_PLAYED.get(),
+            words_submitted: WORDS_SUBMITTED.get(),
+            active_games: ACTIVE_GAMES.get(),
+            connected_players: CONNECTED_PLAYERS.get(),
+        }),
+    )
+        .into_response()
+}
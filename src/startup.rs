@@ -1,25 +1,39 @@
 use tokio::net::TcpListener;
+use tokio::signal;
 
 use crate::config::Config;
+use crate::game::Game;
 use crate::game_factory::actor::GameFactoryActor;
+use crate::game_factory::DEFAULT_WORD_CATEGORY;
+use crate::player::tasks::PlayerTasks;
 use crate::routes;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
+use std::future::Future;
 use std::io::{BufRead, BufReader};
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+
+// How long the games and the players have to say goodbye once the server is told to shut down
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub async fn create_web_server(
     config: Config,
     listener: TcpListener,
+    shutdown_signal: impl Future<Output = ()> + Send + 'static,
 ) -> Result<(), std::io::Error> {
-    let words = read_words_from_file(&config.words_file);
-    log::info!(
-        "Words loaded. File: '{}', Words: '{}'.",
-        config.words_file,
-        words.join(",")
-    );
-    let game_factory = Arc::new(GameFactoryActor::spawn(config.game.clone(), words));
+    let words = read_word_categories(&config.words_file, &config.word_categories).await;
+    let blocklist = read_blocklist(config.blocklist_file.as_deref());
+    log::info!("Blocklist loaded. Words: '{}'.", blocklist.len());
+    let game_factory = Arc::new(GameFactoryActor::spawn(
+        config.game.clone(),
+        words,
+        blocklist,
+    ));
 
-    let router = routes::create_router(config).with_state(game_factory);
+    let player_tasks = PlayerTasks::default();
+    let router = routes::create_router(config, game_factory.clone(), player_tasks.clone());
 
     log::info!(
         "Listening on {}",
@@ -28,20 +42,157 @@ pub async fn create_web_server(
             .expect("Can't get the local address of the listener.")
     );
 
-    axum::serve(listener, router).await
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        shutdown_signal.await;
+        log::info!("Shutting down, closing every game.");
+        let close_games_and_players = async {
+            if let Err(error) = game_factory.shut_down().await {
+                log::error!("Could not close the games before shutting down. Error: '{error}'.");
+            }
+            // The players are told by their own actors, after the games broadcast it
+            player_tasks.wait_until_stopped().await;
+        };
+        if tokio::time::timeout(SHUTDOWN_TIMEOUT, close_games_and_players)
+            .await
+            .is_err()
+        {
+            log::warn!(
+                "Not every game and player was closed after {} seconds, shutting down anyway.",
+                SHUTDOWN_TIMEOUT.as_secs()
+            );
+        }
+    })
+    .await
+}
+
+// Ctrl+C, or SIGTERM on Unix
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("Failed to listen for Ctrl+C.");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Failed to listen for SIGTERM.")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+// The words file is always the default category, even if another category has the same name
+async fn read_word_categories(
+    words_file: &str,
+    word_categories: &HashMap<String, String>,
+) -> HashMap<String, Vec<String>> {
+    let mut categories = HashMap::new();
+    for (category, source) in word_categories {
+        let words = read_words(source).await;
+        log::info!(
+            "Word category loaded. Category: '{category}', File: '{source}', Words: '{}'.",
+            words.len()
+        );
+        categories.insert(category.to_string(), words);
+    }
+    let words = read_words(words_file).await;
+    log::info!(
+        "Words loaded. File: '{}', Words: '{}'.",
+        words_file,
+        words.join(",")
+    );
+    categories.insert(DEFAULT_WORD_CATEGORY.to_string(), words);
+    categories
+}
+
+async fn read_words(source: &str) -> Vec<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        read_words_from_url(source).await
+    } else {
+        read_words_from_file(source)
+    }
 }
 
 fn read_words_from_file(file_path: &str) -> Vec<String> {
     let file = File::open(file_path).unwrap_or_else(|error| {
         panic!("Could not load words file. File: '{file_path}', Error: '{error}'.")
     });
-    BufReader::new(file)
-        .lines()
-        .map(|line| {
-            line.expect("Could not parse one of the word lines.")
-                .trim()
-                .to_lowercase()
-        })
+    normalize_words(
+        BufReader::new(file)
+            .lines()
+            .map(|line| line.expect("Could not parse one of the word lines.")),
+    )
+}
+
+// Unlike the words file, the blocklist is optional, so a missing file only leaves it empty
+fn read_blocklist(file_path: Option<&str>) -> HashSet<String> {
+    let Some(file_path) = file_path else {
+        return HashSet::default();
+    };
+    match File::open(file_path) {
+        Ok(file) => normalize_words(BufReader::new(file).lines().map_while(Result::ok))
+            .into_iter()
+            .collect(),
+        Err(error) => {
+            log::warn!("Could not load the blocklist file, no words are blocked. File: '{file_path}', Error: '{error}'.");
+            HashSet::default()
+        }
+    }
+}
+
+async fn read_words_from_url(url: &str) -> Vec<String> {
+    let response = match reqwest::get(url)
+        .await
+        .and_then(|response| response.error_for_status())
+    {
+        Ok(response) => response.text().await,
+        Err(error) => Err(error),
+    };
+    match response {
+        Ok(text) => normalize_words(text.lines().map(|line| line.to_string())),
+        Err(error) => {
+            log::warn!("Could not fetch the words, defaulting to the built-in list of words. Url: '{url}', Error: '{error}'.");
+            Game::default_words()
+        }
+    }
+}
+
+pub(crate) fn normalize_words(lines: impl Iterator<Item = String>) -> Vec<String> {
+    lines
+        .map(|line| line.trim().to_lowercase())
         .filter(|word| !word.is_empty())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use tokio::net::TcpListener;
+
+    use crate::game::Game;
+
+    use super::read_words;
+
+    #[tokio::test]
+    async fn default_words_are_used_when_url_cannot_be_fetched() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        // Without any route every request is answered with a not found
+        tokio::spawn(async move { axum::serve(listener, Router::new()).await });
+
+        let words = read_words(&format!("http://{address}/words")).await;
+
+        assert_eq!(words, Game::default_words());
+    }
+}
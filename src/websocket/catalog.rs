@@ -0,0 +1,149 @@
+// The language of the error titles sent to a player, chosen with the `lang` query param on connect.
+// The error types are stable codes and stay in English
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    // Unknown languages fall back to English
+    pub fn from_code(code: &str) -> Language {
+        match code.to_lowercase().as_str() {
+            "es" => Language::Spanish,
+            _ => Language::English,
+        }
+    }
+
+    // English has no table, the title is the message of the error itself
+    pub fn error_title(&self, error_type: &str) -> Option<&'static str> {
+        match self {
+            Language::English => None,
+            Language::Spanish => spanish_error_title(error_type),
+        }
+    }
+}
+
+fn spanish_error_title(error_type: &str) -> Option<&'static str> {
+    Some(match error_type {
+        "BLOCKED_WORD" => "La palabra no está permitida.",
+        "CANNOT_REJECT_MATCHED_WORDS_WHEN_VOTING_ITEM_IS_NONE" => {
+            "No se pueden rechazar palabras si no hay ninguna palabra en votación."
+        }
+        "CANNOT_RESUBMIT_REJECTED_MATCHED_WORD" => {
+            "No se puede volver a enviar una palabra rechazada."
+        }
+        "CANNOT_KICK_SELF" => "Un jugador no puede expulsarse a sí mismo.",
+        "CANNOT_MERGE_PLAYER_WITH_ITSELF" => "Un jugador no puede fusionarse consigo mismo.",
+        "CHAT_MESSAGE_TOO_LONG" => "El mensaje es demasiado largo.",
+        "GAME_ALREADY_IN_PROGRESS" => "La partida ya está en curso.",
+        "GAME_ALREADY_STARTED" => "La partida ya ha empezado.",
+        "GAME_DOES_NOT_EXIST" => "La partida no existe.",
+        "GAME_RECENTLY_CLOSED" => "La partida se ha cerrado hace poco.",
+        "INVALID_STATE_FOR_REJECTING_MATCHED_WORDS" => {
+            "No se pueden rechazar palabras en este momento de la partida."
+        }
+        "INVALID_STATE_FOR_REMOVING_WORDS" => {
+            "No se pueden eliminar palabras en este momento de la partida."
+        }
+        "INVALID_NICKNAME" => {
+            "El apodo debe tener entre 1 y 20 caracteres y ningún carácter de control."
+        }
+        "INVALID_RECONNECTION_TOKEN" => {
+            "El token de reconexión no pertenece a ningún jugador de la partida."
+        }
+        "INVALID_REJOIN_TICKET" => "El ticket para volver a unirse no es válido o ha caducado.",
+        "INVALID_STATE_FOR_MERGING_PLAYERS" => {
+            "No se pueden fusionar jugadores en este momento de la partida."
+        }
+        "INVALID_STATE_FOR_POST_GAME_ACTION" => {
+            "Esta acción solo está disponible al final de la partida."
+        }
+        "INVALID_STATE_FOR_SKIPPING_VOTING_ITEM" => {
+            "No se puede saltar la palabra en votación en este momento de la partida."
+        }
+        "INVALID_TITLE_LENGTH" => "La longitud del título no es válida.",
+        "KICKED_PLAYER_DOES_NOT_EXIST" => "El jugador a expulsar no existe.",
+        "MERGED_PLAYER_DOES_NOT_EXIST" => "El jugador a fusionar no existe.",
+        "NEW_HOST_IS_NOT_AVAILABLE" => "El nuevo anfitrión no existe o no está conectado.",
+        "INVALID_STATE_FOR_WORDS_SUBMISSION" => {
+            "No se pueden enviar palabras en este momento de la partida."
+        }
+        "INVALID_STATE_FOR_VOTING_WORD_SUBMISSION" => {
+            "No se puede votar una palabra en este momento de la partida."
+        }
+        "NON_HOST_CANNOT_REJECT_MATCHED_WORDS" => "Solo el anfitrión puede rechazar palabras.",
+        "NON_HOST_CANNOT_REMOVE_WORDS" => "Solo el anfitrión puede eliminar palabras.",
+        "NON_HOST_PLAYER_CANNOT_KICK" => "Solo el anfitrión puede expulsar a otros jugadores.",
+        "NON_HOST_PLAYER_CANNOT_MERGE_PLAYERS" => "Solo el anfitrión puede fusionar jugadores.",
+        "NON_HOST_PLAYER_CANNOT_END_SESSION" => "Solo el anfitrión puede terminar la sesión.",
+        "NON_HOST_PLAYER_CANNOT_SKIP_VOTING_ITEM" => {
+            "Solo el anfitrión puede saltar la palabra en votación."
+        }
+        "NON_HOST_PLAYER_CANNOT_TRANSFER_HOST" => "Solo el anfitrión puede ceder el anfitrión.",
+        "NOT_ENOUGH_PLAYERS" => "No hay suficientes jugadores para empezar la partida.",
+        "NOT_ENOUGH_ROUNDS" => "No hay suficientes rondas.",
+        "NOT_EVERY_PLAYER_HAS_VOTED" => "Todavía no han votado todos los jugadores.",
+        "NOT_ENOUGH_WORDS" => "No se han enviado suficientes palabras.",
+        "NON_HOST_PLAYER_CANNOT_SEND_PLAY_AGAIN" => {
+            "Solo el anfitrión puede volver a jugar la partida."
+        }
+        "NON_HOST_PLAYER_CANNOT_CONTINUE_TO_NEXT_ROUND" => {
+            "Solo el anfitrión puede pasar a la siguiente ronda."
+        }
+        "NON_HOST_PLAYER_CANNOT_CONTINUE_TO_NEXT_VOTING_ITEM" => {
+            "Solo el anfitrión puede pasar a la siguiente palabra en votación."
+        }
+        "NON_HOST_PLAYER_CANNOT_START_GAME" => "Solo el anfitrión puede empezar la partida.",
+        "NON_HOST_PLAYER_CANNOT_SET_TITLE" => "Solo el anfitrión puede cambiar el título.",
+        "PLAYER_ALREADY_EXISTS" => "Ya existe un jugador con el mismo apodo.",
+        "PLAYER_WAS_KICKED" => "El jugador ha sido expulsado de la partida.",
+        "PLAYER_CANNOT_SUBMIT_NON_EXISTING_OR_USED_WORD" => {
+            "No se puede votar una palabra que no existe o que ya se ha usado."
+        }
+        "PLAYER_CANNOT_SUBMIT_VOTING_WORD_WHEN_VOTING_ITEM_IS_NONE" => {
+            "No se puede votar si no hay ninguna palabra en votación."
+        }
+        "REJOINING_PLAYER_DOES_NOT_EXIST" => "El jugador que vuelve no forma parte de la partida.",
+        "REJECTED_MATCHED_WORD_DOES_NOT_EXIST" => "La palabra rechazada no existe.",
+        "REJECTED_MATCHED_PLAYER_DOES_NOT_EXIST" => "El jugador de la palabra rechazada no existe.",
+        "REMOVED_WORD_DOES_NOT_EXIST" => "La palabra a eliminar no existe.",
+        "REMOVED_WORD_IS_ALREADY_USED" => "La palabra a eliminar ya se ha usado.",
+        "REPEATED_WORDS" => "No se pueden enviar palabras repetidas.",
+        "REJECTED_MATCHED_WORD_WAS_NOT_PICKED_BY_PLAYER" => {
+            "El jugador no ha votado la palabra rechazada."
+        }
+        "TOO_MANY_ROUNDS" => "Hay demasiadas rondas.",
+        "TOO_MANY_VOTE_CHANGES" => "Se ha cambiado el voto demasiadas veces.",
+        "TOO_MANY_WORDS" => "Se han enviado demasiadas palabras.",
+        "WORD_TOO_LONG" => "La palabra es demasiado larga.",
+        "WORDS_ALREADY_SUBMITTED" => "Las palabras ya se han enviado.",
+        "VOTING_ITEM_TOO_SOON" => "Todavía no se pueden aceptar los votos.",
+        "VOTING_ITEM_PLAYER_CANNOT_SUBMIT_VOTING_WORD" => {
+            "El jugador de la palabra en votación no puede votar."
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Language;
+
+    #[test]
+    fn unknown_language_falls_back_to_english() {
+        assert_eq!(Language::from_code("ES"), Language::Spanish);
+        assert_eq!(Language::from_code("xx"), Language::English);
+        assert_eq!(Language::English.error_title("GAME_DOES_NOT_EXIST"), None);
+    }
+
+    #[test]
+    fn spanish_titles_are_keyed_by_the_error_type() {
+        assert_eq!(
+            Language::Spanish.error_title("GAME_DOES_NOT_EXIST"),
+            Some("La partida no existe.")
+        );
+        assert_eq!(Language::Spanish.error_title("UNKNOWN"), None);
+    }
+}
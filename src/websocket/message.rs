@@ -3,9 +3,9 @@ use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    game::game_fsm::GameFsmState,
+    game::{actor::ChatHistoryMessage, game_fsm::GameFsmState, ScoreBreakdownEntry},
     player::Player,
-    round::{Round, VotingItem, Word},
+    round::{RemainingVotingItem, ReviewItem, Round, VotingItem, Word},
 };
 
 #[derive(Serialize)]
@@ -13,19 +13,161 @@ use crate::{
 pub(crate) enum WsMessageOut {
     #[serde(rename_all = "camelCase")]
     Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
         r#type: String,
         title: String,
         detail: String,
     },
     #[serde(rename_all = "camelCase")]
+    Ack {
+        request_id: String,
+        ok: bool,
+    },
+    #[serde(rename_all = "camelCase")]
     GameState {
+        title: String,
         state: String,
         players: Vec<PlayerDto>,
         rounds: Vec<RoundDto>,
         amount_of_rounds: Option<u8>,
+        player_total_scores: HashMap<String, isize>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChatMessage {
+        sender: String,
+        content: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerTyping {
+        nickname: String,
+        is_typing: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    Players {
+        players: Vec<PlayerDto>,
+    },
+    #[serde(rename_all = "camelCase")]
+    LastWords {
+        words: Vec<String>,
+    },
+    // Only sent to the player that joined, presenting it again on connection reclaims the player
+    #[serde(rename_all = "camelCase")]
+    ReconnectionToken {
+        reconnection_token: String,
+    },
+    // Only sent to a player that reconnects, the welcome is the player itself
+    #[serde(rename_all = "camelCase")]
+    ReconnectSnapshot {
+        welcome: PlayerDto,
+        state: Box<WsMessageOut>,
+        chat_history: Vec<ChatHistoryMessageDto>,
+        my_words: Vec<WordDto>,
+    },
+    // Only sent to the player that submitted the words, the empty ones are dropped
+    #[serde(rename_all = "camelCase")]
+    WordsAccepted {
+        accepted: Vec<String>,
+        dropped: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    RemainingVotingItems {
+        items: Vec<RemainingVotingItemDto>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ScoreBreakdown {
+        breakdown: HashMap<String, Vec<ScoreBreakdownEntryDto>>,
+    },
+    // Only sent to the player that requested it, the rounds that are not included in the game state
+    #[serde(rename_all = "camelCase")]
+    RoundHistory {
+        rounds: Vec<RoundDto>,
+        total: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    WordScored {
+        owner: String,
+        word: String,
+        score: isize,
+    },
+    #[serde(rename_all = "camelCase")]
+    VotingItemResolved {
+        player_nickname: String,
+        word: String,
+        score: isize,
+        matched_players: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    SystemMessage {
+        text: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    WordReveal {
+        masked: String,
+    },
+    YouAreNext,
+    #[serde(rename_all = "camelCase")]
+    FinalScoreboard {
+        ranking: Vec<(String, isize)>,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerJoined {
+        nickname: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerReconnected {
+        nickname: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerLeft {
+        nickname: String,
+        reason: String,
     },
     #[serde(rename_all = "camelCase")]
-    ChatMessage { sender: String, content: String },
+    PhaseDeadline {
+        state: String,
+        seconds_remaining: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    PreparingVoting {
+        seconds_remaining: u64,
+    },
+    SessionEnded,
+    ServerShuttingDown,
+}
+
+impl WsMessageOut {
+    // The serialized kind of the message, names it in the logs without any of its contents
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WsMessageOut::Error { .. } => "error",
+            WsMessageOut::Ack { .. } => "ack",
+            WsMessageOut::GameState { .. } => "gameState",
+            WsMessageOut::ChatMessage { .. } => "chatMessage",
+            WsMessageOut::PlayerTyping { .. } => "playerTyping",
+            WsMessageOut::Players { .. } => "players",
+            WsMessageOut::LastWords { .. } => "lastWords",
+            WsMessageOut::ReconnectionToken { .. } => "reconnectionToken",
+            WsMessageOut::ReconnectSnapshot { .. } => "reconnectSnapshot",
+            WsMessageOut::WordsAccepted { .. } => "wordsAccepted",
+            WsMessageOut::RemainingVotingItems { .. } => "remainingVotingItems",
+            WsMessageOut::ScoreBreakdown { .. } => "scoreBreakdown",
+            WsMessageOut::RoundHistory { .. } => "roundHistory",
+            WsMessageOut::WordScored { .. } => "wordScored",
+            WsMessageOut::VotingItemResolved { .. } => "votingItemResolved",
+            WsMessageOut::SystemMessage { .. } => "systemMessage",
+            WsMessageOut::WordReveal { .. } => "wordReveal",
+            WsMessageOut::YouAreNext => "youAreNext",
+            WsMessageOut::FinalScoreboard { .. } => "finalScoreboard",
+            WsMessageOut::PlayerJoined { .. } => "playerJoined",
+            WsMessageOut::PlayerReconnected { .. } => "playerReconnected",
+            WsMessageOut::PlayerLeft { .. } => "playerLeft",
+            WsMessageOut::PhaseDeadline { .. } => "phaseDeadline",
+            WsMessageOut::PreparingVoting { .. } => "preparingVoting",
+            WsMessageOut::SessionEnded => "sessionEnded",
+            WsMessageOut::ServerShuttingDown => "serverShuttingDown",
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -39,6 +181,8 @@ pub enum WsMessageIn {
     ChatMessage {
         content: String,
     },
+    TypingStarted,
+    TypingStopped,
     #[serde(rename_all = "camelCase")]
     PlayerWords {
         words: Vec<String>,
@@ -48,13 +192,82 @@ pub enum WsMessageIn {
         word: Option<String>,
     },
     #[serde(rename_all = "camelCase")]
+    SetTitle {
+        title: String,
+    },
+    #[serde(rename_all = "camelCase")]
     RejectMatchedWord {
         rejected_player: String,
         rejected_word: String,
     },
+    #[serde(rename_all = "camelCase")]
+    RemoveWord {
+        player: String,
+        word: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    KickPlayer {
+        nickname: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    MergePlayers {
+        source: String,
+        target: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    TransferHost {
+        nickname: String,
+    },
+    ClearVotingWord,
     AcceptPlayersVotingWords,
+    SkipVotingItem,
     ContinueToNextRound,
+    // Goes back to the lobby, where the host chooses the settings of the next game
     PlayAgain,
+    PlayAgainSameSettings,
+    EndSession,
+    GetPlayers,
+    GetLastWords,
+    GetRemainingVotingItems,
+    GetScoreBreakdown,
+    // A page of the rounds left out of the game state, the oldest first
+    #[serde(rename_all = "camelCase")]
+    GetRoundHistory {
+        #[serde(default)]
+        offset: usize,
+        limit: Option<usize>,
+    },
+}
+
+impl WsMessageIn {
+    // The serialized kind of every variant, tells a message of an unsupported kind apart from a
+    // malformed one. Must be kept in sync with the variants
+    pub const KINDS: &'static [&'static str] = &[
+        "startGame",
+        "chatMessage",
+        "typingStarted",
+        "typingStopped",
+        "playerWords",
+        "playerVotingWord",
+        "setTitle",
+        "rejectMatchedWord",
+        "removeWord",
+        "kickPlayer",
+        "mergePlayers",
+        "transferHost",
+        "clearVotingWord",
+        "acceptPlayersVotingWords",
+        "skipVotingItem",
+        "continueToNextRound",
+        "playAgain",
+        "playAgainSameSettings",
+        "endSession",
+        "getPlayers",
+        "getLastWords",
+        "getRemainingVotingItems",
+        "getScoreBreakdown",
+        "getRoundHistory",
+    ];
 }
 
 #[derive(Serialize)]
@@ -75,19 +288,42 @@ impl From<Player> for PlayerDto {
     }
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChatHistoryMessageDto {
+    sender: String,
+    content: String,
+}
+
+impl From<ChatHistoryMessage> for ChatHistoryMessageDto {
+    fn from(val: ChatHistoryMessage) -> Self {
+        Self {
+            sender: val.sender,
+            content: val.content,
+        }
+    }
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RoundDto {
+    pub round: usize,
     pub word: String,
     pub player_words: HashMap<String, Vec<WordDto>>,
     pub player_voting_words: HashMap<String, Option<String>>,
     pub voting_item: Option<VotingItemDto>,
+    pub review_order: Vec<ReviewItemDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitted_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_count: Option<usize>,
 }
 
 impl From<Round> for RoundDto {
     fn from(val: Round) -> Self {
         Self {
-            word: val.word,
+            round: val.number,
+            word: val.masked_word(),
             player_words: val
                 .player_words
                 .iter()
@@ -100,6 +336,29 @@ impl From<Round> for RoundDto {
                 .collect(),
             player_voting_words: val.player_voting_words,
             voting_item: val.voting_item.map(|voting_item| voting_item.into()),
+            review_order: val
+                .review_order
+                .into_iter()
+                .map(|review_item| review_item.into())
+                .collect(),
+            submitted_count: None,
+            expected_count: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewItemDto {
+    player_nickname: String,
+    word: String,
+}
+
+impl From<ReviewItem> for ReviewItemDto {
+    fn from(val: ReviewItem) -> Self {
+        Self {
+            player_nickname: val.player_nickname,
+            word: val.word,
         }
     }
 }
@@ -109,7 +368,8 @@ impl From<Round> for RoundDto {
 pub struct WordDto {
     word: String,
     is_used: bool,
-    score: usize,
+    score: isize,
+    is_flagged: bool,
 }
 
 impl From<Word> for WordDto {
@@ -118,6 +378,7 @@ impl From<Word> for WordDto {
             word: val.word.clone(),
             is_used: val.is_used,
             score: val.score,
+            is_flagged: val.is_flagged,
         }
     }
 }
@@ -140,11 +401,59 @@ impl From<VotingItem> for VotingItemDto {
     }
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemainingVotingItemDto {
+    player_nickname: String,
+    word: Option<String>,
+}
+
+impl From<RemainingVotingItem> for RemainingVotingItemDto {
+    fn from(val: RemainingVotingItem) -> Self {
+        Self {
+            player_nickname: val.player_nickname,
+            word: val.word,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreBreakdownEntryDto {
+    round: usize,
+    // Null for the participation points of the round
+    word: Option<String>,
+    score: isize,
+}
+
+impl From<ScoreBreakdownEntry> for ScoreBreakdownEntryDto {
+    fn from(val: ScoreBreakdownEntry) -> Self {
+        Self {
+            round: val.round,
+            word: val.word,
+            score: val.score,
+        }
+    }
+}
+
+// Every state of the GameFsm, keep it in sync with state_to_string
+pub const GAME_FSM_STATES: [GameFsmState; 8] = [
+    GameFsmState::Lobby,
+    GameFsmState::CreatingNewRound,
+    GameFsmState::PlayersSubmittingWords,
+    GameFsmState::PreparingVoting,
+    GameFsmState::ChooseNextVotingItem,
+    GameFsmState::PlayersSubmittingVotingWord,
+    GameFsmState::EndOfRound,
+    GameFsmState::EndOfGame,
+];
+
 pub fn state_to_string(state: GameFsmState) -> String {
     match state {
         GameFsmState::Lobby => "Lobby".to_string(),
         GameFsmState::CreatingNewRound => "CreatingNewRound".to_string(),
         GameFsmState::PlayersSubmittingWords => "PlayersSubmittingWords".to_string(),
+        GameFsmState::PreparingVoting => "PreparingVoting".to_string(),
         GameFsmState::ChooseNextVotingItem => "ChooseNextVotingItem".to_string(),
         GameFsmState::PlayersSubmittingVotingWord => "PlayersSubmittingVotingWord".to_string(),
         GameFsmState::EndOfRound => "EndOfRound".to_string(),
@@ -1,22 +1,39 @@
+pub mod catalog;
 pub mod message;
 
-use axum::extract::ws::{Message, WebSocket};
-use serde::Serialize;
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket};
+use serde::Deserialize;
 
 use crate::error::domain_error::DomainError;
 use crate::error::external_error::ExternalError;
 use crate::error::Error;
+use crate::metrics::ERRORS;
 use crate::websocket::message::WsMessageOut;
 
+use self::catalog::Language;
 use self::message::WsMessageIn;
 
-pub async fn send_error(websocket: &mut WebSocket, error: &Error) {
+pub async fn send_error(websocket: &mut WebSocket, error: &Error, language: Language) {
+    send_request_error(websocket, error, None, language).await;
+}
+
+pub async fn send_request_error(
+    websocket: &mut WebSocket,
+    error: &Error,
+    request_id: Option<String>,
+    language: Language,
+) {
     match error {
         // Do not return internal errors to the user
         Error::Internal(_) => {}
         _ => {
+            ERRORS.with_label_values(&[error_type(error)]).inc();
             // We are closing the websocket, ignore if there's any error sending the last message
-            let _ = send_message(websocket, &error_to_ws_error(error.clone())).await;
+            let _ = send_message(
+                websocket,
+                &error_to_ws_error(error.clone(), request_id, language),
+            )
+            .await;
         }
     }
 }
@@ -26,25 +43,63 @@ pub async fn close(websocket: WebSocket) {
     let _ = websocket.close().await;
 }
 
+#[derive(Deserialize)]
+struct WsMessageKind {
+    kind: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WsMessageRequestId {
+    request_id: Option<String>,
+}
+
+// The request id is optional on every message, so it is read apart from the message itself
+pub fn parse_request_id(message: &str) -> Option<String> {
+    serde_json::from_str::<WsMessageRequestId>(message)
+        .ok()
+        .and_then(|message| message.request_id)
+}
+
 pub fn parse_message(message: &str) -> Result<WsMessageIn, Error> {
     serde_json::from_str(message).map_err(|error| {
-        Error::External(ExternalError::UnprocessableWebsocketMessage(
-            message.to_string(),
-            error.to_string(),
-        ))
+        match serde_json::from_str::<WsMessageKind>(message) {
+            // The message is well-formed, but this server does not support its kind
+            Ok(WsMessageKind { kind }) if !WsMessageIn::KINDS.contains(&kind.as_str()) => {
+                Error::External(ExternalError::UnknownMessageKind(kind))
+            }
+            _ => Error::External(ExternalError::UnprocessableWebsocketMessage(
+                message.to_string(),
+                error.to_string(),
+            )),
+        }
     })
 }
 
-pub async fn send_message<T>(websocket: &mut WebSocket, value: &T) -> Result<(), Error>
-where
-    T: ?Sized + Serialize,
-{
-    let message = serde_json::to_string(value).map_err(|error| {
-        Error::log_and_create_internal(&format!(
-            "Could not serialize the message. Error: '{error}'."
-        ))
-    })?;
-    send_message_string(websocket, &message).await
+// The reason of the close frame sent when the server fails, without the details of the failure
+const INTERNAL_CLOSE_REASON: &str = "Internal server error.";
+
+pub async fn send_message(websocket: &mut WebSocket, value: &WsMessageOut) -> Result<(), Error> {
+    match serde_json::to_string(value) {
+        Ok(message) => send_message_string(websocket, &message).await,
+        // The messages are our own DTOs, so failing to serialize one is a bug. The player would get no
+        // feedback otherwise, so the connection is closed telling them the server failed
+        Err(error) => {
+            // Only the kind is logged, the contents can hold the reconnection token of the player
+            let error = Error::log_and_create_internal(&format!(
+                "Could not serialize the '{}' message. Error: '{error}'.",
+                value.kind()
+            ));
+            // The websocket might already be closed, if so, ignore the error
+            let _ = websocket
+                .send(Message::Close(Some(CloseFrame {
+                    code: close_code::ERROR,
+                    reason: INTERNAL_CLOSE_REASON.into(),
+                })))
+                .await;
+            Err(error)
+        }
+    }
 }
 
 pub async fn send_message_string(websocket: &mut WebSocket, value: &str) -> Result<(), Error> {
@@ -54,79 +109,139 @@ pub async fn send_message_string(websocket: &mut WebSocket, value: &str) -> Resu
         .map_err(|error| Error::External(ExternalError::WebsocketClosed(error.to_string())))
 }
 
-fn error_to_ws_error(error: Error) -> WsMessageOut {
+pub async fn send_ping(websocket: &mut WebSocket) -> Result<(), Error> {
+    websocket
+        .send(Message::Ping(Vec::default()))
+        .await
+        .map_err(|error| Error::External(ExternalError::WebsocketClosed(error.to_string())))
+}
+
+fn error_type(error: &Error) -> &'static str {
+    match error {
+        Error::Domain(domain_error) => match domain_error {
+            DomainError::BlockedWord { .. } => "BLOCKED_WORD",
+            DomainError::CannotRejectMatchedWordsWhenVotingItemIsNone => {
+                "CANNOT_REJECT_MATCHED_WORDS_WHEN_VOTING_ITEM_IS_NONE"
+            }
+            DomainError::CannotResubmitRejectedMatchedWord => {
+                "CANNOT_RESUBMIT_REJECTED_MATCHED_WORD"
+            }
+            DomainError::CannotKickSelf(_) => "CANNOT_KICK_SELF",
+            DomainError::CannotMergePlayerWithItself(_) => "CANNOT_MERGE_PLAYER_WITH_ITSELF",
+            DomainError::ChatMessageTooLong { .. } => "CHAT_MESSAGE_TOO_LONG",
+            DomainError::GameAlreadyInProgress(_) => "GAME_ALREADY_IN_PROGRESS",
+            DomainError::GameAlreadyStarted(_) => "GAME_ALREADY_STARTED",
+            DomainError::GameDoesNotExist(_) => "GAME_DOES_NOT_EXIST",
+            DomainError::GameRecentlyClosed(_) => "GAME_RECENTLY_CLOSED",
+            DomainError::InvalidStateForRejectingMatchedWords(_, _) => {
+                "INVALID_STATE_FOR_REJECTING_MATCHED_WORDS"
+            }
+            DomainError::InvalidStateForRemovingWords(_, _) => "INVALID_STATE_FOR_REMOVING_WORDS",
+            DomainError::InvalidNickname(_) => "INVALID_NICKNAME",
+            DomainError::InvalidReconnectionToken => "INVALID_RECONNECTION_TOKEN",
+            DomainError::InvalidRejoinTicket(_) => "INVALID_REJOIN_TICKET",
+            DomainError::InvalidStateForMergingPlayers(_, _) => "INVALID_STATE_FOR_MERGING_PLAYERS",
+            DomainError::InvalidStateForPostGameAction(_, _) => {
+                "INVALID_STATE_FOR_POST_GAME_ACTION"
+            }
+            DomainError::InvalidStateForSkippingVotingItem(_, _) => {
+                "INVALID_STATE_FOR_SKIPPING_VOTING_ITEM"
+            }
+            DomainError::InvalidTitleLength(_, _) => "INVALID_TITLE_LENGTH",
+            DomainError::KickedPlayerDoesNotExist(_) => "KICKED_PLAYER_DOES_NOT_EXIST",
+            DomainError::MergedPlayerDoesNotExist(_) => "MERGED_PLAYER_DOES_NOT_EXIST",
+            DomainError::NewHostIsNotAvailable(_) => "NEW_HOST_IS_NOT_AVAILABLE",
+            DomainError::InvalidStateForWordsSubmission(_, _) => {
+                "INVALID_STATE_FOR_WORDS_SUBMISSION"
+            }
+            DomainError::InvalidStateForVotingWordSubmission(_, _) => {
+                "INVALID_STATE_FOR_VOTING_WORD_SUBMISSION"
+            }
+            DomainError::NonHostCannotRejectMatchedWords => "NON_HOST_CANNOT_REJECT_MATCHED_WORDS",
+            DomainError::NonHostCannotRemoveWords => "NON_HOST_CANNOT_REMOVE_WORDS",
+            DomainError::NonHostPlayerCannotKick(_) => "NON_HOST_PLAYER_CANNOT_KICK",
+            DomainError::NonHostPlayerCannotMergePlayers(_) => {
+                "NON_HOST_PLAYER_CANNOT_MERGE_PLAYERS"
+            }
+            DomainError::NonHostPlayerCannotEndSession(_) => "NON_HOST_PLAYER_CANNOT_END_SESSION",
+            DomainError::NonHostPlayerCannotSkipVotingItem(_) => {
+                "NON_HOST_PLAYER_CANNOT_SKIP_VOTING_ITEM"
+            }
+            DomainError::NonHostPlayerCannotTransferHost(_) => {
+                "NON_HOST_PLAYER_CANNOT_TRANSFER_HOST"
+            }
+            DomainError::NotEnoughPlayers(_, _) => "NOT_ENOUGH_PLAYERS",
+            DomainError::NotEnoughRounds(_, _) => "NOT_ENOUGH_ROUNDS",
+            DomainError::NotEveryPlayerHasVoted(_) => "NOT_EVERY_PLAYER_HAS_VOTED",
+            DomainError::NotEnoughWords { .. } => "NOT_ENOUGH_WORDS",
+            DomainError::NonHostPlayerCannotSendPlayAgain(_) => {
+                "NON_HOST_PLAYER_CANNOT_SEND_PLAY_AGAIN"
+            }
+            DomainError::NonHostPlayerCannotContinueToNextRound(_) => {
+                "NON_HOST_PLAYER_CANNOT_CONTINUE_TO_NEXT_ROUND"
+            }
+            DomainError::NonHostPlayerCannotContinueToNextVotingItem(_) => {
+                "NON_HOST_PLAYER_CANNOT_CONTINUE_TO_NEXT_VOTING_ITEM"
+            }
+            DomainError::NonHostPlayerCannotStartGame(_) => "NON_HOST_PLAYER_CANNOT_START_GAME",
+            DomainError::NonHostPlayerCannotSetTitle(_) => "NON_HOST_PLAYER_CANNOT_SET_TITLE",
+            DomainError::PlayerAlreadyExists(_) => "PLAYER_ALREADY_EXISTS",
+            DomainError::PlayerWasKicked(_) => "PLAYER_WAS_KICKED",
+            DomainError::PlayerCannotSubmitNonExistingOrUsedVotingWord(_) => {
+                "PLAYER_CANNOT_SUBMIT_NON_EXISTING_OR_USED_WORD"
+            }
+            DomainError::PlayerCannotSubmitVotingWordWhenVotingItemIsNone(_) => {
+                "PLAYER_CANNOT_SUBMIT_VOTING_WORD_WHEN_VOTING_ITEM_IS_NONE"
+            }
+            DomainError::RejoiningPlayerDoesNotExist(_) => "REJOINING_PLAYER_DOES_NOT_EXIST",
+            DomainError::RejectedMatchedWordDoesNotExist => "REJECTED_MATCHED_WORD_DOES_NOT_EXIST",
+            DomainError::RejectedMatchedPlayerDoesNotExist => {
+                "REJECTED_MATCHED_PLAYER_DOES_NOT_EXIST"
+            }
+            DomainError::RemovedWordDoesNotExist => "REMOVED_WORD_DOES_NOT_EXIST",
+            DomainError::RemovedWordIsAlreadyUsed => "REMOVED_WORD_IS_ALREADY_USED",
+            DomainError::RepeatedWords { .. } => "REPEATED_WORDS",
+            DomainError::RejectedMatchedWordWasNotPickedByPlayer => {
+                "REJECTED_MATCHED_WORD_WAS_NOT_PICKED_BY_PLAYER"
+            }
+            DomainError::TooManyRounds(_, _) => "TOO_MANY_ROUNDS",
+            DomainError::TooManyVoteChanges(_) => "TOO_MANY_VOTE_CHANGES",
+            DomainError::TooManyWords { .. } => "TOO_MANY_WORDS",
+            DomainError::WordTooLong { .. } => "WORD_TOO_LONG",
+            DomainError::WordsAlreadySubmitted(_) => "WORDS_ALREADY_SUBMITTED",
+            DomainError::VotingItemTooSoon(_) => "VOTING_ITEM_TOO_SOON",
+            DomainError::VotingItemPlayerCannotSubmitVotingWord(_) => {
+                "VOTING_ITEM_PLAYER_CANNOT_SUBMIT_VOTING_WORD"
+            }
+        },
+        Error::External(external_error) => match external_error {
+            ExternalError::UnprocessableWebsocketMessage(_, _) => "UNPROCESSABLE_WEBSOCKET_MESSAGE",
+            ExternalError::GameFactoryTimeout(_) => "GAME_FACTORY_TIMEOUT",
+            ExternalError::GameCreationThrottled(_) => "GAME_CREATION_THROTTLED",
+            ExternalError::ServerShuttingDown => "SERVER_SHUTTING_DOWN",
+            ExternalError::UnknownMessageKind(_) => "UNKNOWN_MESSAGE_KIND",
+            ExternalError::WebsocketClosed(_) => "WEBSOCKET_CLOSED",
+        },
+        Error::Internal(_) => "INTERNAL",
+    }
+}
+
+fn error_to_ws_error(error: Error, request_id: Option<String>, language: Language) -> WsMessageOut {
+    let r#type = error_type(&error);
     WsMessageOut::Error {
-        r#type: match error {
-            Error::Domain(ref domain_error) => match domain_error {
-                DomainError::CannotRejectMatchedWordsWhenVotingItemIsNone => {
-                    "CANNOT_REJECT_MATCHED_WORDS_WHEN_VOTING_ITEM_IS_NONE"
-                }
-                DomainError::CannotResubmitRejectedMatchedWord => {
-                    "CANNOT_RESUBMIT_REJECTED_MATCHED_WORD"
-                }
-                DomainError::GameAlreadyInProgress(_) => "GAME_ALREADY_IN_PROGRESS",
-                DomainError::GameDoesNotExist(_) => "GAME_DOES_NOT_EXIST",
-                DomainError::InvalidStateForRejectingMatchedWords(_, _) => {
-                    "INVALID_STATE_FOR_REJECTING_MATCHED_WORDS"
-                }
-                DomainError::InvalidStateForWordsSubmission(_, _) => {
-                    "INVALID_STATE_FOR_WORDS_SUBMISSION"
-                }
-                DomainError::InvalidStateForVotingWordSubmission(_, _) => {
-                    "INVALID_STATE_FOR_VOTING_WORD_SUBMISSION"
-                }
-                DomainError::NonHostCannotRejectMatchedWords => {
-                    "NON_HOST_CANNOT_REJECT_MATCHED_WORDS"
-                }
-                DomainError::NotEnoughPlayers(_, _) => "NOT_ENOUGH_PLAYERS",
-                DomainError::NotEnoughRounds(_, _) => "NOT_ENOUGH_ROUNDS",
-                DomainError::NonHostPlayerCannotSendPlayAgain(_) => {
-                    "NON_HOST_PLAYER_CANNOT_SEND_PLAY_AGAIN"
-                }
-                DomainError::NonHostPlayerCannotContinueToNextRound(_) => {
-                    "NON_HOST_PLAYER_CANNOT_CONTINUE_TO_NEXT_ROUND"
-                }
-                DomainError::NonHostPlayerCannotContinueToNextVotingItem(_) => {
-                    "NON_HOST_PLAYER_CANNOT_CONTINUE_TO_NEXT_VOTING_ITEM"
-                }
-                DomainError::NonHostPlayerCannotStartGame(_) => "NON_HOST_PLAYER_CANNOT_START_GAME",
-                DomainError::PlayerAlreadyExists(_) => "PLAYER_ALREADY_EXISTS",
-                DomainError::PlayerCannotSubmitNonExistingOrUsedVotingWord(_) => {
-                    "PLAYER_CANNOT_SUBMIT_NON_EXISTING_OR_USED_WORD"
-                }
-                DomainError::PlayerCannotSubmitVotingWordWhenVotingItemIsNone(_) => {
-                    "PLAYER_CANNOT_SUBMIT_VOTING_WORD_WHEN_VOTING_ITEM_IS_NONE"
-                }
-                DomainError::RejectedMatchedWordDoesNotExist => {
-                    "REJECTED_MATCHED_WORD_DOES_NOT_EXIST"
-                }
-                DomainError::RejectedMatchedPlayerDoesNotExist => {
-                    "REJECTED_MATCHED_PLAYER_DOES_NOT_EXIST"
-                }
-                DomainError::RepeatedWords { .. } => "REPEATED_WORDS",
-                DomainError::RejectedMatchedWordWasNotPickedByPlayer => {
-                    "REJECTED_MATCHED_WORD_WAS_NOT_PICKED_BY_PLAYER"
-                }
-                DomainError::VotingItemPlayerCannotSubmitVotingWord(_) => {
-                    "VOTING_ITEM_PLAYER_CANNOT_SUBMIT_VOTING_WORD"
-                }
-            },
-            Error::External(ref external_error) => match external_error {
-                ExternalError::UnprocessableWebsocketMessage(_, _) => {
-                    "UNPROCESSABLE_WEBSOCKET_MESSAGE"
-                }
-                ExternalError::WebsocketClosed(_) => "WEBSOCKET_CLOSED",
-            },
-            Error::Internal(_) => "INTERNAL",
-        }
-        .to_string(),
-        title: error.to_string(),
+        request_id,
+        r#type: r#type.to_string(),
+        title: language
+            .error_title(r#type)
+            .map(str::to_string)
+            .unwrap_or_else(|| error.to_string()),
         detail: match error {
             Error::Domain(domain_error) => match domain_error {
                 DomainError::RepeatedWords {
                     nickname: _,
                     repeated_words,
                 } => repeated_words.join(","),
+                DomainError::WordTooLong { nickname: _, word } => word,
                 _ => domain_error.to_string(),
             },
             Error::External(external_error) => external_error.to_string(),
@@ -134,3 +249,64 @@ fn error_to_ws_error(error: Error) -> WsMessageOut {
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::error::external_error::ExternalError;
+    use crate::error::Error;
+
+    use super::message::{WsMessageIn, WsMessageOut};
+    use super::parse_message;
+
+    #[test]
+    fn kind_of_an_outgoing_message_is_its_serialized_kind() {
+        let messages = [
+            WsMessageOut::ReconnectionToken {
+                reconnection_token: "token".to_string(),
+            },
+            WsMessageOut::PlayerLeft {
+                nickname: "p1".to_string(),
+                reason: "kicked".to_string(),
+            },
+            WsMessageOut::SessionEnded,
+        ];
+
+        for message in messages {
+            let serialized = serde_json::to_value(&message).unwrap();
+
+            assert_eq!(serialized["kind"], message.kind());
+        }
+    }
+
+    #[test]
+    fn known_message_kinds_are_the_kinds_of_the_variants() {
+        for kind in WsMessageIn::KINDS {
+            let result = serde_json::from_str::<WsMessageIn>(&format!(r#"{{"kind":"{kind}"}}"#));
+
+            // Missing fields are fine, the kind itself has to be recognized
+            assert!(
+                result
+                    .err()
+                    .is_none_or(|error| !error.to_string().starts_with("unknown variant")),
+                "Unknown kind: '{kind}'."
+            );
+        }
+    }
+
+    #[test]
+    fn message_of_an_unknown_kind_is_told_apart_from_a_malformed_one() {
+        let unknown_kind_result = parse_message(r#"{"kind":"danceParty"}"#);
+        let malformed_result = parse_message(r#"{"kind":"startGame"}"#);
+
+        assert!(matches!(
+            unknown_kind_result,
+            Err(Error::External(ExternalError::UnknownMessageKind(kind))) if kind == "danceParty"
+        ));
+        assert!(matches!(
+            malformed_result,
+            Err(Error::External(
+                ExternalError::UnprocessableWebsocketMessage(_, _)
+            ))
+        ));
+    }
+}
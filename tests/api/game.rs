@@ -1,10 +1,15 @@
 use std::time::Duration;
 
+use headcrab::config::{Config, ScoreEventVerbosity};
+use headcrab::metrics::ERRORS;
+
 use crate::helpers::{
     test_app::TestApp,
-    test_game::{GameFsmState, TestGame},
+    test_game::{ChatHistoryMessage, GameFsmState, TestGame, WsMessageIn},
+    test_script::Action,
 };
 
+use futures_util::StreamExt;
 use tokio::time;
 use tokio_tungstenite::tungstenite::Message;
 
@@ -13,6 +18,165 @@ async fn create_game_works() {
     let _ = TestApp::create_game(GameFsmState::Lobby).await;
 }
 
+#[tokio::test]
+async fn game_creation_is_rate_limited_per_ip() {
+    let app = TestApp::spawn_app_with_config(|config| {
+        config.game_creation_rate_limit.burst = 2;
+        config.game_creation_rate_limit.refill_interval_seconds = 60;
+    })
+    .await;
+
+    assert!(app
+        .send_create_game_request(None)
+        .await
+        .status()
+        .is_success());
+    assert!(app
+        .send_create_game_request(None)
+        .await
+        .status()
+        .is_success());
+    assert_eq!(
+        app.send_create_game_request(None).await.status(),
+        reqwest::StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[tokio::test]
+async fn game_creation_is_throttled_globally() {
+    let app = TestApp::spawn_app_with_config(|config| {
+        config.game.max_creates_per_second = Some(2);
+    })
+    .await;
+
+    let statuses =
+        futures_util::future::join_all((0..4).map(|_| app.send_create_game_request(None)))
+            .await
+            .into_iter()
+            .map(|response| response.status())
+            .collect::<Vec<_>>();
+
+    assert_eq!(
+        statuses.iter().filter(|status| status.is_success()).count(),
+        2
+    );
+    assert_eq!(
+        statuses
+            .iter()
+            .filter(|status| **status == reqwest::StatusCode::SERVICE_UNAVAILABLE)
+            .count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn game_can_be_created_with_custom_words() {
+    let mut game = TestApp::spawn_app()
+        .await
+        .new_game_with_request(serde_json::json!({ "words": ["giraffe"] }))
+        .await;
+    for nickname in ["p1", "p2", "p3"] {
+        let _ = game.add_player(nickname).await.unwrap();
+    }
+
+    let state = game.players[0].start_game(1).await.unwrap();
+
+    assert_eq!(state.rounds[0].word, "giraffe");
+}
+
+#[tokio::test]
+async fn game_title_defaults_to_the_game_id() {
+    let mut game = TestApp::create_game_without_players().await;
+
+    let state = game.add_player("p1").await.unwrap();
+
+    assert_eq!(state.title, game.id);
+}
+
+#[tokio::test]
+async fn game_can_be_created_with_a_title() {
+    let mut game = TestApp::spawn_app()
+        .await
+        .new_game_with_request(serde_json::json!({ "title": "Friday night" }))
+        .await;
+
+    let state = game.add_player("p1").await.unwrap();
+
+    assert_eq!(state.title, "Friday night");
+}
+
+#[tokio::test]
+async fn game_cannot_be_created_with_a_too_long_title() {
+    let app = TestApp::spawn_app().await;
+
+    let response = app
+        .send_create_game_request(Some(serde_json::json!({ "title": "a".repeat(51) })))
+        .await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn game_cannot_be_created_with_an_invalid_request() {
+    let app = TestApp::spawn_app().await;
+
+    let response = app
+        .send_create_game_request(Some(serde_json::json!({ "title": 5 })))
+        .await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn host_player_can_set_the_title() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let state = game.players[0].set_title("Friday night").await.unwrap();
+    assert_eq!(state.title, "Friday night");
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let result = game.players[1].set_title("Saturday night").await;
+    assert_eq!(result, Err("NON_HOST_PLAYER_CANNOT_SET_TITLE".to_string()));
+}
+
+#[tokio::test]
+async fn commands_with_a_request_id_are_acknowledged() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    game.players[0]
+        .send_message(Message::Text(
+            r#"{"kind":"setTitle","title":"Friday night","requestId":"r1"}"#.to_string(),
+        ))
+        .await;
+    let ack = game.players[0].receive_message().await;
+    assert_eq!(
+        ack,
+        Ok(WsMessageIn::Ack {
+            request_id: "r1".to_string(),
+            ok: true
+        })
+    );
+    let state = game.players[0].receive_game_state().await.unwrap();
+    assert_eq!(state.title, "Friday night");
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    game.players[1]
+        .send_message(Message::Text(
+            r#"{"kind":"setTitle","title":"Saturday night","requestId":"r2"}"#.to_string(),
+        ))
+        .await;
+    let error = game.players[1].receive_message().await;
+    assert_eq!(error, Err("NON_HOST_PLAYER_CANNOT_SET_TITLE".to_string()));
+    let ack = game.players[1].receive_message().await;
+    assert_eq!(
+        ack,
+        Ok(WsMessageIn::Ack {
+            request_id: "r2".to_string(),
+            ok: false
+        })
+    );
+}
+
 #[tokio::test]
 async fn when_player_already_exists_add_player_with_same_nickname_to_game_fails() {
     let mut game = TestApp::create_game(GameFsmState::Lobby).await;
@@ -43,6 +207,17 @@ async fn non_host_player_cannot_start_game() {
     assert_eq!(result, Err("NON_HOST_PLAYER_CANNOT_START_GAME".to_string()));
 }
 
+#[tokio::test]
+async fn game_cannot_be_started_twice() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+
+    let result = game.players[0].start_game(3).await;
+
+    assert_eq!(result, Err("GAME_ALREADY_STARTED".to_string()));
+    let state = game.players[0].receive_game_state().await.unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingWords);
+}
+
 #[tokio::test]
 async fn game_cannot_be_started_with_less_than_three_players() {
     let mut game = TestApp::create_game_without_players().await;
@@ -70,6 +245,69 @@ async fn game_is_started_with_the_right_settings() {
     assert_eq!(state.amount_of_rounds, Some(3));
 }
 
+#[tokio::test]
+async fn round_start_message_is_broadcast_when_game_starts() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.round_start_message_template = Some("Round {round}: {word}".to_string())
+    })
+    .await;
+
+    let _ = game.players[0]
+        .start_game(TestGame::AMOUNT_OF_ROUNDS)
+        .await
+        .unwrap();
+    let (events, state) = game.players[1]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![WsMessageIn::SystemMessage {
+            text: format!("Round 1: {}", state.last_round().word)
+        }]
+    );
+}
+
+#[tokio::test]
+async fn word_is_revealed_in_stages_while_players_submit_words() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.word_reveal_stages = 2;
+        config.game.word_reveal_interval_seconds = 1;
+    })
+    .await;
+
+    let state = game.players[0]
+        .start_game(TestGame::AMOUNT_OF_ROUNDS)
+        .await
+        .unwrap();
+    let masked = state.last_round().word;
+    assert!(masked.chars().all(|letter| letter == '_'));
+
+    let first_stage = game.players[0].receive_message().await.unwrap();
+    let second_stage = game.players[0].receive_message().await.unwrap();
+    let WsMessageIn::WordReveal { masked: word } = second_stage else {
+        panic!("Expected a WordReveal message. Message: '{second_stage:?}'.");
+    };
+    assert_eq!(word.chars().count(), masked.chars().count());
+    assert!(!word.contains('_'));
+    let revealed_letters = word.chars().count() / 2;
+    assert_eq!(
+        first_stage,
+        WsMessageIn::WordReveal {
+            masked: word
+                .chars()
+                .enumerate()
+                .map(|(index, letter)| if index < revealed_letters {
+                    letter
+                } else {
+                    '_'
+                })
+                .collect()
+        }
+    );
+}
+
 #[tokio::test]
 async fn game_cannot_be_started_with_less_than_1_round() {
     let mut game = TestApp::create_game(GameFsmState::Lobby).await;
@@ -88,17 +326,27 @@ async fn game_is_still_alive_when_all_players_leave() {
     // Drop the players reference, so the players websockets get closed and the server disconnects the player from the game
     drop(game.players);
     game.players = vec![];
+    game.wait_until_all_players_are_disconnected().await;
 
     let state = game.add_player("p4").await.unwrap();
 
     assert_eq!(state.state, GameFsmState::Lobby);
     assert_eq!(state.players.len(), 4);
-    assert!(!state.players.get(0).unwrap().is_connected);
-    assert!(!state.players.get(0).unwrap().is_host);
+    assert!(!state.players.first().unwrap().is_connected);
+    assert!(!state.players.first().unwrap().is_host);
     assert!(state.players.get(3).unwrap().is_connected);
     assert!(state.players.get(3).unwrap().is_host);
 }
 
+#[tokio::test]
+async fn player_with_a_too_long_nickname_cannot_join() {
+    let mut game = TestApp::create_game_without_players().await;
+
+    let result = game.add_player(&"a".repeat(21)).await;
+
+    assert_eq!(result, Err("INVALID_NICKNAME".to_string()));
+}
+
 #[tokio::test]
 async fn game_is_closed_after_inactivity_timeout() {
     let mut game = TestApp::create_game(GameFsmState::Lobby).await;
@@ -114,6 +362,248 @@ async fn game_is_closed_after_inactivity_timeout() {
     assert_eq!(result, Err("GAME_DOES_NOT_EXIST".to_string()));
 }
 
+#[tokio::test]
+async fn empty_game_past_the_lobby_is_kept_for_the_mid_game_grace() {
+    let configure = |config: &mut Config| {
+        config.game.mid_game_empty_grace_seconds = Some(5);
+    };
+    let mut lobby_game = TestApp::create_game_with_config(GameFsmState::Lobby, configure).await;
+    let mut started_game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingWords, configure).await;
+
+    drop(lobby_game.players);
+    lobby_game.players = vec![];
+    let reconnection_token = started_game.players[0].reconnection_token.clone().unwrap();
+    drop(started_game.players);
+    started_game.players = vec![];
+    // Longer than the inactivity timeout, shorter than the mid game grace
+    sleep(lobby_game.app.inactivity_timeout + Duration::from_secs(1)).await;
+
+    let result = lobby_game.add_player("p1").await;
+    assert_eq!(result, Err("GAME_DOES_NOT_EXIST".to_string()));
+    let state = started_game
+        .rejoin_player("p1", &reconnection_token)
+        .await
+        .unwrap();
+    assert_eq!(state.players.len(), 3);
+    assert!(state.players[0].is_connected);
+}
+
+#[tokio::test]
+async fn empty_game_freezes_the_word_submission_deadline_until_a_player_reconnects() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingWords, |config| {
+            config.game.mid_game_empty_grace_seconds = Some(10);
+            config.game.word_submission_seconds = Some(2);
+            config.game.pause_when_empty = true;
+        })
+        .await;
+
+    let reconnection_token = game.players[0].reconnection_token.clone().unwrap();
+    drop(game.players);
+    game.players = vec![];
+    // Longer than the deadline, it would have ended the word submission if it wasn't frozen
+    sleep(Duration::from_secs(3)).await;
+
+    let state = game.rejoin_player("p1", &reconnection_token).await.unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingWords);
+
+    // The rest of the deadline runs once p1 is back, nobody has words so there is nothing to vote
+    let state = game.players[0].receive_game_state().await.unwrap();
+    assert_eq!(state.state, GameFsmState::EndOfRound);
+    assert!(state.last_round().player_words["p1"].is_empty());
+}
+
+#[tokio::test]
+async fn player_can_get_the_players() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+    game.players.remove(2);
+    let state = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let players = game.players[1].get_players().await.unwrap();
+
+    assert_eq!(players, state.players);
+    assert_eq!(players.len(), 3);
+    assert!(players[0].is_host);
+    assert!(players[1].is_connected);
+    assert!(!players[2].is_connected);
+}
+
+#[tokio::test]
+async fn game_is_recently_closed_after_inactivity_timeout() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.closed_game_retention_seconds = 60;
+    })
+    .await;
+
+    drop(game.players);
+    game.players = vec![];
+    sleep(game.app.inactivity_timeout + Duration::from_secs(1)).await;
+
+    let result = game.add_player("p4").await;
+    assert_eq!(result, Err("GAME_RECENTLY_CLOSED".to_string()));
+
+    game.id = "never-existed".to_string();
+    let result = game.add_player("p4").await;
+    assert_eq!(result, Err("GAME_DOES_NOT_EXIST".to_string()));
+}
+
+#[tokio::test]
+async fn chat_messages_are_broadcast_unless_too_long() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.max_chat_length = 5;
+    })
+    .await;
+
+    game.players[0].send_chat_message("hello!").await;
+    assert_eq!(
+        game.players[0].receive_message().await,
+        Err("CHAT_MESSAGE_TOO_LONG".to_string())
+    );
+
+    game.players[0].send_chat_message("hello").await;
+    assert_eq!(
+        game.players[1].receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "hello".to_string()
+        })
+    );
+}
+
+#[tokio::test]
+async fn whitespace_only_chat_messages_are_dropped() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    game.players[0].send_chat_message("   ").await;
+    game.players[0].send_chat_message(" hi ").await;
+
+    assert_eq!(
+        game.players[1].receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "hi".to_string()
+        })
+    );
+}
+
+#[tokio::test]
+async fn typing_is_broadcast_to_everyone_but_the_typing_player() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    game.players[0].start_typing().await;
+
+    for player in &mut game.players[1..] {
+        assert_eq!(
+            player.receive_message().await,
+            Ok(WsMessageIn::PlayerTyping {
+                nickname: "p1".to_string(),
+                is_typing: true
+            })
+        );
+    }
+    game.players[0].stop_typing().await;
+    game.players[0].send_chat_message("hi").await;
+    assert_eq!(
+        game.players[0].receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "hi".to_string()
+        })
+    );
+    assert_eq!(
+        game.players[1].receive_message().await,
+        Ok(WsMessageIn::PlayerTyping {
+            nickname: "p1".to_string(),
+            is_typing: false
+        })
+    );
+}
+
+#[tokio::test]
+async fn disconnecting_player_stops_typing() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+    game.players[2].start_typing().await;
+    assert_eq!(
+        game.players[0].receive_message().await,
+        Ok(WsMessageIn::PlayerTyping {
+            nickname: "p3".to_string(),
+            is_typing: true
+        })
+    );
+
+    drop(game.players.pop());
+
+    assert_eq!(
+        game.players[0].receive_message().await,
+        Ok(WsMessageIn::PlayerTyping {
+            nickname: "p3".to_string(),
+            is_typing: false
+        })
+    );
+}
+
+#[tokio::test]
+async fn chat_subscriber_receives_chat_but_not_state() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+    let mut chat_subscriber = game.add_chat_subscriber("p4").await.unwrap();
+
+    game.players[0].send_chat_message("hi").await;
+    let state = game.players[0].set_title("Friday night").await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    game.players[0].send_chat_message("bye").await;
+
+    assert!(state.players[3].is_connected);
+
+    assert_eq!(
+        chat_subscriber.receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "hi".to_string()
+        })
+    );
+    assert_eq!(
+        chat_subscriber.receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "bye".to_string()
+        })
+    );
+}
+
+#[tokio::test]
+async fn late_joiner_receives_the_latest_chat_messages() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.chat_history_size = 1;
+    })
+    .await;
+    game.players[0].send_chat_message("hi").await;
+    game.players[0].send_chat_message("bye").await;
+    // Make sure the messages reached the game before the late joiner does
+    let _ = game.players[1].receive_message().await.unwrap();
+    let _ = game.players[1].receive_message().await.unwrap();
+
+    let mut chat_subscriber = game.add_chat_subscriber("p4").await.unwrap();
+    game.players[0].send_chat_message("welcome").await;
+
+    assert_eq!(
+        chat_subscriber.receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "bye".to_string()
+        })
+    );
+    assert_eq!(
+        chat_subscriber.receive_message().await,
+        Ok(WsMessageIn::ChatMessage {
+            sender: "p1".to_string(),
+            content: "welcome".to_string()
+        })
+    );
+}
+
 #[tokio::test]
 async fn unknown_websocket_text_message_is_rejected_but_game_still_alive() {
     let mut game = TestApp::create_game(GameFsmState::Lobby).await;
@@ -127,6 +617,24 @@ async fn unknown_websocket_text_message_is_rejected_but_game_still_alive() {
     assert!(state.is_ok());
 }
 
+#[tokio::test]
+async fn websocket_message_of_unknown_kind_is_rejected_but_game_still_alive() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let result = game.players[0]
+        .send_raw_message(Message::Text(r#"{"kind":"DanceParty"}"#.to_string()))
+        .await;
+    assert_eq!(result, Err("UNKNOWN_MESSAGE_KIND".to_string()));
+
+    let result = game.players[0]
+        .send_raw_message(Message::Text(r#"{"kind":"startGame"}"#.to_string()))
+        .await;
+    assert_eq!(result, Err("UNPROCESSABLE_WEBSOCKET_MESSAGE".to_string()));
+
+    let state = game.add_player("p4").await;
+    assert!(state.is_ok());
+}
+
 #[tokio::test]
 async fn when_sending_invalid_message_game_it_is_reject_but_game_is_still_alive() {
     let mut game = TestApp::create_game(GameFsmState::Lobby).await;
@@ -143,12 +651,62 @@ async fn when_sending_invalid_message_game_it_is_reject_but_game_is_still_alive(
 #[tokio::test]
 async fn repeated_words_are_not_allowed() {
     let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let errors = ERRORS.with_label_values(&["REPEATED_WORDS"]).get();
 
     let result = game.players[0]
         .send_custom_words(vec!["w1".to_string(), "w1".to_string()])
         .await;
 
     assert_eq!(result, Err("REPEATED_WORDS".to_string()));
+    assert!(ERRORS.with_label_values(&["REPEATED_WORDS"]).get() > errors);
+}
+
+#[tokio::test]
+async fn too_long_words_are_rejected_with_the_word_in_the_detail() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let word = "a".repeat(31);
+
+    game.players[0]
+        .send_message(Message::Text(
+            serde_json::json!({ "kind": "playerWords", "words": [word] }).to_string(),
+        ))
+        .await;
+
+    let Some(Ok(message)) = game.players[0].rx.next().await else {
+        panic!("Expected an error message.");
+    };
+    let error: serde_json::Value = serde_json::from_str(message.to_text().unwrap()).unwrap();
+    assert_eq!(error["type"], "WORD_TOO_LONG");
+    assert_eq!(error["detail"], word);
+}
+
+#[tokio::test]
+async fn submitting_player_is_told_which_words_were_accepted() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+
+    game.players[0]
+        .send_message(Message::Text(
+            serde_json::json!({ "kind": "playerWords", "words": [" w1 ", "", "w2", "  "] })
+                .to_string(),
+        ))
+        .await;
+
+    let (events, _) = game.players[0]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![WsMessageIn::WordsAccepted {
+            accepted: vec!["w1".to_string(), "w2".to_string()],
+            dropped: 2,
+        }]
+    );
+    let (events, _) = game.players[1]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+    assert!(events.is_empty());
 }
 
 #[tokio::test]
@@ -189,7 +747,7 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(words.len(), 3);
     let p1_words = words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 0);
@@ -211,11 +769,11 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(player_words.len(), 3);
     let p1_words = player_words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = player_words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 2);
-    assert_eq!(p2_words.get(0).unwrap().word, "p2_w1");
+    assert_eq!(p2_words.first().unwrap().word, "p2_w1");
     assert_eq!(p2_words.get(1).unwrap().word, "p2_w2");
     let p3_words = player_words.get(&game.players[2].nickname).unwrap();
     assert_eq!(p3_words.len(), 0);
@@ -240,13 +798,13 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(player_words.len(), 3);
     let p1_words = player_words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = player_words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 0);
     let p3_words = player_words.get(&game.players[2].nickname).unwrap();
     assert_eq!(p3_words.len(), 2);
-    assert_eq!(p3_words.get(0).unwrap().word, "p3_w1");
+    assert_eq!(p3_words.first().unwrap().word, "p3_w1");
     assert_eq!(p3_words.get(1).unwrap().word, "p3_w2");
     // Voting words
     let voting_words = state.last_round().player_voting_words;
@@ -270,11 +828,11 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(player_words.len(), 3);
     let p1_words = player_words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = player_words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 2);
-    assert_eq!(p2_words.get(0).unwrap().word, "p2_w1");
+    assert_eq!(p2_words.first().unwrap().word, "p2_w1");
     assert_eq!(p2_words.get(1).unwrap().word, "p2_w2");
     let p3_words = player_words.get(&game.players[2].nickname).unwrap();
     assert_eq!(p3_words.len(), 0);
@@ -296,14 +854,14 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(words.len(), 3);
     let p1_words = words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 1);
-    assert_eq!(p2_words.get(0).unwrap().word, "p2_w1");
+    assert_eq!(p2_words.first().unwrap().word, "p2_w1");
     let p3_words = words.get(&game.players[2].nickname).unwrap();
     assert_eq!(p3_words.len(), 1);
-    assert_eq!(p3_words.get(0).unwrap().word, "p3_w2");
+    assert_eq!(p3_words.first().unwrap().word, "p3_w2");
     // Voting words
     let voting_words = state.last_round().player_voting_words;
     assert_eq!(voting_words.len(), 1);
@@ -319,15 +877,15 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(words.len(), 3);
     let p1_words = words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 2);
-    assert_eq!(p2_words.get(0).unwrap().word, "p2_w1");
+    assert_eq!(p2_words.first().unwrap().word, "p2_w1");
     assert_eq!(p2_words.get(1).unwrap().word, "p2_w2");
     let p3_words = words.get(&game.players[2].nickname).unwrap();
     assert_eq!(p3_words.len(), 1);
-    assert_eq!(p3_words.get(0).unwrap().word, "p3_w2");
+    assert_eq!(p3_words.first().unwrap().word, "p3_w2");
     // Voting words
     let voting_words = state.last_round().player_voting_words;
     assert_eq!(voting_words.len(), 1);
@@ -343,14 +901,14 @@ async fn player_visibility_of_other_players_words_is_correct() {
     assert_eq!(words.len(), 3);
     let p1_words = words.get(&game.players[0].nickname).unwrap();
     assert_eq!(p1_words.len(), 2);
-    assert_eq!(p1_words.get(0).unwrap().word, "p1_w1");
+    assert_eq!(p1_words.first().unwrap().word, "p1_w1");
     assert_eq!(p1_words.get(1).unwrap().word, "p1_w2");
     let p2_words = words.get(&game.players[1].nickname).unwrap();
     assert_eq!(p2_words.len(), 1);
-    assert_eq!(p2_words.get(0).unwrap().word, "p2_w1");
+    assert_eq!(p2_words.first().unwrap().word, "p2_w1");
     let p3_words = words.get(&game.players[2].nickname).unwrap();
     assert_eq!(p3_words.len(), 2);
-    assert_eq!(p3_words.get(0).unwrap().word, "p3_w1");
+    assert_eq!(p3_words.first().unwrap().word, "p3_w1");
     assert_eq!(p3_words.get(1).unwrap().word, "p3_w2");
     // Voting words
     let voting_words = state.last_round().player_voting_words;
@@ -362,18 +920,271 @@ async fn player_visibility_of_other_players_words_is_correct() {
 }
 
 #[tokio::test]
-async fn players_can_complete_a_round() {
-    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+async fn first_voting_item_is_shown_after_the_voting_pause() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingWords, |config| {
+            config.game.voting_pause_seconds = 1;
+        })
+        .await;
 
-    game.complete_round().await;
+    let state = game.players_send_words().await;
+    let paused_at = time::Instant::now();
+
+    assert_eq!(state.state, GameFsmState::PreparingVoting);
+    assert_eq!(state.last_round().voting_item, None);
+    let (events, state) = game.players[0]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![WsMessageIn::PreparingVoting {
+            seconds_remaining: 1
+        }]
+    );
+    assert!(paused_at.elapsed() >= Duration::from_millis(900));
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    assert_eq!(state.last_round().voting_item.unwrap().word, "p1_w1");
 }
 
 #[tokio::test]
-async fn players_can_complete_a_game() {
-    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
-    let mut state = GameFsmState::PlayersSubmittingWords;
+async fn player_clears_the_voting_word_to_be_undecided_again() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+    let voting_word = game.players[1].words[0].clone();
+    let _ = game.players[1]
+        .send_voting_word(Some(voting_word.clone()))
+        .await
+        .unwrap();
+    let state = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    assert!(state.last_round().player_voting_words.contains_key("p2"));
 
-    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+    let _ = game.players[1].clear_voting_word().await.unwrap();
+    let state = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    assert!(!state.last_round().player_voting_words.contains_key("p2"));
+
+    let state = game.players[1]
+        .send_voting_word(Some(voting_word.clone()))
+        .await
+        .unwrap();
+    assert_eq!(
+        state.last_round().player_voting_words.get("p2"),
+        Some(&Some(voting_word))
+    );
+}
+
+#[tokio::test]
+async fn scored_words_are_broadcast_when_voting_words_are_accepted() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+    let voting_word = game.players[1].words[0].clone();
+    let _ = game.players[1]
+        .send_voting_word(Some(voting_word.clone()))
+        .await
+        .unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    let _ = game.players[0].accept_players_voting_words().await.unwrap();
+    let (events, state) = game.players[2]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            WsMessageIn::WordScored {
+                owner: "p1".to_string(),
+                word: game.players[0].words[0].clone(),
+                score: 2
+            },
+            WsMessageIn::WordScored {
+                owner: "p2".to_string(),
+                word: voting_word,
+                score: 2
+            },
+            WsMessageIn::VotingItemResolved {
+                player_nickname: "p1".to_string(),
+                word: game.players[0].words[0].clone(),
+                score: 2,
+                matched_players: vec!["p2".to_string()]
+            }
+        ]
+    );
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+}
+
+#[tokio::test]
+async fn scored_words_are_not_broadcast_when_score_events_are_state_only() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.score_event_verbosity = ScoreEventVerbosity::StateOnly;
+    })
+    .await;
+    let _ = game.players[0]
+        .start_game(TestGame::AMOUNT_OF_ROUNDS)
+        .await
+        .unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    let _ = game.players_send_words().await;
+    let voting_word = game.players[1].words[0].clone();
+    let _ = game.players[1]
+        .send_voting_word(Some(voting_word.clone()))
+        .await
+        .unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    let _ = game.players[0].accept_players_voting_words().await.unwrap();
+    let (events, state) = game.players[1]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+
+    assert!(events.is_empty());
+    let scored_word = state.last_round().player_words["p2"]
+        .iter()
+        .find(|word| word.word == voting_word)
+        .cloned()
+        .unwrap();
+    assert_eq!(scored_word.score, 2);
+}
+
+#[tokio::test]
+async fn players_can_complete_a_round() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+
+    let state = game
+        .run_scripted(&[
+            Action::SubmitWords("p1"),
+            Action::SubmitWords("p2"),
+            Action::SubmitWords("p3"),
+            // Voting for p1_w1
+            Action::Vote("p2", Some("p2_w1")),
+            Action::Accept("p1"),
+            // Voting for p1_w2
+            Action::Vote("p2", None),
+            Action::Vote("p3", Some("p3_w2")),
+            Action::Accept("p1"),
+            // Voting for p2_w2, then p3_w1
+            Action::Accept("p1"),
+            Action::Accept("p1"),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(state.state, GameFsmState::EndOfRound);
+}
+
+#[tokio::test]
+async fn players_can_play_a_scripted_game_from_the_lobby() {
+    let mut game = TestApp::create_game_without_players().await;
+
+    let state = game
+        .run_scripted(&[
+            Action::Join("p1"),
+            Action::Join("p2"),
+            Action::Join("p3"),
+            Action::StartGame("p1", 1),
+            Action::SubmitWords("p1"),
+            Action::SubmitWords("p2"),
+            Action::SubmitWords("p3"),
+            Action::Accept("p1"),
+            Action::Accept("p1"),
+            Action::Accept("p1"),
+            Action::Accept("p1"),
+            Action::Accept("p1"),
+            Action::Accept("p1"),
+            Action::ContinueToNextRound("p1"),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(state.state, GameFsmState::EndOfGame);
+    assert_eq!(state.rounds.len(), 1);
+}
+
+#[tokio::test]
+async fn player_can_get_their_last_words_after_a_round() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    assert_eq!(game.players[1].get_last_words().await, Ok(vec![]));
+
+    game.complete_round().await;
+
+    let mut last_words = game.players[1].get_last_words().await.unwrap();
+    last_words.sort();
+    assert_eq!(last_words, game.players[1].words);
+}
+
+#[tokio::test]
+async fn remaining_voting_items_shrink_as_words_are_voted() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+
+    let items = game.players[1].get_remaining_voting_items().await.unwrap();
+    assert_eq!(items.len(), 6);
+    assert_eq!(items[0].player_nickname, "p1");
+    assert_eq!(items[0].word, Some("p1_w1".to_string()));
+    assert!(items
+        .iter()
+        .filter(|item| item.player_nickname == "p3")
+        .all(|item| item.word.is_none()));
+
+    let voting_word = game.players[1].words[0].clone();
+    let _ = game.players[1]
+        .send_voting_word(Some(voting_word))
+        .await
+        .unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    let _ = game.players[0].accept_players_voting_words().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    let items = game.players[1].get_remaining_voting_items().await.unwrap();
+    assert_eq!(items.len(), 4);
+    assert_eq!(items[0].word, Some("p1_w2".to_string()));
+}
+
+#[tokio::test]
+async fn next_voting_player_is_told_they_are_next() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingVotingWord, |config| {
+            config.game.announce_next_voting_player = true;
+        })
+        .await;
+
+    // p1 is being voted, p2 goes next
+    assert_eq!(
+        game.players[1].receive_message().await,
+        Ok(WsMessageIn::YouAreNext)
+    );
+
+    // p1_w2 is being voted, p2 still goes next
+    let _ = game.players[0].accept_players_voting_words().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    // p2 is being voted, p3 goes next
+    let _ = game.players[0].accept_players_voting_words().await.unwrap();
+    let (events, _) = game.players[1]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+    assert!(!events.contains(&WsMessageIn::YouAreNext));
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    assert_eq!(
+        game.players[2].receive_message().await,
+        Ok(WsMessageIn::YouAreNext)
+    );
+}
+
+#[tokio::test]
+async fn players_can_complete_a_game() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let mut state = GameFsmState::PlayersSubmittingWords;
+
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
         game.complete_round().await;
         state = game.continue_to_next_round().await.state;
     }
@@ -381,6 +1192,718 @@ async fn players_can_complete_a_game() {
     assert_eq!(state, GameFsmState::EndOfGame);
 }
 
+#[tokio::test]
+async fn final_scoreboard_is_broadcast_when_the_game_ends() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    for _ in 1..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        let _ = game.continue_to_next_round().await;
+    }
+    game.complete_round().await;
+
+    let (events, state) = game.players[0]
+        .continue_to_next_round_and_receive_events()
+        .await
+        .unwrap();
+
+    assert_eq!(state.state, GameFsmState::EndOfGame);
+    let rankings: Vec<&Vec<(String, isize)>> = events
+        .iter()
+        .filter_map(|event| match event {
+            WsMessageIn::FinalScoreboard { ranking } => Some(ranking),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(rankings.len(), 1);
+    let scores: Vec<isize> = rankings[0].iter().map(|(_, score)| *score).collect();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert_eq!(
+        state.player_total_scores,
+        rankings[0].iter().cloned().collect()
+    );
+}
+
+#[tokio::test]
+async fn score_breakdown_adds_up_to_the_total_scores() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let mut state = None;
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        state = Some(game.continue_to_next_round().await);
+    }
+    let state = state.unwrap();
+    assert_eq!(state.state, GameFsmState::EndOfGame);
+
+    let breakdown = game.players[1].get_score_breakdown().await.unwrap();
+
+    assert_eq!(breakdown.len(), state.player_total_scores.len());
+    for (nickname, total_score) in &state.player_total_scores {
+        let entries = &breakdown[nickname];
+        assert_eq!(
+            entries.iter().map(|entry| entry.score).sum::<isize>(),
+            *total_score
+        );
+        assert!(entries
+            .iter()
+            .all(|entry| entry.round >= 1 && entry.round <= TestGame::AMOUNT_OF_ROUNDS as usize));
+    }
+}
+
+#[tokio::test]
+async fn game_state_only_includes_the_configured_trailing_rounds() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingWords, |config| {
+            config.game.max_rounds_in_state = Some(1);
+        })
+        .await;
+    let mut state = None;
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        state = Some(game.continue_to_next_round().await);
+    }
+    let state = state.unwrap();
+    assert_eq!(state.state, GameFsmState::EndOfGame);
+    assert_eq!(state.rounds.len(), 1);
+
+    let (history, total) = game.players[1].get_round_history(0, None).await.unwrap();
+    let (second_page, _) = game.players[1].get_round_history(1, Some(1)).await.unwrap();
+
+    assert_eq!(total, TestGame::AMOUNT_OF_ROUNDS as usize - 1);
+    assert_eq!(
+        history.iter().map(|round| round.round).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert!(history
+        .iter()
+        .all(|round| round.player_words.len() == game.players.len()));
+    assert_eq!(second_page, history[1..]);
+    assert_eq!(state.rounds[0].round, TestGame::AMOUNT_OF_ROUNDS as usize);
+}
+
+#[tokio::test]
+async fn submission_progress_is_shown_without_telling_who_submitted() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingWords, |config| {
+            config.game.show_submission_progress = true;
+        })
+        .await;
+
+    let _ = game.players[0].send_words().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let state = game.players[2].receive_game_state().await.unwrap();
+
+    let round = state.last_round();
+    assert_eq!(round.submitted_count, Some(1));
+    assert_eq!(round.expected_count, Some(3));
+    assert!(round.player_words.is_empty());
+
+    let state = game.players[1].send_words().await.unwrap();
+
+    let round = state.last_round();
+    assert_eq!(round.submitted_count, Some(2));
+    assert_eq!(round.expected_count, Some(3));
+    assert_eq!(
+        round.player_words.keys().collect::<Vec<&String>>(),
+        vec!["p2"]
+    );
+}
+
+#[tokio::test]
+async fn inactive_player_is_kicked_after_the_configured_missed_rounds() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingWords, |config| {
+            config.game.kick_after_missed_rounds = Some(1);
+        })
+        .await;
+    // Closing p3's websocket disconnects it from the game
+    drop(game.players.pop());
+    let state = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    assert!(!state.players[2].is_connected);
+
+    let _ = game.players[0].send_words().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let state = game.players[1].send_words().await.unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    while game.players[0]
+        .accept_players_voting_words()
+        .await
+        .unwrap()
+        .state
+        == GameFsmState::PlayersSubmittingVotingWord
+    {
+        let _ = game.players[1].receive_game_state().await.unwrap();
+    }
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let (events, state) = game.players[0]
+        .continue_to_next_round_and_receive_events()
+        .await
+        .unwrap();
+
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingWords);
+    assert_eq!(state.players.len(), 2);
+    assert!(events.contains(&WsMessageIn::PlayerLeft {
+        nickname: "p3".to_string(),
+        reason: "inactive".to_string(),
+    }));
+    let state = game.players[1].receive_game_state().await.unwrap();
+    assert_eq!(state.players.len(), 2);
+}
+
+#[tokio::test]
+async fn public_state_hides_the_unused_words_of_players_not_being_voted() {
+    let game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+
+    let response = game.app.send_get_public_state_request(&game.id).await;
+
+    assert!(response.status().is_success());
+    let Ok(WsMessageIn::GameState { state, rounds, .. }) = response.json().await else {
+        panic!("Failed to parse the public GameState.");
+    };
+    assert_eq!(state, GameFsmState::PlayersSubmittingVotingWord);
+    let round = rounds.last().unwrap();
+    let voting_player = &round.voting_item.as_ref().unwrap().player_nickname;
+    for (nickname, words) in &round.player_words {
+        if nickname == voting_player {
+            assert!(!words.is_empty());
+        } else {
+            assert!(words.is_empty());
+        }
+    }
+}
+
+#[tokio::test]
+async fn public_state_of_a_missing_game_is_not_found() {
+    let app = TestApp::spawn_app().await;
+
+    let response = app.send_get_public_state_request("missing").await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn disconnected_player_rejoins_with_a_ticket() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let reconnection_token = game.players[2].reconnection_token.clone().unwrap();
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let state = game.rejoin_player("p3", &reconnection_token).await.unwrap();
+
+    assert!(state.players[2].is_connected);
+}
+
+#[tokio::test]
+async fn disconnected_player_reconnects_with_a_token_under_another_nickname() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let reconnection_token = game.players[2].reconnection_token.clone().unwrap();
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let state = game
+        .add_player_with_query(
+            "renamed",
+            &format!("?reconnection_token={reconnection_token}"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(state.players.len(), 3);
+    assert_eq!(state.players[2].nickname, "p3");
+    assert!(state.players[2].is_connected);
+    assert_eq!(game.players[2].reconnection_token, Some(reconnection_token));
+}
+
+#[tokio::test]
+async fn reconnecting_player_receives_a_snapshot_of_the_game() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+    game.players[0].send_chat_message("hi").await;
+    let _ = game.players[1].receive_message().await.unwrap();
+    let reconnection_token = game.players[2].reconnection_token.clone().unwrap();
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let (events, _) = game
+        .rejoin_player_and_receive_events("p3", &reconnection_token)
+        .await
+        .unwrap();
+
+    let Some(WsMessageIn::ReconnectSnapshot {
+        welcome,
+        state,
+        chat_history,
+        my_words,
+    }) = events
+        .into_iter()
+        .find(|event| matches!(event, WsMessageIn::ReconnectSnapshot { .. }))
+    else {
+        panic!("Expected a ReconnectSnapshot message.");
+    };
+    assert_eq!(welcome.nickname, "p3");
+    assert!(welcome.is_connected);
+    let WsMessageIn::GameState { state, players, .. } = *state else {
+        panic!("Expected the snapshot to contain a GameState message.");
+    };
+    assert_eq!(state, GameFsmState::PlayersSubmittingVotingWord);
+    assert!(players[2].is_connected);
+    assert_eq!(
+        chat_history,
+        vec![ChatHistoryMessage {
+            sender: "p1".to_string(),
+            content: "hi".to_string()
+        }]
+    );
+    assert_eq!(
+        my_words
+            .into_iter()
+            .map(|word| word.word)
+            .collect::<Vec<String>>(),
+        game.players[2].words
+    );
+}
+
+#[tokio::test]
+async fn websocket_with_a_stale_reconnection_token_is_rejected() {
+    let game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+
+    let (_, mut rx) = game
+        .app
+        .open_game_websocket_with_query(&game.id, "p4", "?reconnection_token=stale")
+        .await
+        .unwrap()
+        .split();
+
+    let Some(Ok(message)) = rx.next().await else {
+        panic!("Expected an error message.");
+    };
+    assert!(message
+        .to_text()
+        .unwrap()
+        .contains("INVALID_RECONNECTION_TOKEN"));
+}
+
+#[tokio::test]
+async fn stranger_cannot_rejoin_a_game() {
+    let game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+
+    let reconnection_token = game.players[0].reconnection_token.clone().unwrap();
+
+    let response = game
+        .app
+        .send_rejoin_request(&game.id, "stranger", &reconnection_token)
+        .await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+    let response = game
+        .app
+        .send_rejoin_request("missing", "p1", &reconnection_token)
+        .await;
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn disconnected_player_cannot_rejoin_without_a_ticket() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let (_, mut rx) = game
+        .app
+        .open_game_websocket_with_query(&game.id, "p3", "")
+        .await
+        .unwrap()
+        .split();
+
+    let Some(Ok(message)) = rx.next().await else {
+        panic!("Expected an error message.");
+    };
+    assert!(message.to_text().unwrap().contains("INVALID_REJOIN_TICKET"));
+}
+
+#[tokio::test]
+async fn disconnected_player_cannot_be_rejoined_without_its_reconnection_token() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let other_reconnection_token = game.players[0].reconnection_token.clone().unwrap();
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let response = game
+        .app
+        .send_rejoin_request(&game.id, "p3", &other_reconnection_token)
+        .await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn websocket_with_an_invalid_ticket_is_rejected() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let (_, mut rx) = game
+        .app
+        .open_game_websocket_with_query(&game.id, "p3", "?ticket=invalid")
+        .await
+        .unwrap()
+        .split();
+
+    let Some(Ok(message)) = rx.next().await else {
+        panic!("Expected an error message.");
+    };
+    assert!(message.to_text().unwrap().contains("INVALID_REJOIN_TICKET"));
+}
+
+#[tokio::test]
+async fn errors_have_localized_titles_for_the_language_of_the_connection() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+    let _ = game.add_player_with_query("p4", "?lang=es").await.unwrap();
+
+    game.players[3]
+        .send_message(Message::Text(
+            serde_json::json!({"kind": "startGame", "amountOfRounds": 3}).to_string(),
+        ))
+        .await;
+
+    let Some(Ok(message)) = game.players[3].rx.next().await else {
+        panic!("Expected an error message.");
+    };
+    let error: serde_json::Value = serde_json::from_str(message.to_text().unwrap()).unwrap();
+    assert_eq!(error["type"], "NON_HOST_PLAYER_CANNOT_START_GAME");
+    assert_eq!(
+        error["title"],
+        "Solo el anfitrión puede empezar la partida."
+    );
+    assert!(error["detail"].as_str().unwrap().contains("p4"));
+}
+
+#[tokio::test]
+async fn players_are_told_whether_a_player_joined_or_reconnected() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+    let reconnection_token = game.players[2].reconnection_token.clone().unwrap();
+    drop(game.players.pop());
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+
+    let response = game
+        .app
+        .send_rejoin_request(&game.id, "p3", &reconnection_token)
+        .await;
+    let response: serde_json::Value = response.json().await.unwrap();
+    let ticket = response["ticket"].as_str().unwrap();
+    let _p3 = game
+        .app
+        .open_game_websocket_with_query(&game.id, "p3", &format!("?ticket={ticket}"))
+        .await
+        .unwrap();
+    let (events, _) = game.players[0]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![WsMessageIn::PlayerReconnected {
+            nickname: "p3".to_string()
+        }]
+    );
+
+    let _p4 = game
+        .app
+        .open_game_websocket_with_query(&game.id, "p4", "")
+        .await
+        .unwrap();
+    let (events, _) = game.players[0]
+        .receive_events_until_game_state()
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![WsMessageIn::PlayerJoined {
+            nickname: "p4".to_string()
+        }]
+    );
+}
+
+#[tokio::test]
+async fn host_skips_the_voting_item_without_scoring_it() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+
+    let result = game.players[1].skip_voting_item().await;
+    assert_eq!(
+        result,
+        Err("NON_HOST_PLAYER_CANNOT_SKIP_VOTING_ITEM".to_string())
+    );
+    // The rejected command still broadcasts the unchanged state
+    let _ = game.players[0].receive_game_state().await.unwrap();
+
+    let state = game.players[0].skip_voting_item().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    assert_eq!(state.last_round().player_words["p1"][0].score, 0);
+    assert_eq!(state.last_round().voting_item.unwrap().word, "p1_w2");
+}
+
+#[tokio::test]
+async fn host_kicks_a_player() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let state = game.players[0].kick_player("p3").await.unwrap();
+
+    assert_eq!(state.players.len(), 2);
+    assert_eq!(
+        game.players[2].receive_message().await,
+        Ok(WsMessageIn::PlayerLeft {
+            nickname: "p3".to_string(),
+            reason: "kicked".to_string(),
+        })
+    );
+    assert_eq!(
+        game.players[2].receive_message().await,
+        Err("PLAYER_WAS_KICKED".to_string())
+    );
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    drop(game.players.pop());
+    assert_eq!(
+        game.add_player("p3").await,
+        Err("PLAYER_WAS_KICKED".to_string())
+    );
+}
+
+#[tokio::test]
+async fn non_host_cannot_kick_a_player() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let result = game.players[1].kick_player("p3").await;
+
+    assert_eq!(result, Err("NON_HOST_PLAYER_CANNOT_KICK".to_string()));
+}
+
+#[tokio::test]
+async fn host_merges_two_players_in_the_lobby() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let state = game.players[0].merge_players("p3", "p2").await.unwrap();
+
+    let nicknames: Vec<&str> = state
+        .players
+        .iter()
+        .map(|player| player.nickname.as_str())
+        .collect();
+    assert_eq!(nicknames, vec!["p1", "p2"]);
+    assert_eq!(
+        state.players.iter().filter(|player| player.is_host).count(),
+        1
+    );
+    assert!(state.players[0].is_host);
+    assert_eq!(
+        game.players[2].receive_message().await,
+        Ok(WsMessageIn::PlayerLeft {
+            nickname: "p3".to_string(),
+            reason: "merged".to_string(),
+        })
+    );
+    assert!(matches!(
+        game.players[2].rx.next().await,
+        Some(Ok(Message::Close(_))) | None
+    ));
+    let state = game.players[1].receive_game_state().await.unwrap();
+    assert_eq!(state.players.len(), 2);
+}
+
+#[tokio::test]
+async fn non_host_cannot_merge_players() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let result = game.players[1].merge_players("p3", "p2").await;
+
+    assert_eq!(
+        result,
+        Err("NON_HOST_PLAYER_CANNOT_MERGE_PLAYERS".to_string())
+    );
+}
+
+#[tokio::test]
+async fn new_host_can_continue_to_next_round_right_after_the_host_leaves() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    game.complete_round().await;
+    drop(game.players.remove(0));
+    let state = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    assert!(state.players[1].is_host);
+
+    let state = game.players[0].continue_to_next_round().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingWords);
+}
+
+#[tokio::test]
+async fn host_transfers_the_host_to_another_player() {
+    let mut game = TestApp::create_game(GameFsmState::Lobby).await;
+
+    let state = game.players[0].transfer_host("p2").await.unwrap();
+
+    assert!(!state.players[0].is_host);
+    assert!(state.players[1].is_host);
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    assert_eq!(
+        game.players[0].transfer_host("p3").await,
+        Err("NON_HOST_PLAYER_CANNOT_TRANSFER_HOST".to_string())
+    );
+}
+
+#[tokio::test]
+async fn round_advances_after_the_word_submission_deadline() {
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.game.word_submission_seconds = Some(1);
+    })
+    .await;
+    let (events, state) = game.players[0]
+        .start_game_and_receive_events(TestGame::AMOUNT_OF_ROUNDS)
+        .await
+        .unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingWords);
+    assert!(events.contains(&WsMessageIn::PhaseDeadline {
+        state: GameFsmState::PlayersSubmittingWords,
+        seconds_remaining: 1,
+    }));
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    let _ = game.players[0].send_words().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    let _ = game.players[1].send_words().await.unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    // p3 is too slow, the deadline submits no words for it
+    let state = game.players[0].receive_game_state().await.unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    assert!(state.last_round().player_words["p3"].is_empty());
+}
+
+#[tokio::test]
+async fn words_resubmitted_right_after_the_round_moves_on_are_ignored() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    let _ = game.players[0].send_words().await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    let _ = game.players[1].send_words().await.unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    // The first submission moves the round on to voting before the second one is processed
+    game.players[2].send_words_without_waiting().await;
+    game.players[2].words = vec!["p3_w3".to_string(), "p3_w4".to_string()];
+    game.players[2].send_words_without_waiting().await;
+
+    let state = game.players[2].receive_game_state().await.unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    assert_eq!(
+        game.players[2].receive_game_state().await,
+        Err("WORDS_ALREADY_SUBMITTED".to_string())
+    );
+    let state = game.players[2].receive_game_state().await.unwrap();
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    let words: Vec<String> = state.last_round().player_words["p3"]
+        .iter()
+        .map(|word| word.word.to_string())
+        .collect();
+    assert_eq!(words, vec!["p3_w1".to_string(), "p3_w2".to_string()]);
+}
+
+#[tokio::test]
+async fn player_is_disconnected_when_it_stops_sending_pings() {
+    let mut game = TestApp::spawn_app_with_config(|config| {
+        config.game.player_ping_timeout_seconds = 1;
+    })
+    .await
+    .new_game()
+    .await;
+    let _ = game.add_player("p1").await.unwrap();
+
+    time::sleep(Duration::from_millis(1500)).await;
+
+    assert_eq!(
+        game.players[0].receive_message().await,
+        Err("WEBSOCKET_CLOSED".to_string())
+    );
+}
+
+#[tokio::test]
+async fn player_answering_heartbeat_pings_stays_connected() {
+    let mut game = TestApp::spawn_app_with_config(|config| {
+        config.game.player_ping_timeout_seconds = 2;
+        config.game.player_heartbeat_interval_seconds = Some(1);
+    })
+    .await
+    .new_game()
+    .await;
+    let _ = game.add_player("p1").await.unwrap();
+
+    // The player never sends a ping, it only answers the server pings while reading
+    let result = time::timeout(
+        Duration::from_millis(3500),
+        game.players[0].receive_message(),
+    )
+    .await;
+
+    assert!(result.is_err());
+    let state = game.players[0].set_title("title").await.unwrap();
+    assert!(state.players[0].is_connected);
+}
+
+#[tokio::test]
+async fn player_not_answering_heartbeat_pings_is_disconnected() {
+    let mut game = TestApp::spawn_app_with_config(|config| {
+        config.game.player_ping_timeout_seconds = 2;
+        config.game.player_heartbeat_interval_seconds = Some(1);
+    })
+    .await
+    .new_game()
+    .await;
+    let _ = game.add_player("p1").await.unwrap();
+
+    // Not reading leaves the server pings unanswered, they must not keep the player connected
+    time::sleep(Duration::from_millis(3000)).await;
+
+    // Reading sends the pending 'Pong's, which may find the websocket already closed
+    let result = time::timeout(
+        Duration::from_millis(1000),
+        game.players[0].receive_message(),
+    )
+    .await;
+
+    assert!(matches!(result, Ok(Err(_))));
+}
+
+#[tokio::test]
+async fn voting_words_cannot_be_accepted_before_the_minimum_voting_item_time() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingVotingWord, |config| {
+            config.game.min_voting_item_seconds = 1;
+        })
+        .await;
+
+    let result = game.players[0].accept_players_voting_words().await;
+    assert_eq!(result, Err("VOTING_ITEM_TOO_SOON".to_string()));
+    // Like any other rejected command, the unchanged game state is broadcast
+    game.players[0].receive_game_state().await.unwrap();
+
+    time::sleep(Duration::from_millis(1100)).await;
+    let state = game.players[0].accept_players_voting_words().await.unwrap();
+    assert_eq!(state.last_round().voting_item.unwrap().word, "p1_w2");
+}
+
 #[tokio::test]
 async fn players_play_again_a_game() {
     let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
@@ -396,6 +1919,132 @@ async fn players_play_again_a_game() {
     assert_eq!(state, GameFsmState::Lobby);
 }
 
+#[tokio::test]
+async fn host_plays_again_with_the_same_settings() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        let _ = game.continue_to_next_round().await;
+    }
+
+    let state = game.players[0].play_again_same_settings().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingWords);
+    assert_eq!(
+        state.amount_of_rounds,
+        Some(TestGame::AMOUNT_OF_ROUNDS as u8)
+    );
+    assert_eq!(state.rounds.len(), 1);
+}
+
+#[tokio::test]
+async fn host_plays_again_with_new_settings() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        let _ = game.continue_to_next_round().await;
+    }
+
+    let state = game.players[0].play_again().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::Lobby);
+    assert_eq!(state.amount_of_rounds, None);
+    assert!(state.rounds.is_empty());
+}
+
+#[tokio::test]
+async fn host_ends_the_session() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        let _ = game.continue_to_next_round().await;
+    }
+
+    let state = game.players[0].end_session().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::EndOfGame);
+    for player in game.players.iter_mut() {
+        if player.nickname != "p1" {
+            let _ = player.receive_game_state().await.unwrap();
+        }
+        assert_eq!(
+            player.receive_message().await,
+            Ok(WsMessageIn::SessionEnded)
+        );
+        assert!(matches!(
+            player.rx.next().await,
+            Some(Ok(Message::Close(_))) | None
+        ));
+    }
+}
+
+#[tokio::test]
+async fn non_host_cannot_choose_a_post_game_action() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+    for _ in 0..TestGame::AMOUNT_OF_ROUNDS {
+        game.complete_round().await;
+        let _ = game.continue_to_next_round().await;
+    }
+
+    // A rejected action still broadcasts the game state
+    let same_settings = game.players[1].play_again_same_settings().await;
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let new_settings = game.players[1].play_again().await;
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let end_session = game.players[1].end_session().await;
+
+    assert_eq!(
+        same_settings,
+        Err("NON_HOST_PLAYER_CANNOT_SEND_PLAY_AGAIN".to_string())
+    );
+    assert_eq!(
+        new_settings,
+        Err("NON_HOST_PLAYER_CANNOT_SEND_PLAY_AGAIN".to_string())
+    );
+    assert_eq!(
+        end_session,
+        Err("NON_HOST_PLAYER_CANNOT_END_SESSION".to_string())
+    );
+}
+
+#[tokio::test]
+async fn host_can_remove_the_word_being_voted_and_voting_continues() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+
+    let state = game.players[0].remove_word("p1", "p1_w1").await.unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    let round = state.last_round();
+    assert_eq!(round.voting_item.unwrap().word, "p1_w2");
+    assert_eq!(round.player_words["p1"].len(), 1);
+
+    let voting_word = game.players[1].words[0].clone();
+    let _ = game.players[1]
+        .send_voting_word(Some(voting_word))
+        .await
+        .unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    let state = game.players[0].accept_players_voting_words().await.unwrap();
+
+    assert_eq!(state.state, GameFsmState::PlayersSubmittingVotingWord);
+    assert_eq!(
+        state.last_round().voting_item.unwrap().player_nickname,
+        "p2"
+    );
+}
+
+#[tokio::test]
+async fn non_host_player_cannot_remove_word() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingVotingWord).await;
+
+    let result = game.players[1].remove_word("p1", "p1_w1").await;
+
+    assert_eq!(result, Err("NON_HOST_CANNOT_REMOVE_WORDS".to_string()));
+}
+
 #[tokio::test]
 async fn cannot_reject_word_outside_submitting_voting_word_state() {
     let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
@@ -645,6 +2294,38 @@ async fn cannot_resubmit_word_that_was_rejected_previously() {
     assert_eq!(&error, "CANNOT_RESUBMIT_REJECTED_MATCHED_WORD");
 }
 
+#[tokio::test]
+async fn can_resubmit_word_that_was_rejected_previously_when_allowed() {
+    let mut game =
+        TestApp::create_game_with_config(GameFsmState::PlayersSubmittingVotingWord, |config| {
+            config.game.allow_resubmit_after_reject = true;
+        })
+        .await;
+    let rejected_word = game.players[1].words[0].clone();
+
+    let _ = game.players[1]
+        .send_voting_word(Some(rejected_word.clone()))
+        .await
+        .unwrap();
+    let _ = game.players[0].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+    let _ = game.players[0]
+        .reject_matched_word("p2", &rejected_word)
+        .await
+        .unwrap();
+    let _ = game.players[1].receive_game_state().await.unwrap();
+    let _ = game.players[2].receive_game_state().await.unwrap();
+
+    let state = game.players[1]
+        .send_voting_word(Some(rejected_word.clone()))
+        .await
+        .unwrap();
+
+    let round = state.last_round();
+    assert_eq!(round.player_voting_words["p2"], Some(rejected_word));
+    assert!(round.voting_item.unwrap().rejected_matches.is_empty());
+}
+
 async fn sleep(duration: Duration) {
     let mut timer = time::interval(duration);
     timer.tick().await;
@@ -1,3 +1,4 @@
 pub mod test_app;
 pub mod test_game;
 pub mod test_player;
+pub mod test_script;
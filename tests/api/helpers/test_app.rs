@@ -1,8 +1,10 @@
+use std::thread::JoinHandle;
 use std::{net::SocketAddr, time::Duration};
 
 use headcrab::config::Config;
 use serde::Deserialize;
 use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
 use crate::helpers::test_game::GameFsmState;
@@ -12,39 +14,85 @@ use super::test_game::TestGame;
 pub struct TestApp {
     pub base_address: String,
     pub inactivity_timeout: Duration,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+    server_thread: Option<JoinHandle<()>>,
 }
 
 impl TestApp {
     pub async fn spawn_app() -> TestApp {
+        TestApp::spawn_app_with_config(|_| {}).await
+    }
+
+    pub async fn spawn_app_with_config(configure: impl FnOnce(&mut Config)) -> TestApp {
         // Binding to port 0 triggers an OS scan for an available port, this way we can run tests in parallel where each runs its own application
         let random_port_address = SocketAddr::from(([0, 0, 0, 0], 0));
-        let listener = TcpListener::bind(random_port_address)
-            .await
+        let listener = std::net::TcpListener::bind(random_port_address)
             .expect("Failed to bind to bind random port.");
+        listener
+            .set_nonblocking(true)
+            .expect("Failed to make the listener non blocking.");
         let address = listener.local_addr().unwrap();
         std::env::set_var("ENVIRONMENT", "dev");
         let config = {
             let mut config = Config::get().expect("Failed to read configuration.");
             config.game.inactivity_timeout_seconds = 1;
+            configure(&mut config);
             config
         };
 
-        let server = headcrab::startup::create_web_server(config.clone(), listener);
-        let _ = tokio::spawn(server);
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let shutdown_signal = async {
+            // Dropping the TestApp does not stop the server, only shut_down does
+            if shutdown_rx.await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        };
+        let inactivity_timeout = config.game.inactivity_timeout();
+        // A runtime of its own, so that the tasks left behind by the server stop with it, like they
+        // do when the process exits
+        let server_thread = std::thread::spawn(move || {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(2)
+                .enable_all()
+                .build()
+                .expect("Failed to build the server runtime.")
+                .block_on(async move {
+                    let listener =
+                        TcpListener::from_std(listener).expect("Failed to use the listener.");
+                    let _ = headcrab::startup::create_web_server(config, listener, shutdown_signal)
+                        .await;
+                })
+        });
 
         TestApp {
             base_address: format!("localhost:{}", address.port()),
-            inactivity_timeout: config.game.inactivity_timeout(),
+            inactivity_timeout,
+            shutdown_tx: Some(shutdown_tx),
+            server_thread: Some(server_thread),
+        }
+    }
+
+    // Like the server receiving a SIGTERM, returns once the server has stopped
+    pub async fn shut_down(&mut self) {
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(());
+        }
+        if let Some(server_thread) = self.server_thread.take() {
+            tokio::task::spawn_blocking(move || server_thread.join())
+                .await
+                .expect("Failed to wait for the server.")
+                .expect("The server panicked.");
         }
     }
 
-    pub async fn open_game_websocket(
+    pub async fn open_game_websocket_with_query(
         &self,
         game_id: &str,
         nickname: &str,
+        query: &str,
     ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, String> {
         tokio_tungstenite::connect_async(format!(
-            "ws://{}/game/{game_id}/player/{nickname}/ws",
+            "ws://{}/game/{game_id}/player/{nickname}/ws{query}",
             self.base_address
         ))
         .await
@@ -53,13 +101,66 @@ impl TestApp {
     }
 
     pub async fn create_game_without_players() -> TestGame {
-        let app = TestApp::spawn_app().await;
+        TestApp::spawn_app().await.new_game().await
+    }
+
+    pub async fn new_game(self) -> TestGame {
+        let response = self.send_create_game_request(None).await;
+        self.into_test_game(response).await
+    }
 
-        let response = reqwest::Client::new()
-            .post(format!("http://{}/game", app.base_address))
+    pub async fn new_game_with_request(self, request: serde_json::Value) -> TestGame {
+        let response = self.send_create_game_request(Some(request)).await;
+        self.into_test_game(response).await
+    }
+
+    pub async fn send_create_game_request(
+        &self,
+        request: Option<serde_json::Value>,
+    ) -> reqwest::Response {
+        let request_builder =
+            reqwest::Client::new().post(format!("http://{}/game", self.base_address));
+        match request {
+            Some(request) => request_builder.json(&request),
+            None => request_builder,
+        }
+        .send()
+        .await
+        .expect("Failed to execute CreateGame request.")
+    }
+
+    pub async fn send_get_public_state_request(&self, game_id: &str) -> reqwest::Response {
+        reqwest::Client::new()
+            .get(format!(
+                "http://{}/game/{game_id}/public-state",
+                self.base_address
+            ))
             .send()
             .await
-            .expect("Failed to execute CreateGame request.");
+            .expect("Failed to execute GetPublicState request.")
+    }
+
+    pub async fn send_rejoin_request(
+        &self,
+        game_id: &str,
+        nickname: &str,
+        reconnection_token: &str,
+    ) -> reqwest::Response {
+        reqwest::Client::new()
+            .post(format!(
+                "http://{}/game/{game_id}/rejoin",
+                self.base_address
+            ))
+            .json(&serde_json::json!({
+                "nickname": nickname,
+                "reconnection_token": reconnection_token,
+            }))
+            .send()
+            .await
+            .expect("Failed to execute Rejoin request.")
+    }
+
+    async fn into_test_game(self, response: reqwest::Response) -> TestGame {
         assert!(response.status().is_success());
 
         let game_created_response: GameCreatedResponse = response
@@ -69,33 +170,43 @@ impl TestApp {
         assert!(!game_created_response.id.is_empty());
 
         TestGame {
-            app,
+            app: self,
             id: game_created_response.id,
             players: vec![],
         }
     }
 
     pub async fn create_game(desired_state: GameFsmState) -> TestGame {
-        let mut game = TestApp::create_game_without_players().await;
+        TestApp::create_game_with_config(desired_state, |_| {}).await
+    }
+
+    pub async fn create_game_with_config(
+        desired_state: GameFsmState,
+        configure: impl FnOnce(&mut Config),
+    ) -> TestGame {
+        let mut game = TestApp::spawn_app_with_config(configure)
+            .await
+            .new_game()
+            .await;
 
         let state = game.add_player("p1").await.unwrap();
         assert_eq!(state.state, GameFsmState::Lobby);
         assert_eq!(state.players.len(), 1);
-        assert_eq!(state.players.get(0).unwrap().nickname, "p1");
-        assert!(state.players.get(0).unwrap().is_host);
+        assert_eq!(state.players.first().unwrap().nickname, "p1");
+        assert!(state.players.first().unwrap().is_host);
 
         // Make sure to read the events the other players receive when new players join, so that we leave a "clean" response channel for the tests
         let state = game.add_player("p2").await.unwrap();
         assert_eq!(state.state, GameFsmState::Lobby);
         assert_eq!(state.players.len(), 2);
-        assert_eq!(state.players.get(0).unwrap().nickname, "p1");
+        assert_eq!(state.players.first().unwrap().nickname, "p1");
         assert_eq!(state.players.get(1).unwrap().nickname, "p2");
         assert!(!state.players.get(1).unwrap().is_host);
 
         let state = game.add_player("p3").await.unwrap();
         assert_eq!(state.state, GameFsmState::Lobby);
         assert_eq!(state.players.len(), 3);
-        assert_eq!(state.players.get(0).unwrap().nickname, "p1");
+        assert_eq!(state.players.first().unwrap().nickname, "p1");
         assert_eq!(state.players.get(1).unwrap().nickname, "p2");
         assert_eq!(state.players.get(2).unwrap().nickname, "p3");
         assert!(!state.players.get(2).unwrap().is_host);
@@ -121,6 +232,7 @@ impl TestApp {
 
                 game.players_send_words().await.state
             }
+            GameFsmState::PreparingVoting => todo!(),
             GameFsmState::EndOfRound => todo!(),
             GameFsmState::EndOfGame => {
                 let mut state = game.players[0].start_game(3).await.unwrap();
@@ -1,7 +1,9 @@
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
 
 use super::{test_app::TestApp, test_player::TestPlayer};
 
@@ -15,14 +17,34 @@ impl TestGame {
     pub const AMOUNT_OF_ROUNDS: i8 = 3;
 
     pub async fn add_player(&mut self, nickname: &str) -> Result<GameState, String> {
+        self.add_player_with_query(nickname, "").await
+    }
+
+    pub async fn add_player_with_query(
+        &mut self,
+        nickname: &str,
+        query: &str,
+    ) -> Result<GameState, String> {
+        self.add_player_and_receive_events(nickname, query)
+            .await
+            .map(|(_, state)| state)
+    }
+
+    // Adds a player, returning the messages it received before the first GameState
+    pub async fn add_player_and_receive_events(
+        &mut self,
+        nickname: &str,
+        query: &str,
+    ) -> Result<(Vec<WsMessageIn>, GameState), String> {
         let (tx, rx) = self
             .app
-            .open_game_websocket(&self.id, nickname)
+            .open_game_websocket_with_query(&self.id, nickname, query)
             .await?
             .split();
         let mut player = TestPlayer {
             nickname: nickname.to_string(),
             words: vec![format!("{nickname}_w1"), format!("{nickname}_w2")],
+            reconnection_token: None,
             tx,
             rx,
         };
@@ -30,9 +52,87 @@ impl TestGame {
         for player in self.players.iter_mut() {
             let _ = player.receive_game_state().await.unwrap();
         }
-        let state = player.receive_game_state().await?;
+        let (events, state) = player.receive_events_until_game_state().await?;
+        player.reconnection_token = events.iter().find_map(|event| match event {
+            WsMessageIn::ReconnectionToken { reconnection_token } => {
+                Some(reconnection_token.clone())
+            }
+            _ => None,
+        });
         self.players.push(player);
-        Ok(state)
+        Ok((events, state))
+    }
+
+    // Reclaims a disconnected player with a ticket from the rejoin endpoint, like a client would
+    pub async fn rejoin_player(
+        &mut self,
+        nickname: &str,
+        reconnection_token: &str,
+    ) -> Result<GameState, String> {
+        self.rejoin_player_and_receive_events(nickname, reconnection_token)
+            .await
+            .map(|(_, state)| state)
+    }
+
+    pub async fn rejoin_player_and_receive_events(
+        &mut self,
+        nickname: &str,
+        reconnection_token: &str,
+    ) -> Result<(Vec<WsMessageIn>, GameState), String> {
+        let response = self
+            .app
+            .send_rejoin_request(&self.id, nickname, reconnection_token)
+            .await;
+        if !response.status().is_success() {
+            return Err(response.status().to_string());
+        }
+        let response: serde_json::Value = response.json().await.unwrap();
+        let ticket = response["ticket"].as_str().unwrap().to_string();
+        self.add_player_and_receive_events(nickname, &format!("?ticket={ticket}"))
+            .await
+    }
+
+    // The server only notices a dropped websocket after a while, this waits until it did
+    pub async fn wait_until_all_players_are_disconnected(&self) {
+        loop {
+            let response = self.app.send_get_public_state_request(&self.id).await;
+            if let Ok(WsMessageIn::GameState { players, .. }) = response.json().await {
+                if players.iter().all(|player| !player.is_connected) {
+                    return;
+                }
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    // Adds a player that only receives chat messages, it is not part of `players`
+    pub async fn add_chat_subscriber(&mut self, nickname: &str) -> Result<TestPlayer, String> {
+        let (tx, rx) = self
+            .app
+            .open_game_websocket_with_query(&self.id, nickname, "?subscribe=chat")
+            .await?
+            .split();
+        for player in self.players.iter_mut() {
+            let _ = player.receive_game_state().await.unwrap();
+        }
+        let mut player = TestPlayer {
+            nickname: nickname.to_string(),
+            words: vec![],
+            reconnection_token: None,
+            tx,
+            rx,
+        };
+        match player.receive_message().await? {
+            WsMessageIn::ReconnectionToken { reconnection_token } => {
+                player.reconnection_token = Some(reconnection_token)
+            }
+            unexpected_message => {
+                return Err(format!(
+                    "Expected a ReconnectionToken message. Message: '{unexpected_message:?}'."
+                ))
+            }
+        }
+        Ok(player)
     }
 
     pub async fn players_send_words(&mut self) -> GameState {
@@ -55,7 +155,7 @@ impl TestGame {
 
         // Voting for p1_w1
         // p1: [used, unused], p2: [used, unused], p3: [unused, unused]
-        let voting_word = self.players[1].words.get(0).cloned();
+        let voting_word = self.players[1].words.first().cloned();
         let _ = self.players[1].send_voting_word(voting_word).await.unwrap();
         let _ = self.players[0].receive_game_state().await.unwrap();
         let _ = self.players[2].receive_game_state().await.unwrap();
@@ -115,10 +215,12 @@ impl TestGame {
 
 #[derive(Deserialize, Debug, PartialEq)]
 pub struct GameState {
+    pub title: String,
     pub state: GameFsmState,
     pub players: Vec<Player>,
     pub rounds: Vec<Round>,
     pub amount_of_rounds: Option<u8>,
+    pub player_total_scores: HashMap<String, isize>,
 }
 
 impl GameState {
@@ -143,22 +245,56 @@ pub struct VotingItem {
     pub rejected_matches: HashMap<String, HashSet<String>>,
 }
 
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreBreakdownEntry {
+    pub round: usize,
+    pub word: Option<String>,
+    pub score: isize,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatHistoryMessage {
+    pub sender: String,
+    pub content: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemainingVotingItem {
+    pub player_nickname: String,
+    pub word: Option<String>,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Round {
+    pub round: usize,
     pub word: String,
     pub player_words: HashMap<String, Vec<Word>>,
     pub player_voting_words: HashMap<String, Option<String>>,
     pub voting_item: Option<VotingItem>,
+    pub review_order: Vec<ReviewItem>,
+    pub submitted_count: Option<usize>,
+    pub expected_count: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewItem {
+    pub player_nickname: String,
+    pub word: String,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Word {
     pub word: String,
+    pub score: isize,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "camelCase", tag = "kind")]
 pub enum WsMessageIn {
     #[serde(rename_all = "camelCase")]
@@ -169,11 +305,115 @@ pub enum WsMessageIn {
     },
     #[serde(rename_all = "camelCase")]
     GameState {
+        title: String,
         state: GameFsmState,
         players: Vec<Player>,
         rounds: Vec<Round>,
         amount_of_rounds: Option<u8>,
+        player_total_scores: HashMap<String, isize>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Ack {
+        request_id: String,
+        ok: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChatMessage {
+        sender: String,
+        content: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerTyping {
+        nickname: String,
+        is_typing: bool,
     },
+    #[serde(rename_all = "camelCase")]
+    Players {
+        players: Vec<Player>,
+    },
+    #[serde(rename_all = "camelCase")]
+    LastWords {
+        words: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ReconnectionToken {
+        reconnection_token: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ReconnectSnapshot {
+        welcome: Player,
+        state: Box<WsMessageIn>,
+        chat_history: Vec<ChatHistoryMessage>,
+        my_words: Vec<Word>,
+    },
+    #[serde(rename_all = "camelCase")]
+    WordsAccepted {
+        accepted: Vec<String>,
+        dropped: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    RemainingVotingItems {
+        items: Vec<RemainingVotingItem>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ScoreBreakdown {
+        breakdown: HashMap<String, Vec<ScoreBreakdownEntry>>,
+    },
+    #[serde(rename_all = "camelCase")]
+    RoundHistory {
+        rounds: Vec<Round>,
+        total: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    WordScored {
+        owner: String,
+        word: String,
+        score: isize,
+    },
+    #[serde(rename_all = "camelCase")]
+    VotingItemResolved {
+        player_nickname: String,
+        word: String,
+        score: isize,
+        matched_players: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    SystemMessage {
+        text: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    WordReveal {
+        masked: String,
+    },
+    YouAreNext,
+    #[serde(rename_all = "camelCase")]
+    FinalScoreboard {
+        ranking: Vec<(String, isize)>,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerJoined {
+        nickname: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerReconnected {
+        nickname: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerLeft {
+        nickname: String,
+        reason: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PhaseDeadline {
+        state: GameFsmState,
+        seconds_remaining: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    PreparingVoting {
+        seconds_remaining: u64,
+    },
+    SessionEnded,
+    ServerShuttingDown,
 }
 
 #[derive(Serialize)]
@@ -193,19 +433,61 @@ pub enum WsMessageOut {
         word: Option<String>,
     },
     #[serde(rename_all = "camelCase")]
+    SetTitle {
+        title: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChatMessage {
+        content: String,
+    },
+    TypingStarted,
+    TypingStopped,
+    #[serde(rename_all = "camelCase")]
     RejectMatchedWord {
         rejected_player: String,
         rejected_word: String,
     },
+    #[serde(rename_all = "camelCase")]
+    RemoveWord {
+        player: String,
+        word: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    KickPlayer {
+        nickname: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    MergePlayers {
+        source: String,
+        target: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    TransferHost {
+        nickname: String,
+    },
+    ClearVotingWord,
     AcceptPlayersVotingWords,
+    SkipVotingItem,
     ContinueToNextRound,
     PlayAgain,
+    PlayAgainSameSettings,
+    EndSession,
+    GetPlayers,
+    GetLastWords,
+    GetRemainingVotingItems,
+    GetScoreBreakdown,
+    #[serde(rename_all = "camelCase")]
+    GetRoundHistory {
+        offset: usize,
+        limit: Option<usize>,
+    },
 }
 
 #[derive(Deserialize, Debug, PartialEq)]
 pub enum GameFsmState {
     Lobby,
     PlayersSubmittingWords,
+    PreparingVoting,
     PlayersSubmittingVotingWord,
     EndOfRound,
     EndOfGame,
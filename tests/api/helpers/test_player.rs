@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use futures_util::{
     stream::{SplitSink, SplitStream},
     SinkExt, StreamExt,
@@ -5,31 +7,69 @@ use futures_util::{
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
-use super::test_game::{GameState, WsMessageIn, WsMessageOut};
+use super::test_game::{
+    GameState, Player, RemainingVotingItem, Round, ScoreBreakdownEntry, WsMessageIn, WsMessageOut,
+};
 
 pub struct TestPlayer {
     pub nickname: String,
     pub words: Vec<String>,
+    // Sent by the server when the player joined
+    pub reconnection_token: Option<String>,
     pub tx: SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
     pub rx: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
 }
 
 impl TestPlayer {
     pub async fn receive_game_state(&mut self) -> Result<GameState, String> {
-        match self.rx.next().await {
+        self.receive_events_until_game_state()
+            .await
+            .map(|(_, state)| state)
+    }
+
+    // Receives messages until the next GameState, returning the events broadcast before it
+    pub async fn receive_events_until_game_state(
+        &mut self,
+    ) -> Result<(Vec<WsMessageIn>, GameState), String> {
+        let mut events = vec![];
+        loop {
+            match self.receive_message().await? {
+                WsMessageIn::GameState {
+                    title,
+                    state,
+                    players,
+                    rounds,
+                    amount_of_rounds,
+                    player_total_scores,
+                } => {
+                    return Ok((
+                        events,
+                        GameState {
+                            title,
+                            state,
+                            players,
+                            rounds,
+                            amount_of_rounds,
+                            player_total_scores,
+                        },
+                    ))
+                }
+                event => events.push(event),
+            }
+        }
+    }
+
+    pub async fn receive_message(&mut self) -> Result<WsMessageIn, String> {
+        // Reading answers the server heartbeat pings on its own, they are not game messages
+        let message = loop {
+            match self.rx.next().await {
+                Some(Ok(Message::Ping(_))) | Some(Ok(Message::Pong(_))) => continue,
+                message => break message,
+            }
+        };
+        match message {
             Some(Ok(message)) => {
                 match serde_json::from_str(message.to_text().expect("Message was not a text")) {
-                    Ok(WsMessageIn::GameState {
-                        state,
-                        players,
-                        rounds,
-                        amount_of_rounds,
-                    }) => Ok(GameState {
-                        state,
-                        players,
-                        rounds,
-                        amount_of_rounds,
-                    }),
                     Ok(WsMessageIn::Error {
                         r#type,
                         title,
@@ -39,6 +79,7 @@ impl TestPlayer {
                         assert!(!detail.is_empty());
                         Err(r#type)
                     }
+                    Ok(message) => Ok(message),
                     Err(error) => Err(format!("Could not parse the message. Error: '{error}'.")),
                 }
             }
@@ -53,10 +94,27 @@ impl TestPlayer {
         self.receive_game_state().await
     }
 
+    pub async fn start_game_and_receive_events(
+        &mut self,
+        amount_of_rounds: i8,
+    ) -> Result<(Vec<WsMessageIn>, GameState), String> {
+        self.send_text_message(WsMessageOut::StartGame { amount_of_rounds })
+            .await;
+        self.receive_events_until_game_state().await
+    }
+
     pub async fn send_words(&mut self) -> Result<GameState, String> {
         self.send_custom_words(self.words.clone()).await
     }
 
+    // Sends the words without waiting for the resulting GameState
+    pub async fn send_words_without_waiting(&mut self) {
+        self.send_text_message(WsMessageOut::PlayerWords {
+            words: self.words.clone(),
+        })
+        .await;
+    }
+
     pub async fn send_custom_words(&mut self, words: Vec<String>) -> Result<GameState, String> {
         self.send_text_message(WsMessageOut::PlayerWords { words })
             .await;
@@ -69,23 +127,134 @@ impl TestPlayer {
         self.receive_game_state().await
     }
 
+    pub async fn clear_voting_word(&mut self) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::ClearVotingWord).await;
+        self.receive_game_state().await
+    }
+
     pub async fn accept_players_voting_words(&mut self) -> Result<GameState, String> {
         self.send_text_message(WsMessageOut::AcceptPlayersVotingWords)
             .await;
         self.receive_game_state().await
     }
 
+    pub async fn skip_voting_item(&mut self) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::SkipVotingItem).await;
+        self.receive_game_state().await
+    }
+
+    pub async fn set_title(&mut self, title: &str) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::SetTitle {
+            title: title.to_string(),
+        })
+        .await;
+        self.receive_game_state().await
+    }
+
     pub async fn continue_to_next_round(&mut self) -> Result<GameState, String> {
         self.send_text_message(WsMessageOut::ContinueToNextRound)
             .await;
         self.receive_game_state().await
     }
 
+    pub async fn continue_to_next_round_and_receive_events(
+        &mut self,
+    ) -> Result<(Vec<WsMessageIn>, GameState), String> {
+        self.send_text_message(WsMessageOut::ContinueToNextRound)
+            .await;
+        self.receive_events_until_game_state().await
+    }
+
     pub async fn play_again(&mut self) -> Result<GameState, String> {
         self.send_text_message(WsMessageOut::PlayAgain).await;
         self.receive_game_state().await
     }
 
+    pub async fn play_again_same_settings(&mut self) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::PlayAgainSameSettings)
+            .await;
+        self.receive_game_state().await
+    }
+
+    pub async fn end_session(&mut self) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::EndSession).await;
+        self.receive_game_state().await
+    }
+
+    pub async fn send_chat_message(&mut self, content: &str) {
+        self.send_text_message(WsMessageOut::ChatMessage {
+            content: content.to_string(),
+        })
+        .await;
+    }
+
+    pub async fn start_typing(&mut self) {
+        self.send_text_message(WsMessageOut::TypingStarted).await;
+    }
+
+    pub async fn stop_typing(&mut self) {
+        self.send_text_message(WsMessageOut::TypingStopped).await;
+    }
+
+    pub async fn get_players(&mut self) -> Result<Vec<Player>, String> {
+        self.send_text_message(WsMessageOut::GetPlayers).await;
+        match self.receive_message().await? {
+            WsMessageIn::Players { players } => Ok(players),
+            unexpected_message => Err(format!(
+                "Expected a Players message. Message: '{unexpected_message:?}'."
+            )),
+        }
+    }
+
+    pub async fn get_last_words(&mut self) -> Result<Vec<String>, String> {
+        self.send_text_message(WsMessageOut::GetLastWords).await;
+        match self.receive_message().await? {
+            WsMessageIn::LastWords { words } => Ok(words),
+            unexpected_message => Err(format!(
+                "Expected a LastWords message. Message: '{unexpected_message:?}'."
+            )),
+        }
+    }
+
+    pub async fn get_remaining_voting_items(&mut self) -> Result<Vec<RemainingVotingItem>, String> {
+        self.send_text_message(WsMessageOut::GetRemainingVotingItems)
+            .await;
+        match self.receive_message().await? {
+            WsMessageIn::RemainingVotingItems { items } => Ok(items),
+            unexpected_message => Err(format!(
+                "Expected a RemainingVotingItems message. Message: '{unexpected_message:?}'."
+            )),
+        }
+    }
+
+    pub async fn get_score_breakdown(
+        &mut self,
+    ) -> Result<HashMap<String, Vec<ScoreBreakdownEntry>>, String> {
+        self.send_text_message(WsMessageOut::GetScoreBreakdown)
+            .await;
+        match self.receive_message().await? {
+            WsMessageIn::ScoreBreakdown { breakdown } => Ok(breakdown),
+            unexpected_message => Err(format!(
+                "Expected a ScoreBreakdown message. Message: '{unexpected_message:?}'."
+            )),
+        }
+    }
+
+    pub async fn get_round_history(
+        &mut self,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<(Vec<Round>, usize), String> {
+        self.send_text_message(WsMessageOut::GetRoundHistory { offset, limit })
+            .await;
+        match self.receive_message().await? {
+            WsMessageIn::RoundHistory { rounds, total } => Ok((rounds, total)),
+            unexpected_message => Err(format!(
+                "Expected a RoundHistory message. Message: '{unexpected_message:?}'."
+            )),
+        }
+    }
+
     pub async fn send_raw_message(&mut self, message: Message) -> Result<GameState, String> {
         self.send_message(message).await;
         self.receive_game_state().await
@@ -108,6 +277,40 @@ impl TestPlayer {
         self.receive_game_state().await
     }
 
+    pub async fn remove_word(&mut self, player: &str, word: &str) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::RemoveWord {
+            player: player.to_string(),
+            word: word.to_string(),
+        })
+        .await;
+        self.receive_game_state().await
+    }
+
+    pub async fn kick_player(&mut self, nickname: &str) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::KickPlayer {
+            nickname: nickname.to_string(),
+        })
+        .await;
+        self.receive_game_state().await
+    }
+
+    pub async fn merge_players(&mut self, source: &str, target: &str) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::MergePlayers {
+            source: source.to_string(),
+            target: target.to_string(),
+        })
+        .await;
+        self.receive_game_state().await
+    }
+
+    pub async fn transfer_host(&mut self, nickname: &str) -> Result<GameState, String> {
+        self.send_text_message(WsMessageOut::TransferHost {
+            nickname: nickname.to_string(),
+        })
+        .await;
+        self.receive_game_state().await
+    }
+
     async fn send_text_message(&mut self, message: WsMessageOut) {
         self.send_message(Message::Text(
             serde_json::to_string(&message).expect("Could not serialize message"),
@@ -0,0 +1,62 @@
+use super::test_game::{GameState, TestGame};
+
+// A step of a scripted game, taken by the player with the given nickname
+#[derive(Debug)]
+pub enum Action<'a> {
+    Join(&'a str),
+    StartGame(&'a str, i8),
+    SubmitWords(&'a str),
+    Vote(&'a str, Option<&'a str>),
+    Accept(&'a str),
+    ContinueToNextRound(&'a str),
+}
+
+impl TestGame {
+    // Runs every action in order, the rest of the players receive the GameState each one broadcasts.
+    // Returns the GameState the player of the last action received, or the first error
+    pub async fn run_scripted(&mut self, actions: &[Action<'_>]) -> Result<GameState, String> {
+        let mut state = None;
+        for action in actions {
+            let nickname = match action {
+                // Joining already clears the messages on the other players
+                Action::Join(nickname) => {
+                    state = Some(self.add_player(nickname).await?);
+                    continue;
+                }
+                Action::StartGame(nickname, _)
+                | Action::SubmitWords(nickname)
+                | Action::Vote(nickname, _)
+                | Action::Accept(nickname)
+                | Action::ContinueToNextRound(nickname) => nickname,
+            };
+            let index = self
+                .players
+                .iter()
+                .position(|player| player.nickname == *nickname)
+                .ok_or_else(|| {
+                    format!("The player of the action has not joined. Action: '{action:?}'.")
+                })?;
+            let player = &mut self.players[index];
+            state = Some(match action {
+                Action::StartGame(_, amount_of_rounds) => {
+                    player.start_game(*amount_of_rounds).await?
+                }
+                Action::SubmitWords(_) => player.send_words().await?,
+                Action::Vote(_, word) => {
+                    player
+                        .send_voting_word(word.map(|word| word.to_string()))
+                        .await?
+                }
+                Action::Accept(_) => player.accept_players_voting_words().await?,
+                Action::ContinueToNextRound(_) => player.continue_to_next_round().await?,
+                Action::Join(_) => unreachable!("Joining is handled above"),
+            });
+            for (other_index, other_player) in self.players.iter_mut().enumerate() {
+                if other_index != index {
+                    let _ = other_player.receive_game_state().await?;
+                }
+            }
+        }
+        state.ok_or_else(|| "The script has no actions.".to_string())
+    }
+}
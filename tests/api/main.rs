@@ -1,3 +1,7 @@
 mod game;
 mod health;
 mod helpers;
+mod protocol;
+mod shutdown;
+mod stats;
+mod words;
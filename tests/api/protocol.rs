@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+use crate::helpers::test_app::TestApp;
+
+#[tokio::test]
+async fn protocol_states_lists_every_game_state() {
+    let app = TestApp::spawn_app().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://{}/protocol/states", app.base_address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert!(response.status().is_success());
+    let states: StatesResponse = response
+        .json()
+        .await
+        .expect("Failed to parse StatesResponse.");
+    assert_eq!(
+        states.states,
+        vec![
+            "Lobby",
+            "CreatingNewRound",
+            "PlayersSubmittingWords",
+            "PreparingVoting",
+            "ChooseNextVotingItem",
+            "PlayersSubmittingVotingWord",
+            "EndOfRound",
+            "EndOfGame",
+        ]
+    );
+}
+
+#[derive(Deserialize)]
+struct StatesResponse {
+    states: Vec<String>,
+}
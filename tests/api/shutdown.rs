@@ -0,0 +1,26 @@
+use futures_util::StreamExt;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::helpers::{
+    test_app::TestApp,
+    test_game::{GameFsmState, WsMessageIn},
+};
+
+#[tokio::test]
+async fn connected_players_are_told_the_server_is_shutting_down() {
+    let mut game = TestApp::create_game(GameFsmState::PlayersSubmittingWords).await;
+
+    // Every task of the server is gone once it stops, the players must have been told already
+    game.app.shut_down().await;
+
+    for player in game.players.iter_mut() {
+        assert_eq!(
+            player.receive_message().await,
+            Ok(WsMessageIn::ServerShuttingDown)
+        );
+        assert!(matches!(
+            player.rx.next().await,
+            Some(Ok(Message::Close(_))) | None
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+use crate::helpers::test_app::TestApp;
+
+#[tokio::test]
+async fn stats_reflect_created_games() {
+    let game = TestApp::create_game_without_players().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://{}/stats", game.app.base_address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert!(response.status().is_success());
+    let stats: StatsResponse = response
+        .json()
+        .await
+        .expect("Failed to parse StatsResponse.");
+    assert!(stats.games_created >= 1);
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsResponse {
+    games_created: u64,
+}
@@ -0,0 +1,65 @@
+use axum::{routing::get, Router};
+use tokio::net::TcpListener;
+
+use crate::helpers::test_app::TestApp;
+use crate::helpers::test_game::GameFsmState;
+
+#[tokio::test]
+async fn words_are_loaded_from_url() {
+    let words_url = spawn_words_server(" Pizza \nsurf\n\nPIZZA\n").await;
+
+    let mut game = TestApp::create_game_with_config(GameFsmState::Lobby, |config| {
+        config.words_file = words_url
+    })
+    .await;
+
+    let state = game.players[0].start_game(1).await.unwrap();
+
+    assert!(["pizza", "surf"].contains(&state.last_round().word.as_str()));
+}
+
+#[tokio::test]
+async fn words_are_taken_from_the_category_of_the_game() {
+    let food_url = spawn_words_server("pizza\n").await;
+    let app = TestApp::spawn_app_with_config(|config| {
+        config.word_categories.insert("food".to_string(), food_url);
+    })
+    .await;
+    let mut game = app
+        .new_game_with_request(serde_json::json!({ "category": "food" }))
+        .await;
+    for nickname in ["p1", "p2", "p3"] {
+        let _ = game.add_player(nickname).await.unwrap();
+    }
+
+    let state = game.players[0].start_game(1).await.unwrap();
+
+    assert_eq!(state.last_round().word, "pizza");
+}
+
+#[tokio::test]
+async fn unknown_category_uses_the_default_words() {
+    let words_url = spawn_words_server("surf\n").await;
+    let app = TestApp::spawn_app_with_config(|config| config.words_file = words_url).await;
+    let mut game = app
+        .new_game_with_request(serde_json::json!({ "category": "unknown" }))
+        .await;
+    for nickname in ["p1", "p2", "p3"] {
+        let _ = game.add_player(nickname).await.unwrap();
+    }
+
+    let state = game.players[0].start_game(1).await.unwrap();
+
+    assert_eq!(state.last_round().word, "surf");
+}
+
+async fn spawn_words_server(words: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind random port.");
+    let address = listener.local_addr().unwrap();
+    let router = Router::new().route("/words", get(move || async move { words }));
+    tokio::spawn(async move { axum::serve(listener, router).await });
+
+    format!("http://{address}/words")
+}